@@ -0,0 +1,184 @@
+//! Benchmarks for barcode encoding and generation.
+//!
+//! Run with `cargo bench`. Each variable-length symbology is encoded from a small, medium and
+//! large input, each fixed-length symbology from a single input, and each generator renders the
+//! same encoded EAN-13 barcode, so that regressions in either are reported by criterion.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use scanning::error::Result;
+use scanning::generators::ascii::ASCII;
+use scanning::generators::json::JSON;
+use scanning::generators::svg::SVG;
+use scanning::sym::codabar::Codabar;
+use scanning::sym::code11::Code11;
+use scanning::sym::code128::{CharacterSet, Code128};
+use scanning::sym::code39::Code39;
+use scanning::sym::code93::Code93;
+use scanning::sym::ean13::EAN13;
+use scanning::sym::ean8::EAN8;
+use scanning::sym::tf::TF;
+use scanning::sym::upca::UPCA;
+use std::hint::black_box;
+
+/// Benchmarks joining 13 seven-module encodings (an EAN-13-sized workload) with `flat_map`, as
+/// `join_iters` used to, against `flatten`, as it does now.
+fn bench_join_iters(c: &mut Criterion) {
+    let encodings: Vec<[u8; 7]> = (0..13).map(|i| [1, 0, i % 2, 1, 1, i % 3, 0]).collect();
+    let mut group = c.benchmark_group("join_iters");
+
+    group.bench_function("flat_map", |b| {
+        b.iter(|| {
+            black_box(&encodings)
+                .iter()
+                .flat_map(IntoIterator::into_iter)
+                .copied()
+                .collect::<Vec<u8>>()
+        });
+    });
+    group.bench_function("flatten", |b| {
+        b.iter(|| {
+            black_box(&encodings)
+                .iter()
+                .flatten()
+                .copied()
+                .collect::<Vec<u8>>()
+        });
+    });
+    group.finish();
+}
+
+/// Benchmarks the encoding of each input, constructing the barcode outside of the timed loop.
+fn bench_encode<B, E>(
+    c: &mut Criterion,
+    name: &str,
+    inputs: &[(&str, &'static str)],
+    new: impl Fn(&'static str) -> Result<B>,
+    encode: impl Fn(&B) -> E,
+) {
+    let mut group = c.benchmark_group(format!("{name}_encode"));
+
+    for &(size, data) in inputs {
+        let barcode = new(data).unwrap_or_else(|e| panic!("Failed to create {name}: {e}"));
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &barcode, |b, barcode| {
+            b.iter(|| encode(black_box(barcode)));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_encoders(c: &mut Criterion) {
+    // Fixed-length symbologies are benchmarked with a single input.
+    bench_encode(
+        c,
+        "ean13",
+        &[("fixed", "750103131130")],
+        EAN13::new,
+        EAN13::encode,
+    );
+    bench_encode(
+        c,
+        "upca",
+        &[("fixed", "03600029145")],
+        UPCA::new,
+        UPCA::encode,
+    );
+    bench_encode(c, "ean8", &[("fixed", "5512345")], EAN8::new, EAN8::encode);
+    bench_encode(
+        c,
+        "code128",
+        &[
+            ("small", "ÀHI"),
+            ("medium", "ƁHello, World!"),
+            ("large", "Ć12345678901234567890123456789012345678901234"),
+        ],
+        |data| Code128::new(data, CharacterSet::None),
+        Code128::encode,
+    );
+    bench_encode(
+        c,
+        "code93",
+        &[
+            ("small", "9"),
+            ("medium", "TEST93"),
+            ("large", "CODE 93-$/+%.0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+        ],
+        Code93::new,
+        Code93::encode,
+    );
+    bench_encode(
+        c,
+        "code39",
+        &[
+            ("small", "1"),
+            ("medium", "CODE-39"),
+            ("large", "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+        ],
+        Code39::new,
+        Code39::encode,
+    );
+    bench_encode(
+        c,
+        "code11",
+        &[
+            ("small", "0"),
+            ("medium", "123-45"),
+            ("large", "9988-45643201-111-999-8"),
+        ],
+        Code11::new,
+        Code11::encode,
+    );
+    bench_encode(
+        c,
+        "itf",
+        &[
+            ("small", "12"),
+            ("medium", "1234567"),
+            ("large", "98765432109876543210"),
+        ],
+        TF::interleaved,
+        TF::encode,
+    );
+    bench_encode(
+        c,
+        "stf",
+        &[
+            ("small", "1"),
+            ("medium", "1234567"),
+            ("large", "98765432109876543210"),
+        ],
+        TF::standard,
+        TF::encode,
+    );
+    bench_encode(
+        c,
+        "codabar",
+        &[
+            ("small", "A1B"),
+            ("medium", "A40156B"),
+            ("large", "C12-34$56:78/90.12+D"),
+        ],
+        Codabar::new,
+        Codabar::encode,
+    );
+}
+
+/// Benchmarks each generator rendering an encoded EAN-13 barcode.
+fn bench_generators(c: &mut Criterion) {
+    let encoded = EAN13::new("750103131130")
+        .expect("Failed to create EAN13 barcode")
+        .encode();
+    let mut group = c.benchmark_group("generate");
+    let ascii = ASCII::new();
+    let json = JSON::new();
+    let svg = SVG::new(80);
+
+    group.bench_function("ascii", |b| b.iter(|| ascii.generate(black_box(&encoded))));
+    group.bench_function("json", |b| b.iter(|| json.generate(black_box(&encoded))));
+    group.bench_function("svg", |b| b.iter(|| svg.generate(black_box(&encoded))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_encoders, bench_join_iters, bench_generators);
+criterion_main!(benches);
@@ -6,17 +6,24 @@ use std::error::Error as StdError;
 
 /// The possible errors that can occur during barcode encoding and generation.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// An invalid character found during encoding.
     Character,
     /// An invalid data length during encoding.
     Length,
+    /// The data is shorter than the minimum length allowed during encoding.
+    TooShort,
+    /// The data is longer than the maximum length allowed during encoding.
+    TooLong,
     /// An error during barcode generation.
     Generate,
     /// Invalid checksum.
     Checksum,
     /// Invalid data.
     Conversion,
+    /// No starting character set was specified, either as an argument or in the data.
+    CharacterSet,
 }
 
 /// Alias-type for Result<T, `barcoders::error::Error`>.
@@ -27,12 +34,77 @@ impl fmt::Display for Error {
         match self {
             Self::Character => write!(f, "Barcode data is invalid"),
             Self::Length => write!(f, "Barcode data length is invalid"),
+            Self::TooShort => write!(f, "Barcode data is too short"),
+            Self::TooLong => write!(f, "Barcode data is too long"),
             Self::Generate => write!(f, "Could not generate barcode data"),
             Self::Checksum => write!(f, "Invalid checksum"),
             Self::Conversion => write!(f, "Invalid data conversion"),
+            Self::CharacterSet => write!(f, "No starting character set specified"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl StdError for Error {}
+
+/// IO errors, e.g. when writing a generated barcode to a sink, are generation errors.
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(_: std::io::Error) -> Self {
+        Self::Generate
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        Self::other(error)
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "serde")))]
+mod tests {
+    use crate::error::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_error_conversion() {
+        use std::io::Write;
+
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("sink closed"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        fn write_barcode<W: Write>(sink: &mut W) -> Result<()> {
+            sink.write_all(b"101")?;
+            Ok(())
+        }
+
+        assert_eq!(write_barcode(&mut FailingWriter), Err(Error::Generate));
+
+        let io_error = std::io::Error::from(Error::Checksum);
+
+        assert_eq!(io_error.kind(), std::io::ErrorKind::Other);
+        assert_eq!(io_error.to_string(), "Invalid checksum");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&Error::Checksum).expect("Failed to serialize error");
+
+        assert_eq!(json, "\"Checksum\"");
+        assert_eq!(
+            serde_json::from_str::<Error>(&json).expect("Failed to deserialize error"),
+            Error::Checksum
+        );
+    }
+}
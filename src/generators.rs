@@ -15,6 +15,13 @@
 //! - `json`: Generate JSON barcodes.
 //! - `image`: Generate image-based barcodes.
 //! - `svg`: Generate SVG barcodes.
+//! - `pbm`: Generate PBM (portable bitmap) barcodes, without any third-party dependencies.
+//! - `zpl`: Generate ZPL labels for Zebra printers.
+//! - `unicode`: Generate barcodes from Unicode block characters, for terminals.
+//! - `serde`: Derive `Serialize`/`Deserialize` for `error::Error`.
+//!
+//! The `render` module is available whenever either the `image` or `svg` feature is enabled, and
+//! renders to the best available format. The `cache` module requires the `std` feature.
 
 #[cfg(feature = "ascii")]
 pub mod ascii;
@@ -27,3 +34,18 @@ pub mod image;
 
 #[cfg(feature = "svg")]
 pub mod svg;
+
+#[cfg(feature = "pbm")]
+pub mod pbm;
+
+#[cfg(feature = "zpl")]
+pub mod zpl;
+
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
+#[cfg(any(feature = "svg", all(feature = "image", feature = "std")))]
+pub mod render;
+
+#[cfg(feature = "std")]
+pub mod cache;
@@ -4,10 +4,27 @@
 //!
 //! You will pretty much never need to turn this feature on unless you are adding new functionality
 //! or running the test suite.
+//!
+//! Each symbology also provides a `to_ascii` shortcut, via the `Barcode` trait, which delegates
+//! to this generator.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::sym::ean13::*;
+//! use scanning::sym::Barcode;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let ascii = barcode.to_ascii(10, 1).unwrap();
+//! ```
+//!
+//! Barcodes can also be rendered in color for terminals which support 24-bit ("true color")
+//! ANSI escape codes, with `ASCII::render_terminal_color`.
 
 use crate::error::Result;
+use crate::sym::helpers;
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{format, string::String, vec::Vec};
 
 /// The ASCII barcode generator type.
 #[derive(Copy, Clone, Debug)]
@@ -17,11 +34,60 @@ pub struct ASCII {
     /// The X dimension. Specifies the width of the "narrow" bars.
     /// For ASCII, each will be ```self.xdim``` characters wide.
     pub xdim: usize,
+    /// The width (in multiples of the X dimension) of the empty space on either side of the bars.
+    pub quiet_zone: usize,
 }
 
+/// The quiet zone width (in multiples of the X dimension) used by `ASCII::new`.
+pub const DEFAULT_QUIET_ZONE: usize = 10;
+
 /// Maps binary digits to ASCII representation (0=' ', 1='#')
 const CHARS: [char; 2] = [' ', '#'];
 
+/// The ANSI escape code which resets the colors of the terminal.
+const RESET: &str = "\x1b[0m";
+
+/// The colors of a barcode rendered for a terminal by `ASCII::render_terminal_color`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TerminalColors {
+    /// The RGB color of the bars.
+    pub foreground: [u8; 3],
+    /// The RGB color of the spaces.
+    pub background: [u8; 3],
+    /// Whether to render plain ASCII instead, such as when the `NO_COLOR` environment variable
+    /// is set or the terminal does not support true color.
+    pub no_color: bool,
+}
+
+impl Default for TerminalColors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TerminalColors {
+    /// Returns new colors of black bars on a white background.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: [0, 0, 0],
+            background: [255, 255, 255],
+            no_color: false,
+        }
+    }
+
+    // The ANSI escape code which sets the background color of the terminal to the color of
+    // the given module.
+    fn escape(self, module: u8) -> String {
+        let [r, g, b] = match module {
+            0 => self.background,
+            _ => self.foreground,
+        };
+
+        format!("\x1b[48;2;{r};{g};{b}m")
+    }
+}
+
 impl Default for ASCII {
     fn default() -> Self {
         Self::new()
@@ -35,16 +101,49 @@ impl ASCII {
         Self {
             height: 10,
             xdim: 1,
+            quiet_zone: DEFAULT_QUIET_ZONE,
         }
     }
 
+    /// Set the quiet zone width (in multiples of the X dimension), or 0 to render the bars
+    /// edge-to-edge.
+    #[must_use]
+    pub const fn quiet_zone(mut self, modules: usize) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    // The modules of the barcode, surrounded by the empty modules of the quiet zone.
+    fn padded<'a>(&self, barcode: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+        let quiet = core::iter::repeat_n(0, self.quiet_zone);
+
+        quiet.clone().chain(barcode.iter().copied()).chain(quiet)
+    }
+
     fn generate_row(&self, barcode: &[u8]) -> String {
-        barcode
-            .iter()
-            .flat_map(|&d| std::iter::repeat_n(CHARS[d as usize], self.xdim))
+        self.padded(barcode)
+            .flat_map(|d| core::iter::repeat_n(CHARS[d as usize], self.xdim))
             .collect()
     }
 
+    // A row of colored cells, in which the color only changes at the edge of each bar.
+    fn generate_color_row(&self, barcode: &[u8], colors: TerminalColors) -> String {
+        let mut row = String::new();
+        let mut previous = None;
+
+        for d in self.padded(barcode) {
+            if previous != Some(d) {
+                row.push_str(&colors.escape(d));
+                previous = Some(d);
+            }
+
+            row.extend(core::iter::repeat_n(' ', self.xdim));
+        }
+
+        row.push_str(RESET);
+        row
+    }
+
     /// Generates the given barcode.
     ///
     /// Returns a `Result<String, Error>` indicating success.
@@ -52,9 +151,13 @@ impl ASCII {
     /// # Errors
     ///
     /// This function will return an error if the barcode data is invalid or cannot be processed.
+    /// Returns an `Error::Length` if the barcode is empty.
     pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
         let mut output = String::new();
-        let row = self.generate_row(barcode.as_ref());
+        let row = self.generate_row(barcode);
 
         for (i, _l) in (0..self.height).enumerate() {
             output.push_str(&row[..]);
@@ -66,6 +169,48 @@ impl ASCII {
 
         Ok(output)
     }
+
+    /// Generates a single row of the given barcode, without repeating it for the height or
+    /// adding any newline, for embedding in log lines, CSV fields and diffs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the barcode data is invalid or cannot be processed.
+    /// Returns an `Error::Length` if the barcode is empty.
+    pub fn generate_single_line<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        Ok(self.generate_row(barcode))
+    }
+
+    /// Generates the given barcode for a terminal, drawing each module as a cell with the
+    /// background color of the bars or spaces using ANSI true color escape codes.
+    ///
+    /// Each row resets the colors at its end. If `no_color` is set, this is the same as
+    /// `generate`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the barcode data is invalid or cannot be processed.
+    /// Returns an `Error::Length` if the barcode is empty.
+    pub fn render_terminal_color<T: AsRef<[u8]>>(
+        &self,
+        barcode: T,
+        colors: TerminalColors,
+    ) -> Result<String> {
+        if colors.no_color {
+            return self.generate(barcode);
+        }
+
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        let row = self.generate_color_row(barcode, colors);
+        let rows: Vec<&str> = core::iter::repeat_n(&row[..], self.height).collect();
+
+        Ok(rows.join("\n"))
+    }
 }
 
 #[cfg(test)]
@@ -76,15 +221,78 @@ mod tests {
     use crate::sym::code128::*;
     use crate::sym::code39::*;
     use crate::sym::code93::*;
+    use crate::sym::databar::*;
     use crate::sym::ean13::*;
     use crate::sym::ean8::*;
     use crate::sym::ean_supp::*;
+    use crate::sym::msi::*;
     use crate::sym::tf::*;
+    use crate::sym::upce::*;
+    use crate::sym::Barcode;
+
+    #[test]
+    fn non_binary_modules_as_ascii() {
+        let ascii = ASCII::new();
+
+        assert_eq!(
+            ascii
+                .generate([1, 0, 5, 1])
+                .expect_err("Expected an Error::Conversion but got None"),
+            crate::error::Error::Conversion
+        );
+    }
+
+    #[test]
+    fn ean_13_as_terminal_color() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let ascii = ASCII {
+            height: 2,
+            xdim: 1,
+            quiet_zone: 0,
+        };
+        let colors = TerminalColors {
+            foreground: [255, 0, 0],
+            ..TerminalColors::new()
+        };
+        let colored = ascii
+            .render_terminal_color(&ean13.encode()[..], colors)
+            .expect("Failed to generate terminal output");
+        let rows: Vec<&str> = colored.lines().collect();
+
+        assert_eq!(rows.len(), 2);
+        // The first module of the left guard is a bar, followed by a space.
+        assert!(rows[0].starts_with("\x1b[48;2;255;0;0m \x1b[48;2;255;255;255m \x1b[48;2;255;0;0m"));
+        assert!(rows[0].ends_with("\x1b[0m"));
+        assert_eq!(rows[0].matches(' ').count(), ean13.encode().len());
+
+        let plain = ascii
+            .render_terminal_color(
+                &ean13.encode()[..],
+                TerminalColors {
+                    no_color: true,
+                    ..colors
+                },
+            )
+            .expect("Failed to generate terminal output");
+
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(
+            plain,
+            ascii
+                .generate(&ean13.encode()[..])
+                .expect("Failed to generate ASCII")
+        );
+    }
+
+    #[test]
+    fn empty_modules_as_ascii() {
+        assert_eq!(ASCII::new().generate([]), Err(crate::error::Error::Length));
+    }
 
     #[test]
     fn ean_13_as_ascii() {
         let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&ean13.encode()[..])
             .expect("Failed to generate ASCII representation for EAN13 barcode");
@@ -107,10 +315,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_quiet_zone_ascii() {
+        let encoded = EAN13::new("750103131130")
+            .expect("Failed to create EAN13 barcode")
+            .encode();
+        let plain = ASCII::new()
+            .quiet_zone(0)
+            .generate(&encoded[..])
+            .expect("Failed to generate ASCII");
+        let padded = ASCII::new()
+            .generate(&encoded[..])
+            .expect("Failed to generate ASCII");
+        let wide = ASCII {
+            height: 2,
+            xdim: 2,
+            ..ASCII::new()
+        }
+        .generate(&encoded[..])
+        .expect("Failed to generate ASCII");
+
+        assert_eq!(padded.lines().count(), 10);
+
+        for (row, plain_row) in padded.lines().zip(plain.lines()) {
+            assert_eq!(row, format!("{0}{plain_row}{0}", " ".repeat(10)));
+        }
+
+        for row in wide.lines() {
+            assert_eq!(row.len(), (95 + 20) * 2);
+            assert!(row.starts_with(&format!("{}#", " ".repeat(20))));
+            assert!(row.ends_with(&format!("#{}", " ".repeat(20))));
+        }
+    }
+
+    #[test]
+    fn single_line_ascii() {
+        let encoded = EAN13::new("750103131130")
+            .expect("Failed to create EAN13 barcode")
+            .encode();
+        let ascii = ASCII {
+            height: 3,
+            xdim: 2,
+            ..ASCII::new()
+        };
+        let generated = ascii
+            .generate(&encoded[..])
+            .expect("Failed to generate ASCII");
+        let single = ascii
+            .generate_single_line(&encoded[..])
+            .expect("Failed to generate ASCII");
+
+        assert!(!single.contains('\n'));
+        assert_eq!(Some(&single[..]), generated.lines().next());
+        assert_eq!(single.len(), (95 + 20) * 2);
+        assert_eq!(
+            ASCII::new()
+                .quiet_zone(0)
+                .generate_single_line([1, 0, 1, 1]),
+            Ok(String::from("# ##"))
+        );
+        assert_eq!(
+            ascii.generate_single_line([1, 0, 5]),
+            Err(crate::error::Error::Conversion)
+        );
+    }
+
+    #[test]
+    fn to_ascii_matches_generate() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let code39 = Code39::new("1ISTHELONELIESTNUMBER").expect("Failed to create Code39 barcode");
+        let databar = DataBar14::new("2001234567890").expect("Failed to create DataBar barcode");
+        let upce = UPCE::new("0123456").expect("Failed to create UPCE barcode");
+        let msi = MSI::new("1234567", MsiCheck::Mod10).expect("Failed to create MSI barcode");
+        let ascii = ASCII {
+            height: 6,
+            xdim: 2,
+            ..ASCII::new()
+        };
+
+        assert_eq!(ean13.to_ascii(6, 2), ascii.generate(ean13.encode()));
+        assert_eq!(code39.to_ascii(6, 2), ascii.generate(code39.encode()));
+        assert_eq!(databar.to_ascii(6, 2), ascii.generate(databar.encode()));
+        assert_eq!(upce.to_ascii(6, 2), ascii.generate(upce.encode()));
+        assert_eq!(msi.to_ascii(6, 2), ascii.generate(msi.encode()));
+    }
+
     #[test]
     fn ean_13_as_ascii_small_height_double_width() {
         let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
-        let ascii = ASCII { height: 6, xdim: 2 };
+        let ascii = ASCII {
+            height: 6,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&ean13.encode()[..])
             .expect("Failed to generate ASCII representation for EAN13 barcode");
@@ -129,7 +426,7 @@ mod tests {
     #[test]
     fn ean_8_as_ascii() {
         let ean8 = EAN8::new("1234567").expect("Failed to create EAN8 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&ean8.encode()[..])
             .expect("Failed to generate ASCII representation for EAN8 barcode");
@@ -155,7 +452,11 @@ mod tests {
     #[test]
     fn ean_8_as_ascii_small_height_double_width() {
         let ean8 = EAN8::new("1234567").expect("Failed to create EAN8 barcode");
-        let ascii = ASCII { height: 5, xdim: 2 };
+        let ascii = ASCII {
+            height: 5,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&ean8.encode()[..])
             .expect("Failed to generate ASCII representation for EAN8 barcode");
@@ -173,7 +474,7 @@ mod tests {
     #[test]
     fn code_39_as_ascii() {
         let code39 = Code39::new("TEST8052").expect("Failed to create Code39 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&code39.encode()[..])
             .expect("Failed to generate ASCII representation for Code39 barcode");
@@ -196,7 +497,11 @@ mod tests {
     #[test]
     fn code_39_as_ascii_small_height_double_weight() {
         let code39 = Code39::new("1234").expect("Failed to create Code39 barcode");
-        let ascii = ASCII { height: 7, xdim: 2 };
+        let ascii = ASCII {
+            height: 7,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&code39.encode()[..])
             .expect("Failed to generate ASCII representation for Code39 barcode");
@@ -216,7 +521,7 @@ mod tests {
     #[test]
     fn codabar_as_ascii() {
         let codabar = Codabar::new("A98B").expect("Failed to create Codabar barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&codabar.encode()[..])
             .expect("Failed to generate ASCII representation for Codabar barcode");
@@ -242,7 +547,11 @@ mod tests {
     #[test]
     fn codabar_as_ascii_small_height_double_weight() {
         let codabar = Codabar::new("A40156B").expect("Failed to create Codabar barcode");
-        let ascii = ASCII { height: 7, xdim: 2 };
+        let ascii = ASCII {
+            height: 7,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&codabar.encode()[..])
             .expect("Failed to generate ASCII representation for Codabar barcode");
@@ -263,7 +572,7 @@ mod tests {
     fn code_128_as_ascii() {
         let code128 =
             Code128::new("HELLO", CharacterSet::A).expect("Failed to create Code128 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&code128.encode()[..])
             .expect("Failed to generate ASCII representation for Code128 barcode");
@@ -290,7 +599,11 @@ mod tests {
     fn code_128_as_ascii_small_height_double_weight() {
         let code128 = Code128::new("HELLO", CharacterSet::A)
             .expect("Failed to create Code128 barcode with CharacterSet::A");
-        let ascii = ASCII { height: 7, xdim: 2 };
+        let ascii = ASCII {
+            height: 7,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&code128.encode()[..])
             .expect("Failed to generate ASCII representation for Code128 barcode");
@@ -310,7 +623,7 @@ mod tests {
     #[test]
     fn ean2_as_ascii() {
         let ean2 = EANSUPP::new("34").expect("Failed to create EAN2 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&ean2.encode()[..])
             .expect("Failed to generate ASCII representation for EAN2 barcode");
@@ -336,7 +649,7 @@ mod tests {
     #[test]
     fn ean5_as_ascii() {
         let ean5 = EANSUPP::new("50799").expect("Failed to create EAN5 barcode");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&ean5.encode()[..])
             .expect("Failed to generate ASCII representation for EAN5 barcode");
@@ -363,7 +676,7 @@ mod tests {
     fn itf_as_ascii() {
         let itf = TF::interleaved("12345")
             .expect("Failed to create interleaved TF barcode with input '12345'");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&itf.encode()[..])
             .expect("Failed to generate ASCII representation for interleaved TF barcode");
@@ -390,7 +703,7 @@ mod tests {
     fn code_93_as_ascii() {
         let code93 =
             Code93::new("TEST93").expect("Failed to create Code93 barcode with input 'TEST93'");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&code93.encode()[..])
             .expect("Failed to generate ASCII representation for Code93 barcode");
@@ -417,7 +730,11 @@ mod tests {
     fn code_93_as_ascii_small_height_double_weight() {
         let code93 =
             Code93::new("TEST93").expect("Failed to create Code93 barcode with input 'TEST93'");
-        let ascii = ASCII { height: 7, xdim: 2 };
+        let ascii = ASCII {
+            height: 7,
+            xdim: 2,
+            quiet_zone: 0,
+        };
         let generated = ascii
             .generate(&code93.encode()[..])
             .expect("Failed to generate ASCII representation for Code93 barcode");
@@ -438,7 +755,7 @@ mod tests {
     fn code_11_as_ascii() {
         let code11 =
             Code11::new("12-9").expect("Failed to create Code11 barcode with input '12-9'");
-        let ascii = ASCII::new();
+        let ascii = ASCII::new().quiet_zone(0);
         let generated = ascii
             .generate(&code11.encode()[..])
             .expect("Failed to generate ASCII representation for Code11 barcode");
@@ -0,0 +1,360 @@
+//! A cache of encoded barcodes.
+//!
+//! Servers often render the same popular barcodes repeatedly. `BarcodeCache` maps a symbology
+//! and its input data to the encoded modules, so that identical requests skip re-encoding. The
+//! cache holds a limited number of entries, evicting the least recently used entry when full.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::generators::cache::*;
+//! use scanning::sym::Symbology;
+//!
+//! let mut cache = BarcodeCache::new(100);
+//! let encoded = cache.encode(Symbology::EAN13, "750103131130").unwrap().to_vec();
+//!
+//! assert_eq!(cache.get(Symbology::EAN13, "750103131130"), Some(&encoded[..]));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::sym::codabar::Codabar;
+use crate::sym::code11::Code11;
+use crate::sym::code128::{CharacterSet, Code128};
+use crate::sym::code39::Code39;
+use crate::sym::code93::Code93;
+use crate::sym::databar::DataBar14;
+use crate::sym::ean13::EAN13;
+use crate::sym::ean8::EAN8;
+use crate::sym::ean_supp::EANSUPP;
+use crate::sym::msi::{MsiCheck, MSI};
+use crate::sym::tf::TF;
+use crate::sym::upca::UPCA;
+use crate::sym::upce::UPCE;
+use crate::sym::Symbology;
+use std::collections::HashMap;
+
+/// A least-recently-used cache of encoded barcodes, keyed by symbology and input data.
+#[derive(Clone, Debug)]
+pub struct BarcodeCache {
+    capacity: usize,
+    // The index of the entry of each symbology and data, looked up without allocating a key.
+    index: HashMap<Symbology, HashMap<String, usize>>,
+    // The entries, linked in order of use so that eviction takes constant time. Once the cache
+    // is full, the slot of the least recently used entry is reused.
+    entries: Vec<Entry>,
+    // The most recently used entry.
+    head: Option<usize>,
+    // The least recently used entry.
+    tail: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    sym: Symbology,
+    data: String,
+    encoded: Vec<u8>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl BarcodeCache {
+    /// Returns a new, empty cache which holds at most `capacity` entries.
+    ///
+    /// A capacity of zero is raised to one, so that `BarcodeCache::encode` always has an entry
+    /// to return the encoding from.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            capacity,
+            index: HashMap::new(),
+            entries: Vec::with_capacity(capacity),
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// Returns the maximum number of entries held by the cache.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries in the cache.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the cached encoding of the given data, marking it as recently used.
+    pub fn get(&mut self, sym: Symbology, data: &str) -> Option<&[u8]> {
+        let i = self.position(sym, data)?;
+
+        self.touch(i);
+
+        Some(&self.entries[i].encoded[..])
+    }
+
+    /// Caches the encoding of the given data, evicting the least recently used entry if the
+    /// cache is full.
+    pub fn insert(&mut self, sym: Symbology, data: String, encoded: Vec<u8>) {
+        if let Some(i) = self.position(sym, &data) {
+            self.entries[i].encoded = encoded;
+            self.touch(i);
+            return;
+        }
+
+        let entry = Entry {
+            sym,
+            data: data.clone(),
+            encoded,
+            prev: None,
+            next: None,
+        };
+        let i = match self.tail {
+            Some(lru) if self.entries.len() >= self.capacity => {
+                self.unlink(lru);
+
+                let evicted = core::mem::replace(&mut self.entries[lru], entry);
+
+                if let Some(keys) = self.index.get_mut(&evicted.sym) {
+                    keys.remove(&evicted.data);
+                }
+
+                lru
+            }
+            _ => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+
+        self.push_front(i);
+        self.index.entry(sym).or_default().insert(data, i);
+    }
+
+    fn position(&self, sym: Symbology, data: &str) -> Option<usize> {
+        self.index.get(&sym)?.get(data).copied()
+    }
+
+    // Marks the entry as the most recently used.
+    fn touch(&mut self, i: usize) {
+        if self.head != Some(i) {
+            self.unlink(i);
+            self.push_front(i);
+        }
+    }
+
+    fn unlink(&mut self, i: usize) {
+        let Entry { prev, next, .. } = self.entries[i];
+
+        match prev {
+            Some(p) => self.entries[p].next = next,
+            None => self.head = next,
+        }
+
+        match next {
+            Some(n) => self.entries[n].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, i: usize) {
+        self.entries[i].prev = None;
+        self.entries[i].next = self.head;
+
+        match self.head {
+            Some(h) => self.entries[h].prev = Some(i),
+            None => self.tail = Some(i),
+        }
+
+        self.head = Some(i);
+    }
+
+    /// Returns the encoding of the given data, encoding and caching it on a cache miss.
+    ///
+    /// Data is encoded with the default constructor of each symbology. Code128 data must use
+    /// the Unicode syntax accepted by `Code128::new` with `CharacterSet::None`, ITF data is
+    /// padded with a check digit if required, and MSI data is given a modulo-10 check digit.
+    ///
+    /// # Errors
+    /// Returns the same errors as the constructor of the symbology.
+    /// Returns an `Error::Length` if the data of an EAN-2 or EAN-5 supplement has the wrong length.
+    pub fn encode(&mut self, sym: Symbology, data: &str) -> Result<&[u8]> {
+        if self.get(sym, data).is_none() {
+            let encoded = encode(sym, data)?;
+
+            self.insert(sym, data.to_string(), encoded);
+        }
+
+        self.get(sym, data).ok_or(Error::Generate)
+    }
+}
+
+fn encode(sym: Symbology, data: &str) -> Result<Vec<u8>> {
+    match sym {
+        Symbology::EAN13 => EAN13::new(data).map(|b| b.encode()),
+        Symbology::UPCA => UPCA::new(data).map(|b| b.encode()),
+        Symbology::UPCE => UPCE::new(data).map(|b| b.encode()),
+        Symbology::EAN8 => EAN8::new(data).map(|b| b.encode()),
+        Symbology::EAN2 | Symbology::EAN5 => match (sym, EANSUPP::new(data)?) {
+            (Symbology::EAN2, b @ EANSUPP::EAN2(..)) | (Symbology::EAN5, b @ EANSUPP::EAN5(..)) => {
+                Ok(b.encode())
+            }
+            _ => Err(Error::Length),
+        },
+        Symbology::Code11 => Code11::new(data).map(|b| b.encode()),
+        Symbology::Code39 => Code39::new(data).map(|b| b.encode()),
+        Symbology::Code93 => Code93::new(data).map(|b| b.encode()),
+        Symbology::Code128 => Code128::new(data, CharacterSet::None).map(|b| b.encode()),
+        Symbology::ITF => TF::interleaved(data).map(|b| b.encode()),
+        Symbology::ITF14 => TF::itf14(data).map(|b| b.encode()),
+        Symbology::STF => TF::standard(data).map(|b| b.encode()),
+        Symbology::Codabar => Codabar::new(data).map(|b| b.encode()),
+        Symbology::DataBar14 => DataBar14::new(data).map(|b| b.encode()),
+        Symbology::MSI => MSI::new(data, MsiCheck::Mod10).map(|b| b.encode()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::cache::*;
+
+    #[test]
+    fn cache_hit_returns_identical_vector() {
+        let mut cache = BarcodeCache::new(2);
+        let first = cache
+            .encode(Symbology::EAN13, "750103131130")
+            .expect("Failed to encode EAN13")
+            .as_ptr();
+        let second = cache
+            .encode(Symbology::EAN13, "750103131130")
+            .expect("Failed to encode EAN13");
+
+        assert_eq!(second.as_ptr(), first);
+        assert_eq!(
+            second,
+            &EAN13::new("750103131130")
+                .expect("Failed to create EAN13 barcode")
+                .encode()[..]
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn cache_encodes_each_symbology() {
+        let mut cache = BarcodeCache::new(8);
+
+        assert_eq!(
+            cache.encode(Symbology::DataBar14, "2001234567890"),
+            Ok(&DataBar14::new("2001234567890")
+                .expect("Failed to create DataBar barcode")
+                .encode()[..])
+        );
+        assert_eq!(
+            cache.encode(Symbology::ITF14, "1540014128876"),
+            Ok(&TF::itf14("1540014128876")
+                .expect("Failed to create ITF-14 barcode")
+                .encode()[..])
+        );
+        assert_eq!(
+            cache.encode(Symbology::MSI, "1234567"),
+            Ok(&MSI::new("1234567", MsiCheck::Mod10)
+                .expect("Failed to create MSI barcode")
+                .encode()[..])
+        );
+        assert_eq!(
+            cache.encode(Symbology::UPCE, "0123456"),
+            Ok(&UPCE::new("0123456")
+                .expect("Failed to create UPCE barcode")
+                .encode()[..])
+        );
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let mut cache = BarcodeCache::new(2);
+
+        cache.insert(Symbology::Code39, String::from("A"), vec![1]);
+        cache.insert(Symbology::Code39, String::from("B"), vec![1, 0]);
+        // Use "A" so that "B" becomes the least recently used entry.
+        assert_eq!(cache.get(Symbology::Code39, "A"), Some(&[1][..]));
+        cache.insert(Symbology::Code39, String::from("C"), vec![1, 0, 1]);
+
+        assert_eq!(cache.len(), cache.capacity());
+        assert_eq!(cache.get(Symbology::Code39, "B"), None);
+        assert_eq!(cache.get(Symbology::Code39, "A"), Some(&[1][..]));
+        assert_eq!(cache.get(Symbology::Code39, "C"), Some(&[1, 0, 1][..]));
+        // The same data in a different symbology is a different entry.
+        assert_eq!(cache.get(Symbology::Code93, "A"), None);
+    }
+
+    #[test]
+    fn cache_evicts_in_order_of_use() {
+        let mut cache = BarcodeCache::new(3);
+
+        for data in ["A", "B", "C"] {
+            cache.insert(Symbology::Code39, String::from(data), vec![1]);
+        }
+
+        // Re-inserting an entry replaces its encoding and marks it as used, without evicting.
+        cache.insert(Symbology::Code39, String::from("A"), vec![1, 1]);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(Symbology::Code39, "C"), Some(&[1][..]));
+
+        // "B" and then "A" are now the least recently used.
+        cache.insert(Symbology::Code39, String::from("D"), vec![1, 0]);
+        cache.insert(Symbology::Code39, String::from("E"), vec![1, 0, 0]);
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(Symbology::Code39, "A"), None);
+        assert_eq!(cache.get(Symbology::Code39, "B"), None);
+        assert_eq!(cache.get(Symbology::Code39, "C"), Some(&[1][..]));
+        assert_eq!(cache.get(Symbology::Code39, "D"), Some(&[1, 0][..]));
+        assert_eq!(cache.get(Symbology::Code39, "E"), Some(&[1, 0, 0][..]));
+
+        let mut single = BarcodeCache::new(1);
+
+        single.insert(Symbology::Code39, String::from("A"), vec![1]);
+        single.insert(Symbology::Code39, String::from("B"), vec![1, 0]);
+        assert_eq!(single.get(Symbology::Code39, "A"), None);
+        assert_eq!(single.get(Symbology::Code39, "B"), Some(&[1, 0][..]));
+        assert_eq!(single.len(), 1);
+    }
+
+    #[test]
+    fn cache_encode_errors() {
+        let mut cache = BarcodeCache::new(2);
+
+        assert_eq!(
+            cache.encode(Symbology::EAN13, "75010313113A"),
+            Err(Error::Character)
+        );
+        assert_eq!(cache.encode(Symbology::EAN2, "12345"), Err(Error::Length));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn cache_with_zero_capacity_holds_one_entry() {
+        let mut cache = BarcodeCache::new(0);
+        let encoded = EAN13::new("750103131130")
+            .expect("Failed to create EAN13")
+            .encode();
+
+        assert_eq!(cache.capacity(), 1);
+        assert_eq!(
+            cache.encode(Symbology::EAN13, "750103131130"),
+            Ok(&encoded[..])
+        );
+        assert!(cache.encode(Symbology::UPCA, "03600029145").is_ok());
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(Symbology::EAN13, "750103131130"), None);
+    }
+}
@@ -6,7 +6,7 @@
 //! For example:
 //!
 //! ```rust
-//! use barcoders::generators::image::*;
+//! use scanning::generators::image::*;
 //!
 //! // Specify your own struct fields.
 //! let png = Image::PNG{height: 80,
@@ -19,6 +19,14 @@
 //! let png = Image::png(100);
 //! ```
 //!
+//! PNG output is written with a bit depth of 1: as grayscale for black bars on a white
+//! background, and otherwise as a two-color palette (with transparency if either color has any).
+//! This keeps the files small and the bars crisp, as every pixel is either the foreground or the
+//! background color.
+//!
+//! PNG and WEBP output honor the alpha channel of the colors, so semi-transparent barcodes can
+//! be overlaid on other images.
+//!
 //! See the README for more examples.
 
 use crate::error::{Error, Result};
@@ -80,7 +88,7 @@ macro_rules! expand_image_variants {
 }
 
 /// Represents a RGBA color for the barcode foreground and background.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Color {
     /// Reg, Green, Blue, Alpha value.
     rgba: [u8; 4],
@@ -88,16 +96,31 @@ pub struct Color {
 
 impl Color {
     /// Constructor.
+    #[must_use]
     pub fn new(rgba: [u8; 4]) -> Color {
         Color { rgba }
     }
 
+    /// Constructor for an opaque color from its red, green and blue channels.
+    #[must_use]
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new([r, g, b, 255])
+    }
+
+    /// Constructor for a color from its red, green, blue and alpha channels.
+    #[must_use]
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new([r, g, b, a])
+    }
+
     /// Constructor for black (#000000).
+    #[must_use]
     pub fn black() -> Color {
         Color::new([0, 0, 0, 255])
     }
 
     /// Constructor for white (#FFFFFF).
+    #[must_use]
     pub fn white() -> Color {
         Color::new([255, 255, 255, 255])
     }
@@ -107,6 +130,9 @@ impl Color {
     }
 }
 
+/// The height used by `Image::default()`.
+pub const DEFAULT_HEIGHT: u32 = 100;
+
 /// Possible rotation values for images.
 #[derive(Copy, Clone, Debug)]
 pub enum Rotation {
@@ -131,23 +157,34 @@ image_variants![
     ImageBuffer
 ];
 
+impl Default for Image {
+    /// Returns a new PNG with default values and a height of `DEFAULT_HEIGHT`.
+    fn default() -> Self {
+        Self::png(DEFAULT_HEIGHT)
+    }
+}
+
 impl Image {
     /// Returns a new GIF with default values.
+    #[must_use]
     pub fn gif(height: u32) -> Image {
         image_defaults!(GIF, height)
     }
 
     /// Returns a new PNG with default values.
+    #[must_use]
     pub fn png(height: u32) -> Image {
         image_defaults!(PNG, height)
     }
 
     /// Returns a new WEBP with default values.
+    #[must_use]
     pub fn webp(height: u32) -> Image {
         image_defaults!(WEBP, height)
     }
 
     /// Returns a new ImageBuffer with default values.
+    #[must_use]
     pub fn image_buffer(height: u32) -> Image {
         image_defaults!(ImageBuffer, height)
     }
@@ -155,6 +192,26 @@ impl Image {
     /// Generates the given barcode. Returns a `Result<Vec<u8>, Error>` of the encoded bytes or
     /// an error message.
     pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<Vec<u8>> {
+        self.generate_with_dimensions(barcode)
+            .map(|(bytes, _, _)| bytes)
+    }
+
+    /// Generates the given barcode. Returns a `Result<(Vec<u8>, u32, u32), Error>` of the encoded
+    /// bytes along with the width and height of the image in pixels, or an error message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Generate` if the generator is an `ImageBuffer` or the image cannot be
+    /// encoded.
+    pub fn generate_with_dimensions<T: AsRef<[u8]>>(
+        &self,
+        barcode: T,
+    ) -> Result<(Vec<u8>, u32, u32)> {
+        if barcode.as_ref().is_empty() {
+            return Err(Error::Length);
+        }
+
         let format = match *self {
             Image::GIF { .. } => ImageFormat::Gif,
             Image::PNG { .. } => ImageFormat::Png,
@@ -164,9 +221,14 @@ impl Image {
 
         let mut bytes: Vec<u8> = vec![];
         let img = self.place_pixels(&barcode);
+        let (width, height) = (img.width(), img.height());
+
+        if format == ImageFormat::Png {
+            return self.encode_png(&img).map(|bytes| (bytes, width, height));
+        }
 
         match img.write_to(&mut Cursor::new(&mut bytes), format) {
-            Ok(_) => Ok(bytes),
+            Ok(_) => Ok((bytes, width, height)),
             _ => Err(Error::Generate),
         }
     }
@@ -177,11 +239,61 @@ impl Image {
         self,
         barcode: T,
     ) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>> {
+        if barcode.as_ref().is_empty() {
+            return Err(Error::Length);
+        }
+
         let img = self.place_pixels(&barcode);
 
         Ok(img.to_rgba8())
     }
 
+    // Encodes the image as a PNG with a bit depth of 1, which the `image` crate cannot write:
+    // grayscale for black on white, and otherwise a palette of the background and foreground.
+    fn encode_png(&self, img: &DynamicImage) -> Result<Vec<u8>> {
+        let (bg, fg) = expand_image_variants!(
+            *self,
+            {background: b, foreground: f, ..} => (b.rgba, f.rgba),
+            GIF, PNG, WEBP, ImageBuffer
+        );
+        let grayscale = fg == [0, 0, 0, 255] && bg == [255, 255, 255, 255];
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        let row_len = width.div_ceil(8) as usize;
+        let mut data = vec![0; row_len * height as usize];
+
+        for (x, y, pixel) in rgba.enumerate_pixels() {
+            // A set bit is white in grayscale, i.e. the background, but the foreground in the
+            // palette.
+            if (pixel.0 == fg) != grayscale {
+                data[(y as usize * row_len) + (x as usize / 8)] |= 0x80 >> (x % 8);
+            }
+        }
+
+        let mut bytes: Vec<u8> = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+
+        encoder.set_depth(png::BitDepth::One);
+
+        if grayscale {
+            encoder.set_color(png::ColorType::Grayscale);
+        } else {
+            encoder.set_color(png::ColorType::Indexed);
+            encoder.set_palette(vec![bg[0], bg[1], bg[2], fg[0], fg[1], fg[2]]);
+
+            if bg[3] < 255 || fg[3] < 255 {
+                encoder.set_trns(vec![bg[3], fg[3]]);
+            }
+        }
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .map_err(|_| Error::Generate)?;
+
+        Ok(bytes)
+    }
+
     fn place_pixels<T: AsRef<[u8]>>(&self, barcode: T) -> DynamicImage {
         let barcode = barcode.as_ref();
         let (xdim, height, rotation, bg, fg) = expand_image_variants!(
@@ -245,6 +357,58 @@ mod tests {
         writer.write(bytes).unwrap();
     }
 
+    // Decodes a generated PNG and checks its size and every pixel against the modules, as the
+    // length of the compressed bytes depends on the encoder.
+    fn assert_png(generated: &[u8], png: &Image, modules: &[u8]) {
+        let (height, xdim, rotation, background, foreground) = match *png {
+            Image::PNG {
+                height,
+                xdim,
+                rotation,
+                background,
+                foreground,
+            } => (height, xdim, rotation, background.rgba, foreground.rgba),
+            _ => panic!("Expected a PNG generator"),
+        };
+        let decoded = image::load_from_memory_with_format(generated, ImageFormat::Png)
+            .unwrap()
+            .to_rgba8();
+        let width = modules.len() as u32 * xdim;
+
+        match rotation {
+            Rotation::Ninety | Rotation::TwoSeventy => {
+                assert_eq!(decoded.dimensions(), (height, width));
+            }
+            _ => assert_eq!(decoded.dimensions(), (width, height)),
+        }
+
+        for (x, y, pixel) in decoded.enumerate_pixels() {
+            // The column of the unrotated barcode which the pixel belongs to.
+            let column = match rotation {
+                Rotation::Zero => x,
+                Rotation::Ninety => y,
+                Rotation::OneEighty => width - 1 - x,
+                Rotation::TwoSeventy => width - 1 - y,
+            };
+            let expected = if modules[(column / xdim) as usize] == 0 {
+                background
+            } else {
+                foreground
+            };
+
+            assert_eq!(pixel.0, expected, "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn empty_modules_as_image() {
+        let empty: [u8; 0] = [];
+
+        assert_eq!(Image::png(80).generate(empty), Err(Error::Length));
+        assert_eq!(Image::gif(80).generate(empty), Err(Error::Length));
+        assert!(Image::image_buffer(80).generate_buffer(empty).is_err());
+    }
+
     #[test]
     fn ean_13_as_gif() {
         let ean13 = EAN13::new("750103131130").unwrap();
@@ -278,7 +442,7 @@ mod tests {
             write_file(&generated[..], "ean13.png");
         }
 
-        assert_eq!(generated.len(), 872);
+        assert_png(&generated, &png, &ean13.encode());
     }
 
     #[test]
@@ -301,7 +465,30 @@ mod tests {
             write_file(&generated[..], "ean13_90.png");
         }
 
-        assert_eq!(generated.len(), 716);
+        assert_png(&generated, &png, &ean13.encode());
+    }
+
+    #[test]
+    fn rotated_ean_13_as_png_with_dimensions() {
+        let ean13 = EAN13::new("750103131130").unwrap();
+        let png = Image::PNG {
+            height: 100,
+            xdim: 2,
+            rotation: Rotation::Ninety,
+            foreground: Color {
+                rgba: [0, 0, 0, 255],
+            },
+            background: Color {
+                rgba: [255, 255, 255, 255],
+            },
+        };
+        let (generated, width, height) = png.generate_with_dimensions(&ean13.encode()[..]).unwrap();
+        let decoded = image::load_from_memory(&generated[..]).unwrap();
+
+        assert_eq!(width, 100);
+        assert_eq!(height, 190);
+        assert_eq!(decoded.width(), width);
+        assert_eq!(decoded.height(), height);
     }
 
     #[test]
@@ -392,7 +579,101 @@ mod tests {
             write_file(&generated[..], "colored_opaque_ean13.png");
         }
 
-        assert_eq!(generated.len(), 1027);
+        assert_png(&generated, &png, &ean13.encode());
+    }
+
+    #[test]
+    fn semi_transparent_ean_13_as_png_and_webp() {
+        let ean13 = EAN13::new("750103131130").unwrap();
+        let modules = ean13.encode();
+        let foreground = Color::rgba(255, 38, 42, 128);
+        let background = Color::rgba(255, 255, 255, 0);
+        let png = Image::PNG {
+            height: 10,
+            xdim: 2,
+            rotation: Rotation::Zero,
+            foreground,
+            background,
+        };
+        let webp = Image::WEBP {
+            height: 10,
+            xdim: 2,
+            rotation: Rotation::Zero,
+            foreground,
+            background,
+        };
+
+        for generated in [png.generate(&modules[..]), webp.generate(&modules[..])] {
+            let decoded = image::load_from_memory(&generated.unwrap()[..])
+                .unwrap()
+                .to_rgba8();
+
+            for (i, &m) in modules.iter().enumerate() {
+                let pixel = decoded.get_pixel((i as u32) * 2 + 1, 5);
+
+                if m == 1 {
+                    assert_eq!(pixel.0, [255, 38, 42, 128]);
+                } else {
+                    assert_eq!(pixel.0[3], 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_image() {
+        match Image::default() {
+            Image::PNG { height, xdim, .. } => {
+                assert_eq!(height, DEFAULT_HEIGHT);
+                assert_eq!(xdim, 1);
+            }
+            _ => panic!("Expected a PNG"),
+        }
+    }
+
+    #[test]
+    fn color_from_channels() {
+        assert_eq!(Color::rgb(1, 2, 3), Color::new([1, 2, 3, 255]));
+        assert_eq!(Color::rgba(1, 2, 3, 4), Color::new([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn png_uses_one_bit_depth() {
+        let ean13 = EAN13::new("750153666132").unwrap();
+        let header = |foreground, background| {
+            let png = Image::PNG {
+                height: 10,
+                xdim: 3,
+                rotation: Rotation::Ninety,
+                foreground: Color::new(foreground),
+                background: Color::new(background),
+            };
+            let generated = png.generate(&ean13.encode()[..]).unwrap();
+            let reader = png::Decoder::new(Cursor::new(&generated[..]))
+                .read_info()
+                .unwrap();
+            let info = reader.info();
+
+            assert_png(&generated, &png, &ean13.encode());
+            (info.color_type, info.bit_depth, info.trns.is_some())
+        };
+
+        assert_eq!(
+            header([0, 0, 0, 255], [255, 255, 255, 255]),
+            (png::ColorType::Grayscale, png::BitDepth::One, false)
+        );
+        assert_eq!(
+            header([0, 0, 0, 255], [255, 255, 255, 0]),
+            (png::ColorType::Indexed, png::BitDepth::One, true)
+        );
+        assert_eq!(
+            header([255, 38, 42, 255], [34, 52, 255, 255]),
+            (png::ColorType::Indexed, png::BitDepth::One, false)
+        );
+        assert_eq!(
+            header([255, 38, 42, 120], [34, 52, 255, 120]),
+            (png::ColorType::Indexed, png::BitDepth::One, true)
+        );
     }
 
     #[test]
@@ -415,7 +696,7 @@ mod tests {
             write_file(&generated[..], "code39.png");
         }
 
-        assert_eq!(generated.len(), 716);
+        assert_png(&generated, &png, &code39.encode());
     }
 
     #[test]
@@ -484,7 +765,7 @@ mod tests {
             write_file(&generated[..], "code93.png");
         }
 
-        assert_eq!(generated.len(), 681);
+        assert_png(&generated, &png, &code93.encode());
     }
 
     #[test]
@@ -553,7 +834,7 @@ mod tests {
             write_file(&generated[..], "code11.png");
         }
 
-        assert_eq!(generated.len(), 602);
+        assert_png(&generated, &png, &code11.encode());
     }
 
     #[test]
@@ -599,7 +880,7 @@ mod tests {
             write_file(&generated[..], "codabar.png");
         }
 
-        assert_eq!(generated.len(), 681);
+        assert_png(&generated, &png, &codabar.encode());
     }
 
     #[test]
@@ -650,7 +931,7 @@ mod tests {
 
     #[test]
     fn code128_as_png() {
-        let code128 = Code128::new("ÀHIĆ345678").unwrap();
+        let code128 = Code128::new("ÀHIĆ345678", CharacterSet::None).unwrap();
         let png = Image::PNG {
             height: 60,
             xdim: 1,
@@ -668,12 +949,12 @@ mod tests {
             write_file(&generated[..], "code128.png");
         }
 
-        assert_eq!(generated.len(), 659);
+        assert_png(&generated, &png, &code128.encode());
     }
 
     #[test]
     fn code128_as_gif() {
-        let code128 = Code128::new("ÀHELLOWORLD").unwrap();
+        let code128 = Code128::new("ÀHELLOWORLD", CharacterSet::None).unwrap();
         let gif = Image::GIF {
             height: 90,
             xdim: 3,
@@ -696,7 +977,7 @@ mod tests {
 
     #[test]
     fn rotated_code128_as_gif() {
-        let code128 = Code128::new("ÀHELLOWORLD").unwrap();
+        let code128 = Code128::new("ÀHELLOWORLD", CharacterSet::None).unwrap();
         let gif = Image::GIF {
             height: 90,
             xdim: 3,
@@ -719,7 +1000,7 @@ mod tests {
 
     #[test]
     fn rotated_code128_as_image_buffer() {
-        let code128 = Code128::new("ƁCLOJURE").unwrap();
+        let code128 = Code128::new("ƁCLOJURE", CharacterSet::None).unwrap();
         let img = Image::ImageBuffer {
             height: 93,
             xdim: 2,
@@ -757,7 +1038,7 @@ mod tests {
             write_file(&generated[..], "ean8.png");
         }
 
-        assert_eq!(generated.len(), 692);
+        assert_png(&generated, &png, &ean8.encode());
     }
 
     #[test]
@@ -780,7 +1061,7 @@ mod tests {
             write_file(&generated[..], "ean8_270.png");
         }
 
-        assert_eq!(generated.len(), 808);
+        assert_png(&generated, &png, &ean8.encode());
     }
 
     #[test]
@@ -849,7 +1130,7 @@ mod tests {
             write_file(&generated[..], "ean2.png");
         }
 
-        assert_eq!(generated.len(), 485);
+        assert_png(&generated, &png, &ean2.encode());
     }
 
     #[test]
@@ -938,7 +1219,7 @@ mod tests {
             write_file(&generated[..], "ift.png");
         }
 
-        assert_eq!(generated.len(), 911);
+        assert_png(&generated, &png, &itf.encode());
     }
 
     #[test]
@@ -961,7 +1242,7 @@ mod tests {
             write_file(&generated[..], "sft.png");
         }
 
-        assert_eq!(generated.len(), 1131);
+        assert_png(&generated, &png, &stf.encode());
     }
 
     #[test]
@@ -10,10 +10,21 @@
 //!   "encoding": [1, 0, 0, 1, 1, 0, ...],
 //! }
 //! ```
+//!
+//! The output is minified by default. Set `pretty` to produce indented, newline-separated
+//! output for human inspection.
+//!
+//! Modules must be `0` or `1` by default, and any other value is rejected. Set `raw_values` to
+//! emit the module values exactly as given instead, e.g. for multi-level symbologies.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::sym::helpers;
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 /// The JSON  barcode generator type.
 #[derive(Copy, Clone, Debug)]
@@ -22,6 +33,10 @@ pub struct JSON {
     pub height: usize,
     /// The X dimension. Specifies the width of the "narrow" bars.
     pub xdim: usize,
+    /// Whether to produce indented, newline-separated output instead of minified output.
+    pub pretty: bool,
+    /// Whether to emit the module values as given, instead of requiring them to be 0 or 1.
+    pub raw_values: bool,
 }
 
 impl Default for JSON {
@@ -37,6 +52,8 @@ impl JSON {
         Self {
             height: 10,
             xdim: 1,
+            pretty: false,
+            raw_values: false,
         }
     }
 
@@ -48,26 +65,61 @@ impl JSON {
     ///
     /// This function will return an error if the barcode data cannot be processed
     /// into a valid JSON representation.
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Conversion` if the barcode contains non-binary modules, unless
+    /// `raw_values` is set.
     pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
-        let mut bits = barcode.as_ref().iter().fold(String::new(), |acc, &b| {
-            let n = match b {
-                0 => "0",
-                _ => "1",
-            };
+        let barcode = barcode.as_ref();
+
+        if self.raw_values {
+            if barcode.is_empty() {
+                return Err(Error::Length);
+            }
+        } else {
+            helpers::validate_modules(barcode)?;
+        }
 
-            acc + n + ","
+        let mut bits = barcode.iter().fold(String::new(), |mut acc, &b| {
+            match b {
+                0 => acc.push('0'),
+                1 => acc.push('1'),
+                // Other values are only accepted with `raw_values`.
+                b => acc.push_str(&b.to_string()),
+            }
+
+            acc.push(',');
+            acc
         });
 
         // Kill trailing comma.
         bits.pop();
 
-        let output = format!(
-            "{{\"height\":{},\"xdim\":{},\"encoding\":[{}]}}",
-            self.height, self.xdim, bits
-        );
+        let output = if self.pretty {
+            format!(
+                "{{\n  \"height\": {},\n  \"xdim\": {},\n  \"encoding\": [{}]\n}}",
+                self.height, self.xdim, bits
+            )
+        } else {
+            format!(
+                "{{\"height\":{},\"xdim\":{},\"encoding\":[{}]}}",
+                self.height, self.xdim, bits
+            )
+        };
 
         Ok(output)
     }
+
+    /// Generates the given barcode as UTF-8 encoded bytes.
+    ///
+    /// This is useful for writing directly to byte-oriented sinks.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the barcode data cannot be processed
+    /// into a valid JSON representation.
+    pub fn generate_bytes<T: AsRef<[u8]>>(&self, barcode: T) -> Result<Vec<u8>> {
+        self.generate(barcode).map(String::into_bytes)
+    }
 }
 
 #[cfg(test)]
@@ -94,10 +146,35 @@ mod tests {
         assert_eq!(generated, "{\"height\":10,\"xdim\":1,\"encoding\":[1,0,1,0,1,1,0,0,0,1,0,1,0,0,1,1,1,0,0,1,1,0,0,1,0,1,0,0,1,1,1,0,1,1,1,1,0,1,0,1,1,0,0,1,1,0,1,0,1,0,1,0,0,0,0,1,0,1,1,0,0,1,1,0,1,1,0,0,1,1,0,1,0,0,0,0,1,0,1,1,1,0,0,1,0,1,1,1,0,1,0,0,1,0,1]}".trim());
     }
 
+    #[test]
+    fn empty_modules_as_json() {
+        assert_eq!(JSON::new().generate([]), Err(Error::Length));
+    }
+
+    #[test]
+    fn ean_13_as_json_bytes() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let json = JSON::new();
+        let generated = json
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate JSON");
+        let bytes = json
+            .generate_bytes(&ean13.encode()[..])
+            .expect("Failed to generate JSON");
+
+        assert_eq!(bytes, generated.as_bytes());
+        assert!(json.generate_bytes(&[1, 0, 2][..]).is_err());
+    }
+
     #[test]
     fn ean_13_as_json_small_height_double_width() {
         let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
-        let json = JSON { height: 6, xdim: 2 };
+        let json = JSON {
+            height: 6,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&ean13.encode()[..])
             .expect("Failed to generate JSON");
@@ -119,7 +196,12 @@ mod tests {
     #[test]
     fn ean_8_as_json_small_height_double_width() {
         let ean8 = EAN8::new("1234567").expect("Failed to create EAN8 barcode");
-        let json = JSON { height: 5, xdim: 2 };
+        let json = JSON {
+            height: 5,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&ean8.encode()[..])
             .expect("Failed to generate JSON");
@@ -141,7 +223,12 @@ mod tests {
     #[test]
     fn code_93_as_json_small_height_double_weight() {
         let code93 = Code93::new("1234").expect("Failed to create Code93 barcode");
-        let json = JSON { height: 7, xdim: 2 };
+        let json = JSON {
+            height: 7,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&code93.encode()[..])
             .expect("Failed to generate JSON");
@@ -163,7 +250,12 @@ mod tests {
     #[test]
     fn code_39_as_json_small_height_double_weight() {
         let code39 = Code39::new("1234").expect("Failed to create Code39 barcode");
-        let json = JSON { height: 7, xdim: 2 };
+        let json = JSON {
+            height: 7,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&code39.encode()[..])
             .expect("Failed to generate JSON");
@@ -185,7 +277,12 @@ mod tests {
     #[test]
     fn codabar_as_json_small_height_double_weight() {
         let codabar = Codabar::new("A40156B").expect("Failed to create Codabar barcode");
-        let json = JSON { height: 7, xdim: 2 };
+        let json = JSON {
+            height: 7,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&codabar.encode()[..])
             .expect("Failed to generate JSON");
@@ -209,7 +306,12 @@ mod tests {
     fn code_128_as_json_small_height_double_weight() {
         let code128 =
             Code128::new("HELLO", CharacterSet::A).expect("Failed to create Code128 barcode");
-        let json = JSON { height: 7, xdim: 2 };
+        let json = JSON {
+            height: 7,
+            xdim: 2,
+            pretty: false,
+            raw_values: false,
+        };
         let generated = json
             .generate(&code128.encode()[..])
             .expect("Failed to generate JSON");
@@ -264,4 +366,42 @@ mod tests {
 
         assert_eq!(generated, "{\"height\":10,\"xdim\":1,\"encoding\":[1,0,1,1,0,0,1,0,1,1,0,1,0,1,1,0,1,1,0,1,0,1,1,0,1,1,0,1,0,1,1,0,1,0,1,1,0,1,0,1,1,0,1,0,1,0,1,1,0,1,0,1,0,1,1,0,1,0,1,0,1,0,1,1,0,1,0,1,1,0,1,0,0,1,0,1,0,1,0,1,1,0,1,0,1,1,0,0,1]}".trim());
     }
+
+    #[test]
+    fn raw_values_as_json() {
+        let modules = [1, 0, 2, 1];
+        let raw = JSON {
+            raw_values: true,
+            ..JSON::new()
+        };
+
+        assert_eq!(
+            raw.generate(modules),
+            Ok(String::from(
+                "{\"height\":10,\"xdim\":1,\"encoding\":[1,0,2,1]}"
+            ))
+        );
+        assert_eq!(raw.generate([]), Err(Error::Length));
+        // Without raw values, non-binary modules are rejected.
+        assert_eq!(JSON::new().generate(modules), Err(Error::Conversion));
+    }
+
+    #[test]
+    fn ean_13_as_pretty_json() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let minified = JSON::new()
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate JSON");
+        let pretty = JSON {
+            pretty: true,
+            ..JSON::new()
+        }
+        .generate(&ean13.encode()[..])
+        .expect("Failed to generate JSON");
+        let collapsed: String = pretty.chars().filter(|c| !c.is_whitespace()).collect();
+
+        assert!(pretty.starts_with("{\n  \"height\": 10,\n  \"xdim\": 1,\n  \"encoding\": [1,0,1,"));
+        assert!(pretty.ends_with("]\n}"));
+        assert_eq!(collapsed, minified);
+    }
 }
@@ -0,0 +1,208 @@
+//! Functionality for generating PBM (portable bitmap) representations of barcodes.
+//!
+//! PBM is the simplest raster image format, and needs no third-party dependencies, so it is a
+//! lightweight alternative to the `image` feature for targets such as embedded devices. Most
+//! image tools can read and convert PBM images.
+//!
+//! Both variants of the format are supported: the binary ("raw", P4) format with `PBM::generate`
+//! and the ASCII ("plain", P1) format with `PBM::generate_plain`. In both, a bar is a black pixel.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::generators::pbm::*;
+//! use scanning::sym::ean13::*;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let pbm = PBM::new(80).xdim(2);
+//! let bytes = pbm.generate(barcode.encode()).unwrap();
+//!
+//! assert!(bytes.starts_with(b"P4\n190 80\n"));
+//! ```
+
+use crate::error::Result;
+use crate::sym::helpers;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// The maximum length of a line of a plain PBM image.
+const PLAIN_LINE_LEN: usize = 70;
+
+/// The PBM barcode generator type.
+#[derive(Copy, Clone, Debug)]
+pub struct PBM {
+    /// The height of the barcode (```self.height``` pixels high).
+    pub height: usize,
+    /// The X dimension. Specifies the width of the "narrow" bars, each of which will be
+    /// ```self.xdim``` pixels wide.
+    pub xdim: usize,
+}
+
+/// The height used by `PBM::default()`.
+pub const DEFAULT_HEIGHT: usize = 100;
+
+impl Default for PBM {
+    /// Returns a new PBM with default values and a height of `DEFAULT_HEIGHT`.
+    fn default() -> Self {
+        Self::new(DEFAULT_HEIGHT)
+    }
+}
+
+impl PBM {
+    /// Returns a new PBM with default values.
+    #[must_use]
+    pub const fn new(height: usize) -> Self {
+        Self { height, xdim: 1 }
+    }
+
+    /// Set the x dimensional bar width
+    #[must_use]
+    pub const fn xdim(mut self, xdim: usize) -> Self {
+        self.xdim = xdim;
+        self
+    }
+
+    // The pixels of a single row of the image, 1 for black and 0 for white.
+    fn pixels<'a>(&self, barcode: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+        let xdim = self.xdim;
+
+        barcode
+            .iter()
+            .flat_map(move |&m| core::iter::repeat_n(m, xdim))
+    }
+
+    fn header(&self, magic: &str, barcode: &[u8]) -> String {
+        format!("{magic}\n{} {}\n", barcode.len() * self.xdim, self.height)
+    }
+
+    /// Generates the given barcode as a binary (P4) PBM image.
+    ///
+    /// Each row is packed eight pixels to a byte, most significant bit first, and padded to a
+    /// whole number of bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Conversion` if the barcode contains non-binary modules.
+    pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<Vec<u8>> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        let pixels: Vec<u8> = self.pixels(barcode).collect();
+        let row = helpers::pack_bits(&pixels);
+        let mut image = self.header("P4", barcode).into_bytes();
+
+        image.reserve(row.len() * self.height);
+
+        for _ in 0..self.height {
+            image.extend_from_slice(&row);
+        }
+
+        Ok(image)
+    }
+
+    /// Generates the given barcode as a plain (P1) PBM image.
+    ///
+    /// Each pixel is written as a `0` or `1` character, and each row is written on its own
+    /// lines, wrapped at 70 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Conversion` if the barcode contains non-binary modules.
+    pub fn generate_plain<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        let pixels: Vec<u8> = self.pixels(barcode).collect();
+        let mut row = String::with_capacity(pixels.len() + (pixels.len() / PLAIN_LINE_LEN) + 1);
+
+        for line in pixels.chunks(PLAIN_LINE_LEN) {
+            row.extend(line.iter().map(|&p| if p == 0 { '0' } else { '1' }));
+            row.push('\n');
+        }
+
+        let mut image = self.header("P1", barcode);
+
+        image.reserve(row.len() * self.height);
+
+        for _ in 0..self.height {
+            image.push_str(&row);
+        }
+
+        Ok(image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::generators::pbm::*;
+    use crate::sym::ean13::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    #[test]
+    fn ean_13_as_pbm() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let modules = ean13.encode();
+        let pbm = PBM::new(80).xdim(2);
+        let generated = pbm.generate(&modules).expect("Failed to generate PBM");
+        let header = b"P4\n190 80\n";
+        // 190 pixels are packed into 24 bytes per row.
+        let row = &generated[header.len()..header.len() + 24];
+
+        assert!(generated.starts_with(header));
+        assert_eq!(generated.len(), header.len() + (24 * 80));
+        assert_eq!(&row[..2], &[0b1100_1100, 0b1111_0000]);
+        // The last 2 bits of each row are padding.
+        assert_eq!(row[23], 0b1100_1100);
+        assert!(generated[header.len()..].chunks(24).all(|r| r == row));
+    }
+
+    #[test]
+    fn ean_13_as_plain_pbm() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let modules = ean13.encode();
+        let generated = PBM::new(3)
+            .generate_plain(&modules)
+            .expect("Failed to generate PBM");
+        let row: String = modules
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &m)| {
+                let newline = if i == 69 { Some('\n') } else { None };
+
+                core::iter::once(if m == 0 { '0' } else { '1' }).chain(newline)
+            })
+            .collect();
+
+        assert_eq!(generated, format!("P1\n95 3\n{row}\n{row}\n{row}\n"));
+    }
+
+    #[test]
+    fn small_pbm() {
+        let pbm = PBM::new(2).xdim(3);
+
+        assert_eq!(
+            pbm.generate([1, 0, 1]).expect("Failed to generate PBM"),
+            b"P4\n9 2\n\xe3\x80\xe3\x80".to_vec()
+        );
+        assert_eq!(
+            pbm.generate_plain([1, 0, 1])
+                .expect("Failed to generate PBM"),
+            "P1\n9 2\n111000111\n111000111\n"
+        );
+    }
+
+    #[test]
+    fn invalid_modules_as_pbm() {
+        let pbm = PBM::default();
+        let empty: [u8; 0] = [];
+
+        assert_eq!(pbm.generate(empty), Err(Error::Length));
+        assert_eq!(pbm.generate_plain(empty), Err(Error::Length));
+        assert_eq!(pbm.generate(vec![1, 0, 5]), Err(Error::Conversion));
+        assert_eq!(pbm.generate_plain([1, 0, 5]), Err(Error::Conversion));
+    }
+}
@@ -0,0 +1,111 @@
+//! Format-agnostic rendering of barcodes.
+//!
+//! `render` produces a PNG when the `image` feature is compiled in, and otherwise falls back to
+//! an SVG. The result is tagged with the format that was actually produced, so that libraries
+//! built on top can always ask for "an image of the barcode" without needing to know which
+//! generators are available.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::generators::render::*;
+//! use scanning::sym::ean13::*;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let rendered = render(&barcode.encode()[..], 80).unwrap();
+//!
+//! match rendered {
+//!     Rendered::Png(bytes) => assert!(!bytes.is_empty()),
+//!     Rendered::Svg(svg) => assert!(svg.starts_with("<svg")),
+//! }
+//! ```
+
+use crate::error::Result;
+#[cfg(all(feature = "image", feature = "std"))]
+use crate::generators::image::Image;
+#[cfg(not(all(feature = "image", feature = "std")))]
+use crate::generators::svg::SVG;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// A rendered barcode, tagged with the format it was rendered in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rendered {
+    /// PNG image data, produced when the `image` feature is enabled.
+    Png(Vec<u8>),
+    /// SVG data, produced when the `image` feature is disabled.
+    Svg(String),
+}
+
+impl Rendered {
+    /// Returns the MIME type of the rendered data.
+    #[must_use]
+    pub const fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png(_) => "image/png",
+            Self::Svg(_) => "image/svg+xml",
+        }
+    }
+
+    /// Returns the rendered data as raw bytes.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Png(bytes) => bytes,
+            Self::Svg(svg) => svg.as_bytes(),
+        }
+    }
+}
+
+/// Renders the given barcode with the given height, using the best available format.
+///
+/// This is a PNG when the `image` feature is enabled, and an SVG otherwise. Both use the
+/// default settings of their respective generators.
+///
+/// # Errors
+///
+/// Returns an error if the underlying generator fails to render the barcode.
+#[cfg(all(feature = "image", feature = "std"))]
+pub fn render<T: AsRef<[u8]>>(barcode: T, height: u32) -> Result<Rendered> {
+    Image::png(height).generate(barcode).map(Rendered::Png)
+}
+
+/// Renders the given barcode with the given height, using the best available format.
+///
+/// This is a PNG when the `image` feature is enabled, and an SVG otherwise. Both use the
+/// default settings of their respective generators.
+///
+/// # Errors
+///
+/// Returns an error if the underlying generator fails to render the barcode.
+#[cfg(not(all(feature = "image", feature = "std")))]
+pub fn render<T: AsRef<[u8]>>(barcode: T, height: u32) -> Result<Rendered> {
+    SVG::new(height).generate(barcode).map(Rendered::Svg)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::render::*;
+    use crate::sym::ean13::*;
+
+    #[cfg(all(feature = "image", feature = "std"))]
+    #[test]
+    fn ean_13_renders_as_png() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let rendered = render(&ean13.encode()[..], 80).expect("Failed to render barcode");
+
+        assert_eq!(rendered.mime_type(), "image/png");
+        assert!(rendered.as_bytes().starts_with(b"\x89PNG"));
+    }
+
+    #[cfg(not(all(feature = "image", feature = "std")))]
+    #[test]
+    fn ean_13_renders_as_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let rendered = render(&ean13.encode()[..], 80).expect("Failed to render barcode");
+
+        assert_eq!(rendered.mime_type(), "image/svg+xml");
+        assert!(rendered.as_bytes().starts_with(b"<svg"));
+        assert!(render(&[1, 0, 5][..], 80).is_err());
+    }
+}
@@ -13,22 +13,47 @@
 //!               xdim: 1,
 //!               background: Color{rgba: [255, 0, 0, 255]},
 //!               foreground: Color::black(),
-//!               xmlns: Some(String::from("http://www.w3.org/2000/svg"))};
+//!               xmlns: Some(String::from("http://www.w3.org/2000/svg")),
+//!               border: None,
+//!               wide_ratio: None,
+//!               title: None,
+//!               desc: None,
+//!               quiet_zone: 0,
+//!               text: None,
+//!               units: None,
+//!               crisp_edges: false,
+//!               mirrored: false,
+//!               guard_color: None,
+//!               bar_style: BarStyle::Modules};
 //!
 //! // Or use the constructor for defaults (you must specify the height).
 //! let svg = SVG::new(100)
 //!               .xdim(2)
 //!               .background(Color::white())
 //!               .foreground(Color::black())
-//!               .xmlns(String::from("http://www.w3.org/2000/svg"));
+//!               .xmlns(String::from("http://www.w3.org/2000/svg"))
+//!               .title(String::from("750103131130"))
+//!               .desc(String::from("EAN-13"));
+//!
+//! // Or bundle the presentation options together and apply them at once.
+//! let options = SvgOptions::new()
+//!               .quiet_zone(10)
+//!               .text(String::from("750103131130"))
+//!               .units(String::from("mm"))
+//!               .crisp_edges(true);
+//! let svg = SVG::new(100).options(options);
 //! ```
 
 use crate::error::Result;
+use crate::sym::ean13::EAN13;
+use crate::sym::ean_supp::Combined;
+use crate::sym::helpers;
+use crate::sym::tf::Bearer;
 #[cfg(not(feature = "std"))]
-use alloc::{
-    format,
-    string::{String, ToString},
-};
+use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 trait ToHex {
     fn to_hex(self) -> String;
@@ -51,7 +76,7 @@ trait ToHex {
 }
 
 /// Represents a RGBA color for the barcode foreground and background.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Color {
     /// Reg, Green, Blue, Alpha value.
     pub rgba: [u8; 4],
@@ -64,6 +89,18 @@ impl Color {
         Self { rgba }
     }
 
+    /// Constructor for an opaque color from its red, green and blue channels.
+    #[must_use]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::new([r, g, b, 255])
+    }
+
+    /// Constructor for a color from its red, green, blue and alpha channels.
+    #[must_use]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new([r, g, b, a])
+    }
+
     /// Constructor for black (#000000).
     #[must_use]
     pub const fn black() -> Self {
@@ -76,6 +113,51 @@ impl Color {
         Self::new([255, 255, 255, 255])
     }
 
+    /// Returns the relative luminance of the color, from 0 for black to 1 for white. The alpha
+    /// channel is ignored.
+    ///
+    /// The sRGB transfer curve is approximated by a gamma of 2, as `powf` is unavailable in
+    /// `no_std` builds.
+    #[must_use]
+    #[allow(clippy::suboptimal_flops)]
+    pub fn luminance(self) -> f64 {
+        let linear = |c: u8| (f64::from(c) / 255.0) * (f64::from(c) / 255.0);
+
+        0.2126 * linear(self.rgba[0])
+            + 0.7152 * linear(self.rgba[1])
+            + 0.0722 * linear(self.rgba[2])
+    }
+
+    /// Returns the contrast ratio between this color and another, from 1 for identical
+    /// luminance to 21 for black and white.
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // Blends the color toward black or white by the given number of 255ths, keeping its alpha.
+    fn toward(self, white: bool, step: u8) -> Self {
+        let [r, g, b, a] = self.rgba;
+        let blend = |c: u8| {
+            let distance = if white {
+                255 - u16::from(c)
+            } else {
+                u16::from(c)
+            };
+            let shift = u8::try_from(distance * u16::from(step) / 255).unwrap_or(u8::MAX);
+
+            if white {
+                c.saturating_add(shift)
+            } else {
+                c.saturating_sub(shift)
+            }
+        };
+
+        Self::new([blend(r), blend(g), blend(b), a])
+    }
+
     fn to_opacity(self) -> String {
         format!("{:.*}", 2, (f64::from(self.rgba[3]) / 255.0))
     }
@@ -91,6 +173,50 @@ impl ToHex for Color {
     }
 }
 
+// Escapes the characters that are not allowed to appear literally in XML text.
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut s, c| {
+            match c {
+                '&' => s.push_str("&amp;"),
+                '<' => s.push_str("&lt;"),
+                '>' => s.push_str("&gt;"),
+                '"' => s.push_str("&quot;"),
+                c => s.push(c),
+            }
+            s
+        })
+}
+
+/// The result of a structured SVG render.
+///
+/// Contains the SVG bars alongside the layout of the human-readable text, so that callers can
+/// place the text themselves.
+#[derive(Clone, Debug)]
+pub struct RenderResult {
+    /// The generated SVG data.
+    pub svg: String,
+    /// The x-coordinate (in SVG units) at which each human-readable character should be centered.
+    pub text_x_positions: Vec<f32>,
+    /// The y-coordinate (in SVG units) of the edge of the bars each human-readable character is
+    /// anchored to: the bottom edge for text rendered below the bars, or the top edge for text
+    /// rendered above them (such as the digits of a supplement).
+    pub text_y_positions: Vec<f32>,
+}
+
+/// The height used by `SVG::default()`.
+pub const DEFAULT_HEIGHT: u32 = 100;
+
+/// The quiet zone width (in multiples of the X dimension) used by `SVG::new`.
+pub const DEFAULT_QUIET_ZONE: u32 = 10;
+
+/// The minimum contrast ratio between the foreground and background enforced by
+/// `SVG::ensure_contrast`.
+pub const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// The font size (in multiples of the X dimension) of the human-readable text.
+const TEXT_SIZE: u32 = 10;
+
 /// The SVG barcode generator type.
 #[derive(Clone, Debug)]
 pub struct SVG {
@@ -105,6 +231,142 @@ pub struct SVG {
     pub background: Color,
     /// The XML namespace
     pub xmlns: Option<String>,
+    /// The color and width of an optional border drawn around the whole symbol.
+    pub border: Option<(Color, u32)>,
+    /// The width (in multiples of the X dimension) at which wide elements are rendered.
+    ///
+    /// Every element wider than a single module is treated as "wide". This is only meaningful
+    /// for two-width symbologies such as Code39, Codabar, Code11 and 2-of-5.
+    pub wide_ratio: Option<u32>,
+    /// An accessible title for the symbol, typically the human-readable value.
+    pub title: Option<String>,
+    /// An accessible description of the symbol, typically the name of the symbology.
+    pub desc: Option<String>,
+    /// The width (in multiples of the X dimension) of the empty space on either side of the bars.
+    pub quiet_zone: u32,
+    /// Human-readable text, rendered centered below the bars.
+    pub text: Option<String>,
+    /// The unit (such as "mm" or "px") of the width and height of the SVG, in which one unit is
+    /// drawn per pixel of the view box. No width or height is set if this is `None`.
+    pub units: Option<String>,
+    /// Whether renderers should be asked to disable anti-aliasing of the bar edges.
+    pub crisp_edges: bool,
+    /// Whether the symbol is mirrored horizontally, such as for printing on the reverse side of
+    /// a transparent surface or for right-to-left layouts.
+    pub mirrored: bool,
+    /// The RGBA color of the guard bars, to distinguish them from the data bars. Guards are only
+    /// known to `generate_with_guards` and `generate_ean13_structured`, and are otherwise drawn
+    /// in the foreground color, as are all guards if this is `None`.
+    pub guard_color: Option<Color>,
+    /// How the bars are drawn. Every style renders an identical symbol.
+    pub bar_style: BarStyle,
+}
+
+/// The elements used to draw the bars of an SVG.
+///
+/// Merging adjacent bars renders an identical symbol, but produces much smaller output.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BarStyle {
+    /// One `<rect>` per module.
+    #[default]
+    Modules,
+    /// One `<rect>` per run of adjacent bars of the same color.
+    Merged,
+    /// A single `<path>` per color, in which adjacent bars are merged.
+    Path,
+}
+
+/// A bundle of the presentation options of an SVG, applied all at once with `SVG::options`.
+///
+/// Options that are left unset keep the value already held by the SVG.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// See `SVG::quiet_zone`.
+    pub quiet_zone: Option<u32>,
+    /// See `SVG::text`.
+    pub text: Option<String>,
+    /// See `SVG::foreground`.
+    pub foreground: Option<Color>,
+    /// See `SVG::background`.
+    pub background: Option<Color>,
+    /// See `SVG::border`.
+    pub border: Option<(Color, u32)>,
+    /// See `SVG::units`.
+    pub units: Option<String>,
+    /// See `SVG::crisp_edges`.
+    pub crisp_edges: Option<bool>,
+}
+
+impl SvgOptions {
+    /// Returns a new set of options, none of which are set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            quiet_zone: None,
+            text: None,
+            foreground: None,
+            background: None,
+            border: None,
+            units: None,
+            crisp_edges: None,
+        }
+    }
+
+    /// Set the quiet zone width (in multiples of the X dimension)
+    #[must_use]
+    pub const fn quiet_zone(mut self, modules: u32) -> Self {
+        self.quiet_zone = Some(modules);
+        self
+    }
+
+    /// Set the human-readable text
+    #[must_use]
+    pub fn text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Set the foreground (bar) color
+    #[must_use]
+    pub const fn foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Set the background color
+    #[must_use]
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Set the border color and width
+    #[must_use]
+    pub const fn border(mut self, color: Color, width: u32) -> Self {
+        self.border = Some((color, width));
+        self
+    }
+
+    /// Set the unit of the width and height of the SVG
+    #[must_use]
+    pub fn units(mut self, units: String) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Set whether anti-aliasing of the bar edges should be disabled
+    #[must_use]
+    pub const fn crisp_edges(mut self, crisp_edges: bool) -> Self {
+        self.crisp_edges = Some(crisp_edges);
+        self
+    }
+}
+
+impl Default for SVG {
+    /// Returns a new SVG with default values and a height of `DEFAULT_HEIGHT`.
+    fn default() -> Self {
+        Self::new(DEFAULT_HEIGHT)
+    }
 }
 
 impl SVG {
@@ -121,6 +383,17 @@ impl SVG {
                 rgba: [255, 255, 255, 255],
             },
             xmlns: None,
+            border: None,
+            wide_ratio: None,
+            title: None,
+            desc: None,
+            quiet_zone: DEFAULT_QUIET_ZONE,
+            text: None,
+            units: None,
+            crisp_edges: false,
+            mirrored: false,
+            guard_color: None,
+            bar_style: BarStyle::Modules,
         }
     }
 
@@ -152,27 +425,246 @@ impl SVG {
         self
     }
 
-    fn rect(&self, style: u8, offset: u32, width: u32) -> String {
-        let fill = match style {
-            1 => self.foreground,
-            _ => self.background,
-        };
+    /// Set the border color and width
+    #[must_use]
+    pub const fn border(mut self, color: Color, width: u32) -> Self {
+        self.border = Some((color, width));
+        self
+    }
+
+    /// Set the width (in multiples of the X dimension) of wide elements
+    #[must_use]
+    pub const fn wide_ratio(mut self, ratio: u32) -> Self {
+        self.wide_ratio = Some(ratio);
+        self
+    }
+
+    /// Set the accessible title of the SVG
+    #[must_use]
+    pub fn title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set the accessible description of the SVG
+    #[must_use]
+    pub fn desc(mut self, desc: String) -> Self {
+        self.desc = Some(desc);
+        self
+    }
+
+    /// Set the quiet zone width (in multiples of the X dimension), or 0 to render the bars
+    /// edge-to-edge.
+    #[must_use]
+    pub const fn quiet_zone(mut self, modules: u32) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    /// Set the human-readable text rendered below the bars
+    #[must_use]
+    pub fn text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Set the unit of the width and height of the SVG
+    #[must_use]
+    pub fn units(mut self, units: String) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Set whether anti-aliasing of the bar edges should be disabled
+    #[must_use]
+    pub const fn crisp_edges(mut self, crisp_edges: bool) -> Self {
+        self.crisp_edges = crisp_edges;
+        self
+    }
+
+    /// Set whether the symbol is mirrored horizontally
+    #[must_use]
+    pub const fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Set the color of the guard bars
+    #[must_use]
+    pub const fn guard_color(mut self, color: Color) -> Self {
+        self.guard_color = Some(color);
+        self
+    }
+
+    /// Set how the bars are drawn
+    #[must_use]
+    pub const fn bar_style(mut self, style: BarStyle) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Apply every option that is set in the given bundle of options
+    #[must_use]
+    pub fn options(self, options: SvgOptions) -> Self {
+        Self {
+            quiet_zone: options.quiet_zone.unwrap_or(self.quiet_zone),
+            text: options.text.or(self.text),
+            foreground: options.foreground.unwrap_or(self.foreground),
+            background: options.background.unwrap_or(self.background),
+            border: options.border.or(self.border),
+            units: options.units.or(self.units),
+            crisp_edges: options.crisp_edges.unwrap_or(self.crisp_edges),
+            ..self
+        }
+    }
+
+    /// Returns whether the foreground and background contrast enough to be scanned reliably,
+    /// i.e. their contrast ratio is at least `MIN_CONTRAST_RATIO`.
+    #[must_use]
+    pub fn has_sufficient_contrast(&self) -> bool {
+        self.foreground.contrast_ratio(self.background) >= MIN_CONTRAST_RATIO
+    }
 
-        let opacity = match &fill.to_opacity()[..] {
+    /// Adjusts the foreground and background colors, if necessary, until their contrast ratio
+    /// is at least `MIN_CONTRAST_RATIO`.
+    ///
+    /// The darker of the two colors is moved toward black, or the lighter one toward white when
+    /// the bars are lighter than the spaces. If that is not enough, the other color is then
+    /// moved in the opposite direction. Each color is moved as little as possible.
+    ///
+    /// Returns whether either color was changed, so that callers can log or report the
+    /// adjustment.
+    pub fn ensure_contrast(&mut self) -> bool {
+        if self.has_sufficient_contrast() {
+            return false;
+        }
+
+        let dark_bars = self.foreground.luminance() <= self.background.luminance();
+        let original = (self.foreground, self.background);
+
+        for step in 1..=u8::MAX {
+            self.foreground = original.0.toward(!dark_bars, step);
+
+            if self.has_sufficient_contrast() {
+                return true;
+            }
+        }
+
+        for step in 1..=u8::MAX {
+            self.background = original.1.toward(dark_bars, step);
+
+            if self.has_sufficient_contrast() {
+                break;
+            }
+        }
+
+        true
+    }
+
+    fn border_width(&self) -> u32 {
+        self.border.map_or(0, |(_, width)| width)
+    }
+
+    const fn quiet_width(&self) -> u32 {
+        self.quiet_zone * self.xdim
+    }
+
+    fn text_height(&self) -> u32 {
+        self.text.as_ref().map_or(0, |_| TEXT_SIZE * self.xdim)
+    }
+
+    // The height of a line of text above shortened bars, which never exceeds the bars.
+    fn text_band(&self) -> u32 {
+        (TEXT_SIZE * self.xdim).min(self.height)
+    }
+
+    fn modules<'a>(&self, barcode: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+        let modules = self
+            .wide_ratio
+            .map_or(Ok(Cow::Borrowed(barcode)), |ratio| {
+                usize::try_from(ratio)
+                    .map(|wide| Cow::Owned(helpers::stretch_wide(barcode, wide)))
+                    .map_err(|_| crate::error::Error::Conversion)
+            })?;
+
+        if self.mirrored {
+            Ok(Cow::Owned(modules.iter().rev().copied().collect()))
+        } else {
+            Ok(modules)
+        }
+    }
+
+    // The <title> and <desc> elements, which must be the first children of the <svg> element.
+    fn accessibility_elements(&self) -> String {
+        let title = self
+            .title
+            .as_ref()
+            .map_or_else(String::new, |t| format!("<title>{}</title>", escape_xml(t)));
+        let desc = self
+            .desc
+            .as_ref()
+            .map_or_else(String::new, |d| format!("<desc>{}</desc>", escape_xml(d)));
+
+        title + &desc
+    }
+
+    fn fill_opacity(fill: Color) -> String {
+        match &fill.to_opacity()[..] {
             "1.00" | "1" => String::new(),
             o => format!(" fill-opacity=\"{o}\" "),
-        };
+        }
+    }
+
+    fn rect(&self, fill: Color, offset: u32, top: u32, width: u32, height: u32) -> String {
+        let opacity = Self::fill_opacity(fill);
 
         format!(
-            "<rect x=\"{}\" y=\"0\" width=\"{}\" height=\"{}\" fill=\"#{}\"{}/>",
-            offset,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{}\"{}/>",
+            offset + self.border_width(),
+            top + self.border_width(),
             width,
-            self.height,
+            height,
             fill.to_hex(),
             opacity
         )
     }
 
+    fn text_element(&self, width: u32) -> String {
+        self.text.as_ref().map_or_else(String::new, |text| {
+            let size = TEXT_SIZE * self.xdim;
+
+            format!(
+                "<text x=\"{x}\" y=\"{y}\" font-family=\"monospace\" font-size=\"{s}\" text-anchor=\"middle\" fill=\"#{c}\">{t}</text>",
+                x = f64::from(self.border_width()) + (f64::from(width) / 2.0),
+                y = self.border_width() + self.height + size,
+                s = size,
+                c = self.foreground.to_hex(),
+                t = escape_xml(text)
+            )
+        })
+    }
+
+    // The border is drawn as a stroke centered on its path, so the path is inset by half of the
+    // stroke width in order for the stroke to sit entirely outside of the symbol.
+    fn border_rect(&self, width: u32) -> String {
+        self.border.map_or_else(String::new, |(color, stroke)| {
+            let opacity = match &color.to_opacity()[..] {
+                "1.00" | "1" => String::new(),
+                o => format!(" stroke-opacity=\"{o}\" "),
+            };
+
+            format!(
+                "<rect x=\"{i}\" y=\"{i}\" width=\"{w}\" height=\"{h}\" fill=\"none\" stroke=\"#{c}\" stroke-width=\"{s}\"{o}/>",
+                i = f64::from(stroke) / 2.0,
+                w = width + stroke,
+                h = self.height + self.text_height() + stroke,
+                c = color.to_hex(),
+                s = stroke,
+                o = opacity
+            )
+        })
+    }
+
     /// Generates the given barcode.
     ///
     /// Returns a `Result<String, Error>` containing the SVG data or an error message.
@@ -181,99 +673,1129 @@ impl SVG {
     ///
     /// This function will return an error if the provided barcode data is invalid or cannot
     /// be processed into a valid SVG representation.
+    /// Returns an `Error::Length` if the barcode is empty.
     pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
-        let barcode = barcode.as_ref();
-        let width = match u32::try_from(barcode.len()) {
-            Ok(len) => len * self.xdim,
-            Err(_) => return Err(crate::error::Error::Length),
-        };
-        let rects: String = barcode
-            .iter()
-            .enumerate()
-            .filter(|&(_, &n)| n == 1)
-            .map(|(i, &n)| {
-                Ok(match u32::try_from(i) {
-                    Ok(offset) => self.rect(n, offset * self.xdim, self.xdim),
-                    Err(_) => return Err(crate::error::Error::Conversion),
-                })
-            })
-            .collect::<Result<String>>()?;
+        self.generate_with_guards(barcode, &[])
+    }
 
-        let xmlns = self
-            .xmlns
-            .as_ref()
-            .map_or_else(String::new, |xmlns| format!("xmlns=\"{xmlns}\" "));
+    /// Generates the given barcode, drawing the bars within the given ranges of modules in the
+    /// `guard_color` (if any), such as `EAN13::GUARDS`.
+    ///
+    /// The ranges refer to the modules as rendered, i.e. after any `wide_ratio` is applied, and
+    /// follow the bars when mirrored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    /// Returns an `Error::Length` if the barcode is empty.
+    pub fn generate_with_guards<T: AsRef<[u8]>>(
+        &self,
+        barcode: T,
+        guards: &[Range<usize>],
+    ) -> Result<String> {
+        let (elements, view_width, view_height) = self.elements(barcode.as_ref(), guards, &[])?;
 
         Ok(format!(
-            "<svg version=\"1.1\" {x}viewBox=\"0 0 {w} {h}\">{s}{r}</svg>",
-            x = xmlns,
-            w = width,
-            h = self.height,
-            s = self.rect(0, 0, width),
-            r = rects
+            "{}{elements}</svg>",
+            self.open_tag(view_width, view_height)
         ))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::generators::svg::*;
-    use crate::sym::codabar::*;
-    use crate::sym::code11::*;
-    use crate::sym::code128::*;
-    use crate::sym::code39::*;
-    use crate::sym::code93::*;
-    use crate::sym::ean13::*;
-    use crate::sym::ean8::*;
-    use crate::sym::ean_supp::*;
-    use crate::sym::tf::*;
-    #[cfg(feature = "std")]
-    use std::fs::File;
-    #[cfg(feature = "std")]
-    use std::io::prelude::*;
-    #[cfg(feature = "std")]
-    use std::io::BufWriter;
-    #[cfg(feature = "std")]
-    use std::path::Path;
+    /// Generates the given barcode as a `<g>` group translated by the given offsets, without the
+    /// `<svg>` root, for composing into a larger SVG document.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    /// Returns an `Error::Length` if the barcode is empty.
+    pub fn generate_fragment<T: AsRef<[u8]>>(
+        &self,
+        barcode: T,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> Result<String> {
+        self.group(
+            barcode.as_ref(),
+            &format!("translate({x_offset} {y_offset})"),
+        )
+    }
 
-    const TEST_DATA_BASE: &str = "./target/debug";
-    const WRITE_TO_FILE: bool = true;
+    // The elements of the given barcode, wrapped in a group with the given transform.
+    fn group(&self, barcode: &[u8], transform: &str) -> Result<String> {
+        let (elements, _, _) = self.elements(barcode, &[], &[])?;
 
-    #[cfg(feature = "std")]
-    fn write_file(data: &str, file: &'static str) {
-        let path = open_file(file);
-        let mut writer = BufWriter::new(path);
-        writer
-            .write_all(data.as_bytes())
-            .expect("Failed to write data to file");
+        Ok(format!(
+            "<g transform=\"{}\">{elements}</g>",
+            escape_xml(transform)
+        ))
     }
 
-    #[cfg(not(feature = "std"))]
-    fn write_file(_data: &str, _file: &'static str) {}
+    // The opening <svg> tag, with the given view box size.
+    fn open_tag(&self, view_width: u32, view_height: u32) -> String {
+        let xmlns = self
+            .xmlns
+            .as_ref()
+            .map_or_else(String::new, |xmlns| format!("xmlns=\"{xmlns}\" "));
+        let size = self.units.as_ref().map_or_else(String::new, |units| {
+            format!("width=\"{view_width}{units}\" height=\"{view_height}{units}\" ")
+        });
+        let rendering = if self.crisp_edges {
+            "shape-rendering=\"crispEdges\" "
+        } else {
+            ""
+        };
 
-    #[cfg(feature = "std")]
-    fn open_file(name: &'static str) -> File {
-        File::create(Path::new(&format!("{TEST_DATA_BASE}/{name}")[..]))
-            .expect("Failed to create file")
+        format!(
+            "<svg version=\"1.1\" {xmlns}{size}{rendering}viewBox=\"0 0 {view_width} {view_height}\">"
+        )
     }
 
-    #[test]
-    fn ean_13_as_svg() {
-        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
-        let svg = SVG::new(80);
-        let generated = svg
-            .generate(&ean13.encode()[..])
-            .expect("Failed to generate SVG");
+    // The child elements of the <svg> element for the given barcode, along with the width and
+    // height of its view box. The bars within the `lowered` ranges of modules are shortened from
+    // the top by the height of a line of text.
+    fn elements(
+        &self,
+        barcode: &[u8],
+        guards: &[Range<usize>],
+        lowered: &[Range<usize>],
+    ) -> Result<(String, u32, u32)> {
+        helpers::validate_modules(barcode)?;
 
-        if WRITE_TO_FILE {
-            write_file(&generated[..], "ean13.svg");
-        }
+        let barcode = self.modules(barcode)?;
+        let width = match u32::try_from(barcode.len()) {
+            Ok(len) => len * self.xdim,
+            Err(_) => return Err(crate::error::Error::Length),
+        };
+        let fill = |i: usize| {
+            let module = if self.mirrored {
+                barcode.len() - 1 - i
+            } else {
+                i
+            };
 
-        assert_eq!(generated.len(), 2890);
-    }
+            let color = match self.guard_color {
+                Some(color) if guards.iter().any(|g| g.contains(&module)) => color,
+                _ => self.foreground,
+            };
+            let top = if lowered.iter().any(|l| l.contains(&module)) {
+                self.text_band()
+            } else {
+                0
+            };
 
-    #[test]
-    fn colored_ean_13_as_svg() {
+            (color, top)
+        };
+        let runs = self.runs(&barcode, fill)?;
+        let rects: String = if self.bar_style == BarStyle::Path {
+            self.paths(&runs)
+        } else {
+            runs.iter()
+                .map(|&(offset, modules, color, top)| {
+                    self.rect(
+                        color,
+                        self.quiet_width() + (offset * self.xdim),
+                        top,
+                        modules * self.xdim,
+                        self.height - top,
+                    )
+                })
+                .collect()
+        };
+
+        let content_width = width + (self.quiet_width() * 2);
+        let content_height = self.height + self.text_height();
+        let elements = format!(
+            "{a}{b}{s}{r}{t}",
+            a = self.accessibility_elements(),
+            b = self.border_rect(content_width),
+            s = self.rect(self.background, 0, 0, content_width, content_height),
+            r = rects,
+            t = self.text_element(content_width)
+        );
+
+        Ok((
+            elements,
+            content_width + (self.border_width() * 2),
+            content_height + (self.border_width() * 2),
+        ))
+    }
+
+    // The bars of the given modules as (offset, width) pairs in modules, along with their fill
+    // and how far below the top of the symbol they start. Each module is its own bar unless the
+    // bar style merges them.
+    fn runs(
+        &self,
+        barcode: &[u8],
+        fill: impl Fn(usize) -> (Color, u32),
+    ) -> Result<Vec<(u32, u32, Color, u32)>> {
+        let merge = self.bar_style != BarStyle::Modules;
+        let mut runs: Vec<(u32, u32, Color, u32)> = vec![];
+
+        for (i, _) in barcode.iter().enumerate().filter(|&(_, &n)| n == 1) {
+            let offset = u32::try_from(i).map_err(|_| crate::error::Error::Conversion)?;
+            let (color, top) = fill(i);
+
+            match runs.last_mut() {
+                Some((start, modules, c, t))
+                    if merge && *start + *modules == offset && *c == color && *t == top =>
+                {
+                    *modules += 1;
+                }
+                _ => runs.push((offset, 1, color, top)),
+            }
+        }
+
+        Ok(runs)
+    }
+
+    // The given bars drawn as one <path> per fill, in the order each fill first appears. Each bar
+    // is a closed subpath of relative horizontal and vertical lines.
+    fn paths(&self, runs: &[(u32, u32, Color, u32)]) -> String {
+        let mut paths: Vec<(Color, String)> = vec![];
+
+        for &(offset, modules, color, top) in runs {
+            let width = modules * self.xdim;
+            let bar = format!(
+                "M{x} {y}h{width}v{h}h-{width}z",
+                x = self.border_width() + self.quiet_width() + (offset * self.xdim),
+                y = self.border_width() + top,
+                h = self.height - top
+            );
+
+            match paths.iter_mut().find(|(c, _)| *c == color) {
+                Some((_, d)) => d.push_str(&bar),
+                None => paths.push((color, bar)),
+            }
+        }
+
+        paths
+            .iter()
+            .map(|(color, d)| Self::path_element(*color, d))
+            .collect()
+    }
+
+    fn path_element(fill: Color, d: &str) -> String {
+        format!(
+            "<path d=\"{d}\" fill=\"#{}\"{}/>",
+            fill.to_hex(),
+            Self::fill_opacity(fill)
+        )
+    }
+
+    /// Generates the given barcode framed by its bearer bars (see `TF::encode_with_bearer`).
+    ///
+    /// The bearer bars are drawn as a border in the foreground color, replacing any `border`,
+    /// and the quiet zone is widened to the bearer's minimum if necessary. Any text is drawn
+    /// within the frame.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    pub fn generate_with_bearer(&self, bearer: &Bearer) -> Result<String> {
+        let svg = Self {
+            border: Some((self.foreground, bearer.width * self.xdim)),
+            quiet_zone: self.quiet_zone.max(bearer.quiet_zone),
+            ..self.clone()
+        };
+
+        svg.generate(&bearer.modules)
+    }
+
+    /// Generates the given barcode as a base64-encoded `data:` URI.
+    ///
+    /// This is useful for embedding the barcode directly into HTML or CSS.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    pub fn generate_data_uri<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        self.generate(barcode).map(|svg| {
+            format!(
+                "data:image/svg+xml;base64,{}",
+                helpers::base64(svg.as_bytes())
+            )
+        })
+    }
+
+    /// Generates the given barcode along with the positions of its human-readable text.
+    ///
+    /// The characters of `value` are evenly distributed across the width of the barcode and
+    /// the x-coordinate of the center of each character is returned, accounting for any border.
+    ///
+    /// Returns a `Result<RenderResult, Error>` containing the SVG data and text positions.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    pub fn generate_structured<T: AsRef<[u8]>, V: AsRef<str>>(
+        &self,
+        barcode: T,
+        value: V,
+    ) -> Result<RenderResult> {
+        let svg = self.generate(barcode.as_ref())?;
+        let modules = self.modules(barcode.as_ref())?.len();
+        let count = value.as_ref().chars().count();
+
+        Ok(RenderResult {
+            svg,
+            text_x_positions: self.text_x_positions(0..modules, modules, count),
+            text_y_positions: vec![self.text_y_position(false); count],
+        })
+    }
+
+    /// Generates the given EAN-13 barcode along with the positions of its human-readable text,
+    /// grouped as on a printed symbol (see `EAN13::TEXT_LAYOUT`).
+    ///
+    /// The first character of `value` is centered to the left of the left guard, within the
+    /// quiet zone, and the remaining twelve are distributed across the left and right groups of
+    /// digits. The quiet zone is widened to `EAN13::TEXT_LAYOUT.leading` modules if necessary, so
+    /// that the first character fits. The guards are drawn in the `guard_color`, if any. Any
+    /// `wide_ratio` is ignored, as EAN-13 is not a two-width symbology.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    /// Returns an `Error::Length` if `value` is not 13 characters long.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn generate_ean13_structured<T: AsRef<[u8]>, V: AsRef<str>>(
+        &self,
+        barcode: T,
+        value: V,
+    ) -> Result<RenderResult> {
+        if value.as_ref().chars().count() != 13 {
+            return Err(crate::error::Error::Length);
+        }
+
+        let layout = EAN13::TEXT_LAYOUT;
+        let leading_zone =
+            u32::try_from(layout.leading).map_err(|_| crate::error::Error::Conversion)?;
+        let svg = Self {
+            wide_ratio: None,
+            quiet_zone: self.quiet_zone.max(leading_zone),
+            ..self.clone()
+        };
+        let total = barcode.as_ref().len();
+        let leading = -(layout.leading as f32) / 2.0;
+        let mut result = RenderResult {
+            svg: svg.generate_with_guards(barcode.as_ref(), &EAN13::GUARDS)?,
+            text_x_positions: vec![svg.module_x(leading, total)],
+            text_y_positions: vec![svg.text_y_position(false); 13],
+        };
+
+        result
+            .text_x_positions
+            .extend(svg.text_x_positions(layout.left, total, 6));
+        result
+            .text_x_positions
+            .extend(svg.text_x_positions(layout.right, total, 6));
+
+        Ok(result)
+    }
+
+    /// Generates a main symbol combined with its supplement (see `EANSUPP::combine`), along with
+    /// the positions of the human-readable text of both.
+    ///
+    /// The characters of `value` are distributed across the main symbol and anchored below its
+    /// bars, whilst the characters of `supplement_value` are distributed across the supplement
+    /// and anchored above its bars, as is conventional. The supplement's bars are shortened to
+    /// leave room for its text within the symbol. The gap between the symbols is rendered as
+    /// empty space. The positions of the main symbol's text come first.
+    ///
+    /// Any `wide_ratio` is ignored, as EAN symbologies are not two-width symbologies.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the provided barcode data is invalid or cannot
+    /// be processed into a valid SVG representation.
+    pub fn generate_with_supplement<V: AsRef<str>, S: AsRef<str>>(
+        &self,
+        combined: &Combined,
+        value: V,
+        supplement_value: S,
+    ) -> Result<RenderResult> {
+        let svg = Self {
+            wide_ratio: None,
+            ..self.clone()
+        };
+        let count = value.as_ref().chars().count();
+        let supplement_count = supplement_value.as_ref().chars().count();
+        let (elements, view_width, view_height) = svg.elements(
+            &combined.modules,
+            &[],
+            core::slice::from_ref(&combined.supplement),
+        )?;
+        let mut result = RenderResult {
+            svg: format!("{}{elements}</svg>", svg.open_tag(view_width, view_height)),
+            text_x_positions: svg.text_x_positions(
+                combined.main.clone(),
+                combined.modules.len(),
+                count,
+            ),
+            text_y_positions: vec![svg.text_y_position(false); count],
+        };
+
+        result.text_x_positions.extend(svg.text_x_positions(
+            combined.supplement.clone(),
+            combined.modules.len(),
+            supplement_count,
+        ));
+        result.text_y_positions.extend(core::iter::repeat_n(
+            svg.text_y_position(true),
+            supplement_count,
+        ));
+
+        Ok(result)
+    }
+
+    // Evenly distributes `count` characters across the `span` of modules of a symbol which is
+    // `total` modules wide, returning the x-coordinate of the center of each character,
+    // accounting for any border. When mirrored, the span is flipped to the other side of the
+    // symbol and the characters run from right to left, matching the reversed bars.
+    // Barcode widths are far below f32 precision limits, and `mul_add` is unavailable in no_std.
+    #[allow(clippy::cast_precision_loss, clippy::suboptimal_flops)]
+    fn text_x_positions(&self, span: Range<usize>, total: usize, count: usize) -> Vec<f32> {
+        let xdim = self.xdim as f32;
+        let start = if self.mirrored {
+            total - span.end
+        } else {
+            span.start
+        };
+        let slot = (span.len() as f32) * xdim / (count as f32);
+        let inset = ((self.border_width() + self.quiet_width()) as f32) + ((start as f32) * xdim);
+        let positions = (0..count).map(|i| inset + (slot * (i as f32 + 0.5)));
+
+        if self.mirrored {
+            positions.rev().collect()
+        } else {
+            positions.collect()
+        }
+    }
+
+    // Returns the x-coordinate of the given (possibly fractional or negative) module offset of a
+    // symbol which is `total` modules wide, accounting for any border and mirroring.
+    #[allow(clippy::cast_precision_loss, clippy::suboptimal_flops)]
+    fn module_x(&self, module: f32, total: usize) -> f32 {
+        let module = if self.mirrored {
+            (total as f32) - module
+        } else {
+            module
+        };
+
+        ((self.border_width() + self.quiet_width()) as f32) + (module * (self.xdim as f32))
+    }
+
+    // Returns the y-coordinate of the bottom edge of the bars, or (if `above`) the bottom edge of
+    // the line of text above bars which are shortened to make room for it.
+    #[allow(clippy::cast_precision_loss)]
+    fn text_y_position(&self, above: bool) -> f32 {
+        let top = self.border_width() as f32;
+
+        if above {
+            top + (self.text_band() as f32)
+        } else {
+            top + (self.height as f32)
+        }
+    }
+}
+
+/// The XML namespace of SVG documents.
+#[cfg(feature = "std")]
+const SVG_XMLNS: &str = "http://www.w3.org/2000/svg";
+
+/// Streams many barcodes into a single SVG document written to a `std::io::Write` sink, such
+/// as a file, without holding the whole document in memory.
+///
+/// Each barcode is rendered by the given `SVG` and written as soon as it is pushed, as a group
+/// positioned by an SVG `transform` such as `"translate(0 120)"`.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SvgStream<W: std::io::Write> {
+    svg: SVG,
+    sink: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> SvgStream<W> {
+    /// Starts a document of the given size, writing its opening tag to the sink.
+    ///
+    /// The namespace, units and rendering hints of `svg` are applied to the document. The SVG
+    /// namespace is always declared, as the document is standalone.
+    ///
+    /// # Errors
+    /// Returns an `Error::Generate` if writing to the sink fails.
+    pub fn new(svg: SVG, mut sink: W, width: u32, height: u32) -> Result<Self> {
+        let document = SVG {
+            xmlns: svg.xmlns.clone().or_else(|| Some(String::from(SVG_XMLNS))),
+            ..svg.clone()
+        };
+
+        sink.write_all(document.open_tag(width, height).as_bytes())?;
+
+        Ok(Self { svg, sink })
+    }
+
+    /// Writes the given barcode to the sink as a group, positioned by the given transform.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the barcode is empty, or another error if it cannot be
+    /// processed into a valid SVG representation.
+    /// Returns an `Error::Generate` if writing to the sink fails.
+    pub fn push<T: AsRef<[u8]>>(&mut self, barcode: T, transform: &str) -> Result<()> {
+        let group = self.svg.group(barcode.as_ref(), transform)?;
+
+        self.sink.write_all(group.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Closes the document, flushing and returning the sink.
+    ///
+    /// # Errors
+    /// Returns an `Error::Generate` if writing to the sink fails.
+    pub fn finish(mut self) -> Result<W> {
+        self.sink.write_all(b"</svg>")?;
+        self.sink.flush()?;
+
+        Ok(self.sink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::svg::*;
+    use crate::sym::codabar::*;
+    use crate::sym::code11::*;
+    use crate::sym::code128::*;
+    use crate::sym::code39::*;
+    use crate::sym::code93::*;
+    use crate::sym::ean13::*;
+    use crate::sym::ean8::*;
+    use crate::sym::ean_supp::*;
+    use crate::sym::tf::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use std::fs::File;
+    #[cfg(feature = "std")]
+    use std::io::prelude::*;
+    #[cfg(feature = "std")]
+    use std::io::BufWriter;
+    #[cfg(feature = "std")]
+    use std::path::Path;
+
+    #[cfg(feature = "std")]
+    const TEST_DATA_BASE: &str = "./target/debug";
+    const WRITE_TO_FILE: bool = true;
+
+    #[cfg(feature = "std")]
+    fn write_file(data: &str, file: &'static str) {
+        let path = open_file(file);
+        let mut writer = BufWriter::new(path);
+        writer
+            .write_all(data.as_bytes())
+            .expect("Failed to write data to file");
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn write_file(_data: &str, _file: &'static str) {}
+
+    #[cfg(feature = "std")]
+    fn open_file(name: &'static str) -> File {
+        File::create(Path::new(&format!("{TEST_DATA_BASE}/{name}")[..]))
+            .expect("Failed to create file")
+    }
+
+    #[test]
+    fn default_svg() {
+        let svg = SVG::default();
+
+        assert_eq!(svg.height, DEFAULT_HEIGHT);
+        assert_eq!(svg.height, 100);
+        assert_eq!(svg.xdim, 1);
+        assert_eq!(svg.quiet_zone, DEFAULT_QUIET_ZONE);
+    }
+
+    #[test]
+    fn ean_13_as_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80).quiet_zone(0);
+        let generated = svg
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate SVG");
+
+        if WRITE_TO_FILE {
+            write_file(&generated[..], "ean13.svg");
+        }
+
+        assert_eq!(generated.len(), 2890);
+    }
+
+    #[test]
+    fn default_quiet_zone_svg() {
+        let encoded = EAN13::new("750103131130")
+            .expect("Failed to create EAN13 barcode")
+            .encode();
+        let generate = |svg: SVG| svg.generate(&encoded[..]).expect("Failed to generate SVG");
+
+        // 95 modules and a 10 module quiet zone either side.
+        assert!(generate(SVG::new(80)).contains("viewBox=\"0 0 115 80\""));
+        assert!(generate(SVG::new(80).xdim(2)).contains("viewBox=\"0 0 230 80\""));
+        assert!(generate(SVG::new(80).quiet_zone(0)).contains("viewBox=\"0 0 95 80\""));
+        // The first bar sits after the quiet zone.
+        assert!(generate(SVG::new(80)).contains("<rect x=\"10\" y=\"0\" width=\"1\""));
+    }
+
+    #[test]
+    fn ean_13_as_structured_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80).quiet_zone(0);
+        let generated = svg
+            .generate_structured(&ean13.encode()[..], "7501031311305")
+            .expect("Failed to generate SVG");
+
+        assert_eq!(generated.svg.len(), 2890);
+        assert_eq!(generated.text_x_positions.len(), 13);
+        assert!(generated
+            .text_y_positions
+            .iter()
+            .all(|&y| (y - 80.0).abs() < f32::EPSILON));
+        assert!(generated
+            .text_x_positions
+            .windows(2)
+            .all(|w| w[0] < w[1] && w[1] < 95.0));
+    }
+
+    #[test]
+    fn ean_13_as_grouped_structured_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80).quiet_zone(10);
+        let result = svg
+            .generate_ean13_structured(&ean13.encode()[..], "7501031311309")
+            .expect("Failed to generate structured SVG");
+        let x = &result.text_x_positions;
+
+        assert_eq!(
+            result.svg,
+            svg.generate(&ean13.encode()[..])
+                .expect("Failed to generate SVG")
+        );
+        assert_eq!(x.len(), 13);
+        // The leading digit is centered in the 7 modules to the left of the left guard.
+        assert!((x[0] - 6.5).abs() < f32::EPSILON);
+        // Each digit of a group is centered over its 7 modules.
+        assert!((x[1] - 16.5).abs() < f32::EPSILON);
+        assert!((x[6] - 51.5).abs() < f32::EPSILON);
+        assert!((x[7] - 63.5).abs() < f32::EPSILON);
+        assert!((x[12] - 98.5).abs() < f32::EPSILON);
+        assert!(matches!(
+            svg.generate_ean13_structured(&ean13.encode()[..], "750103131130"),
+            Err(crate::error::Error::Length)
+        ));
+    }
+
+    #[test]
+    fn ean_13_structured_svg_without_quiet_zone() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let result = SVG::new(80)
+            .quiet_zone(0)
+            .border(Color::black(), 2)
+            .generate_ean13_structured(&ean13.encode()[..], "7501031311309")
+            .expect("Failed to generate structured SVG");
+
+        // The quiet zone is widened to 7 modules, and the leading digit is centered within it.
+        assert!(result.svg.contains("viewBox=\"0 0 113 84\""));
+        assert!((result.text_x_positions[0] - 5.5).abs() < f32::EPSILON);
+        assert!(result
+            .text_x_positions
+            .iter()
+            .all(|&x| x > 2.0 && x < 111.0));
+    }
+
+    #[test]
+    fn ean_13_with_supplement_as_structured_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let ean5 = EANSUPP::new("51234").expect("Failed to create EAN5 barcode");
+        let combined = ean5.combine(&ean13.encode(), 9);
+        let svg = SVG::new(80).quiet_zone(0);
+        let generated = svg
+            .generate_with_supplement(&combined, "7501031311305", "51234")
+            .expect("Failed to generate SVG");
+        let (main_x, supplement_x) = generated.text_x_positions.split_at(13);
+        let (main_y, supplement_y) = generated.text_y_positions.split_at(13);
+
+        assert!(generated.svg.contains("viewBox=\"0 0 151 80\""));
+        assert_eq!(supplement_x.len(), 5);
+        assert!(main_x.iter().all(|&x| x < 95.0));
+        assert!(supplement_x.iter().all(|&x| x > 104.0 && x < 151.0));
+        // Supplement digits are rendered above the supplement bars, main digits below.
+        assert!(supplement_y
+            .iter()
+            .all(|&y| (y - 10.0).abs() < f32::EPSILON));
+        assert!(main_y.iter().all(|&y| (y - 80.0).abs() < f32::EPSILON));
+        assert!(supplement_y[0] < main_y[0]);
+        // The supplement bars start below its text, whilst the main bars are full height.
+        assert!(generated
+            .svg
+            .contains("<rect x=\"104\" y=\"10\" width=\"1\" height=\"70\""));
+        assert!(generated
+            .svg
+            .contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"80\""));
+    }
+
+    #[test]
+    fn supplement_text_is_within_view_box() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let ean2 = EANSUPP::new("34").expect("Failed to create EAN2 barcode");
+        let combined = ean2.combine(&ean13.encode(), DEFAULT_GAP);
+
+        for svg in [
+            SVG::new(80),
+            SVG::new(40).xdim(2).border(Color::black(), 3),
+            SVG::new(5),
+        ] {
+            let generated = svg
+                .generate_with_supplement(&combined, "7501031311309", "34")
+                .expect("Failed to generate SVG");
+            let view_box = generated
+                .svg
+                .split("viewBox=\"0 0 ")
+                .nth(1)
+                .and_then(|rest| rest.split('"').next())
+                .expect("Missing viewBox");
+            let (_, view_height) = view_box.split_once(' ').expect("Malformed viewBox");
+            let view_height: f32 = view_height.parse().expect("Malformed viewBox");
+
+            for &y in &generated.text_y_positions[13..] {
+                assert!(y > 0.0 && y <= view_height);
+            }
+        }
+    }
+
+    #[test]
+    fn mirrored_ean_13_as_structured_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let encoded = ean13.encode();
+        let reversed: Vec<u8> = encoded.iter().rev().copied().collect();
+        let value = "7501031311309";
+        let result = SVG::new(80)
+            .quiet_zone(0)
+            .mirrored(true)
+            .generate_structured(&encoded[..], value)
+            .expect("Failed to generate structured SVG");
+        let unmirrored = SVG::new(80)
+            .quiet_zone(0)
+            .generate_structured(&encoded[..], value)
+            .expect("Failed to generate structured SVG");
+
+        assert_eq!(
+            result.svg,
+            SVG::new(80)
+                .quiet_zone(0)
+                .generate(&reversed[..])
+                .expect("Failed to generate SVG")
+        );
+        // The number-system digit moves from the left edge to the right edge.
+        assert!(unmirrored.text_x_positions[0] < unmirrored.text_x_positions[12]);
+        assert!(result.text_x_positions[0] > result.text_x_positions[12]);
+        for (mirrored, original) in result
+            .text_x_positions
+            .iter()
+            .zip(&unmirrored.text_x_positions)
+        {
+            assert!((mirrored - (95.0 - original)).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn mirrored_ean_13_with_supplement_as_structured_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let ean2 = EANSUPP::new("34").expect("Failed to create EAN2 barcode");
+        let combined = ean2.combine(&ean13.encode(), DEFAULT_GAP);
+        let result = SVG::new(80)
+            .mirrored(true)
+            .generate_with_supplement(&combined, "7501031311309", "34")
+            .expect("Failed to generate structured SVG");
+
+        // The supplement sits to the left of the main symbol, and its text runs right to left.
+        assert!(result.text_x_positions[13] < result.text_x_positions[12]);
+        assert!(result.text_x_positions[14] < result.text_x_positions[13]);
+        assert!(result.text_x_positions[0] > result.text_x_positions[12]);
+    }
+
+    #[test]
+    fn ean_13_as_svg_with_title_and_desc() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80)
+            .title(String::from("7501031311309"))
+            .desc(String::from("EAN-13 <retail>"));
+        let generated = svg
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate SVG");
+        let title = generated.find("<title>").expect("Missing <title>");
+        let desc = generated.find("<desc>").expect("Missing <desc>");
+        let rect = generated.find("<rect").expect("Missing <rect>");
+
+        assert!(generated.contains("<title>7501031311309</title>"));
+        assert!(generated.contains("<desc>EAN-13 &lt;retail&gt;</desc>"));
+        assert!(generated[..title].ends_with('>'));
+        assert!(generated[..title].starts_with("<svg"));
+        assert_eq!(generated[..title].matches('<').count(), 1);
+        assert!(title < desc);
+        assert!(desc < rect);
+    }
+
+    #[test]
+    fn ean_13_as_svg_with_guard_color() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .guard_color(Color::rgb(255, 0, 0));
+        let generated = svg
+            .generate_ean13_structured(&ean13.encode()[..], "7501031311309")
+            .expect("Failed to generate SVG")
+            .svg;
+        let bars: usize = ean13.encode().iter().map(|&m| usize::from(m)).sum();
+
+        if WRITE_TO_FILE {
+            write_file(&generated[..], "ean13_guard_color.svg");
+        }
+
+        // Each of the three guards has two bars.
+        assert_eq!(generated.matches("fill=\"#ff0000\"").count(), 6);
+        assert_eq!(generated.matches("fill=\"#000000\"").count(), bars - 6);
+        // The quiet zone is widened to 7 modules to make room for the leading digit.
+        assert!(generated
+            .contains("<rect x=\"7\" y=\"0\" width=\"1\" height=\"80\" fill=\"#ff0000\"/>"));
+        assert!(generated
+            .contains("<rect x=\"53\" y=\"0\" width=\"1\" height=\"80\" fill=\"#ff0000\"/>"));
+        // The first bar of the first digit of the left group is a data bar.
+        assert!(generated
+            .contains("<rect x=\"11\" y=\"0\" width=\"1\" height=\"80\" fill=\"#000000\"/>"));
+
+        // Without guard ranges, every bar is drawn in the foreground color.
+        let plain = svg
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate SVG");
+
+        assert!(!plain.contains("#ff0000"));
+    }
+
+    // Returns the value of the given numeric attribute of the given element.
+    fn attribute(element: &str, name: &str) -> u32 {
+        let start = element
+            .find(&format!("{name}=\""))
+            .expect("Missing attribute")
+            + name.len()
+            + 2;
+        let value = &element[start..];
+
+        value[..value.find('"').expect("Unterminated attribute")]
+            .parse()
+            .expect("Non-numeric attribute")
+    }
+
+    // Returns the modules covered by the <rect> bars of the given document, skipping the
+    // background.
+    fn rect_modules(document: &str, len: usize) -> Vec<u8> {
+        let mut modules = vec![0; len];
+
+        for rect in document.split("<rect").skip(2) {
+            let x = attribute(rect, "x") as usize;
+
+            modules[x..x + attribute(rect, "width") as usize].fill(1);
+        }
+
+        modules
+    }
+
+    #[test]
+    fn merged_ean_13_as_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let modules = ean13.encode();
+        let svg = SVG::new(80).quiet_zone(0);
+        let plain = svg.generate(&modules[..]).expect("Failed to generate SVG");
+        let merged = svg
+            .clone()
+            .bar_style(BarStyle::Merged)
+            .generate(&modules[..])
+            .expect("Failed to generate SVG");
+        let path = svg
+            .bar_style(BarStyle::Path)
+            .generate(&modules[..])
+            .expect("Failed to generate SVG");
+
+        if WRITE_TO_FILE {
+            write_file(&merged[..], "ean13_merged.svg");
+            write_file(&path[..], "ean13_path.svg");
+        }
+
+        assert!(merged.len() < plain.len());
+        assert!(path.len() < merged.len());
+        assert_eq!(rect_modules(&plain, modules.len()), modules);
+        assert_eq!(rect_modules(&merged, modules.len()), modules);
+        // The background, along with 2 bars for each of the 12 digits and 3 guards.
+        assert_eq!(merged.matches("<rect").count(), 31);
+        assert!(
+            merged.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"80\" fill=\"#000000\"/>")
+        );
+
+        let d = &path[path.find(" d=\"").expect("Missing path data")..];
+        let black: u32 = d
+            .split('h')
+            .skip(1)
+            .filter_map(|h| h.split(|c: char| !c.is_ascii_digit()).next())
+            .filter(|h| !h.is_empty())
+            .map(|h| h.parse::<u32>().expect("Non-numeric width"))
+            .sum();
+
+        assert_eq!(path.matches("<path").count(), 1);
+        assert_eq!(path.matches("<rect").count(), 1);
+        assert_eq!(d.matches('M').count(), 30);
+        assert_eq!(black, modules.iter().map(|&m| u32::from(m)).sum::<u32>());
+        assert!(path.contains("<path d=\"M0 0h1v80h-1zM2 0h1v80h-1z"));
+    }
+
+    #[test]
+    fn merged_ean_13_as_svg_with_guard_color() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80)
+            .xdim(2)
+            .quiet_zone(0)
+            .guard_color(Color::rgb(255, 0, 0));
+        let merged = svg
+            .clone()
+            .bar_style(BarStyle::Merged)
+            .generate_with_guards(&ean13.encode()[..], &EAN13::GUARDS)
+            .expect("Failed to generate SVG");
+        let path = svg
+            .bar_style(BarStyle::Path)
+            .generate_with_guards(&ean13.encode()[..], &EAN13::GUARDS)
+            .expect("Failed to generate SVG");
+
+        // Bars are never merged across a change of color.
+        assert_eq!(merged.matches("fill=\"#ff0000\"").count(), 6);
+        assert_eq!(merged.matches("fill=\"#000000\"").count(), 24);
+        assert_eq!(path.matches("<path").count(), 2);
+        assert!(path.contains("<path d=\"M0 0h2v80h-2zM4 0h2v80h-2z"));
+    }
+
+    // Checks that every tag of the document is closed in order, returning the number of
+    // elements with the given name.
+    fn count_balanced_elements(document: &str, name: &str) -> usize {
+        let mut open: Vec<&str> = vec![];
+        let mut count = 0;
+
+        for tag in document.split('<').skip(1) {
+            let tag = &tag[..tag.find('>').expect("Unterminated tag")];
+
+            if let Some(closing) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(closing), "Mismatched closing tag");
+            } else {
+                let tag_name = tag.split([' ', '/']).next().expect("Missing tag name");
+
+                count += usize::from(tag_name == name);
+
+                if !tag.ends_with('/') {
+                    open.push(tag_name);
+                }
+            }
+        }
+
+        assert!(open.is_empty(), "Unclosed tags: {open:?}");
+        count
+    }
+
+    #[test]
+    fn svg_fragment() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80);
+        let fragment = svg
+            .generate_fragment(ean13.encode(), 10, 20)
+            .expect("Failed to generate SVG fragment");
+        let standalone = svg
+            .generate(ean13.encode())
+            .expect("Failed to generate SVG");
+
+        assert!(fragment.starts_with("<g transform=\"translate(10 20)\">"));
+        assert!(fragment.ends_with("</g>"));
+        assert!(!fragment.contains("<svg"));
+        assert_eq!(count_balanced_elements(&fragment, "g"), 1);
+        assert!(standalone.contains(
+            &fragment["<g transform=\"translate(10 20)\">".len()..fragment.len() - "</g>".len()]
+        ));
+        assert_eq!(
+            svg.generate_fragment([], 0, 0).err(),
+            Some(crate::error::Error::Length)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stream_svg() {
+        let svg = SVG::new(40).title(String::from("\"Shelf\" & <labels>"));
+        let barcodes = [
+            EAN13::new("750103131130")
+                .expect("Failed to create EAN13 barcode")
+                .encode(),
+            Code39::new("SHELF-1")
+                .expect("Failed to create Code39 barcode")
+                .encode(),
+            TF::itf14("1540014128876")
+                .expect("Failed to create ITF-14 barcode")
+                .encode(),
+        ];
+        let mut stream =
+            SvgStream::new(svg.clone(), Vec::new(), 200, 150).expect("Failed to start SVG stream");
+
+        for (i, barcode) in barcodes.iter().enumerate() {
+            stream
+                .push(barcode, &format!("translate(0 {})", i * 50))
+                .expect("Failed to stream barcode");
+        }
+
+        let document = String::from_utf8(stream.finish().expect("Failed to finish SVG stream"))
+            .expect("SVG is not UTF-8");
+
+        if WRITE_TO_FILE {
+            write_file(&document[..], "stream.svg");
+        }
+
+        assert!(document.starts_with(
+            "<svg version=\"1.1\" xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 200 150\">"
+        ));
+        assert_eq!(count_balanced_elements(&document, "svg"), 1);
+        assert_eq!(count_balanced_elements(&document, "g"), 3);
+        assert!(document.contains("<g transform=\"translate(0 100)\"><title>&quot;Shelf&quot; &amp; &lt;labels&gt;</title>"));
+
+        // Each group holds exactly the elements of the standalone SVG.
+        for barcode in &barcodes {
+            let standalone = svg.generate(barcode).expect("Failed to generate SVG");
+            let elements = &standalone[standalone.find('>').expect("Missing <svg> tag") + 1
+                ..standalone.len() - "</svg>".len()];
+
+            assert!(document.contains(elements));
+        }
+
+        assert_eq!(
+            SvgStream::new(svg, Vec::new(), 10, 10)
+                .expect("Failed to start SVG stream")
+                .push([], "")
+                .err(),
+            Some(crate::error::Error::Length)
+        );
+    }
+
+    #[test]
+    fn itf_14_as_svg_with_bearer() {
+        let itf14 = TF::itf14("1540014128876").expect("Failed to create ITF-14 barcode");
+        let svg = SVG::new(80).xdim(2);
+        let generated = svg
+            .generate_with_bearer(&itf14.encode_with_bearer())
+            .expect("Failed to generate SVG");
+
+        if WRITE_TO_FILE {
+            write_file(&generated[..], "itf14_bearer.svg");
+        }
+
+        // 134 modules and two 10 module quiet zones, framed by 10 pixel bearer bars.
+        assert!(generated.contains("viewBox=\"0 0 328 100\""));
+        assert!(generated.contains(
+            "<rect x=\"5\" y=\"5\" width=\"318\" height=\"90\" fill=\"none\" stroke=\"#000000\" stroke-width=\"10\"/>"
+        ));
+        // The first bar follows the bearer bar and the quiet zone.
+        assert!(generated
+            .contains("<rect x=\"30\" y=\"10\" width=\"2\" height=\"80\" fill=\"#000000\"/>"));
+
+        // A wider quiet zone is kept.
+        let wide = svg
+            .quiet_zone(20)
+            .generate_with_bearer(&itf14.encode_with_bearer())
+            .expect("Failed to generate SVG");
+
+        assert!(wide.contains("viewBox=\"0 0 368 100\""));
+    }
+
+    #[test]
+    fn ean_13_as_svg_with_options() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let options = SvgOptions::new()
+            .quiet_zone(10)
+            .text(String::from("7 501031 311309"))
+            .foreground(Color::rgb(0, 0, 255))
+            .background(Color::rgb(255, 255, 0))
+            .border(Color::black(), 2)
+            .units(String::from("mm"))
+            .crisp_edges(true);
+        let svg = SVG::new(80).options(options);
+        let generated = svg
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate SVG");
+
+        if WRITE_TO_FILE {
+            write_file(&generated[..], "ean13_options.svg");
+        }
+
+        // 95 modules, a 10 module quiet zone either side and a 2 pixel border.
+        assert!(generated.starts_with(
+            "<svg version=\"1.1\" width=\"119mm\" height=\"94mm\" \
+             shape-rendering=\"crispEdges\" viewBox=\"0 0 119 94\">"
+        ));
+        assert!(generated.contains("stroke=\"#000000\" stroke-width=\"2\""));
+        assert!(generated
+            .contains("<rect x=\"2\" y=\"2\" width=\"115\" height=\"90\" fill=\"#ffff00\"/>"));
+        // The first bar sits after the quiet zone.
+        assert!(generated
+            .contains("<rect x=\"12\" y=\"2\" width=\"1\" height=\"80\" fill=\"#0000ff\"/>"));
+        assert!(generated.ends_with(
+            "<text x=\"59.5\" y=\"92\" font-family=\"monospace\" font-size=\"10\" \
+             text-anchor=\"middle\" fill=\"#0000ff\">7 501031 311309</text></svg>"
+        ));
+        // Unset options keep the existing values.
+        assert_eq!(
+            SVG::new(80)
+                .xdim(2)
+                .options(SvgOptions::new())
+                .generate(&ean13.encode()[..]),
+            SVG::new(80).xdim(2).generate(&ean13.encode()[..])
+        );
+    }
+
+    #[test]
+    fn empty_modules_as_svg() {
+        assert_eq!(SVG::new(80).generate([]), Err(crate::error::Error::Length));
+        assert!(matches!(
+            SVG::new(80).generate_structured([], ""),
+            Err(crate::error::Error::Length)
+        ));
+    }
+
+    #[test]
+    fn ean_13_as_svg_data_uri() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80).quiet_zone(0);
+        let generated = svg
+            .generate_data_uri(&ean13.encode()[..])
+            .expect("Failed to generate SVG data URI");
+
+        assert!(generated.starts_with("data:image/svg+xml;base64,PHN2ZyB2ZXJzaW9u"));
+        assert_eq!(generated.len(), 26 + 3856);
+    }
+
+    #[test]
+    fn color_from_channels() {
+        assert_eq!(Color::rgb(1, 2, 3), Color::new([1, 2, 3, 255]));
+        assert_eq!(Color::rgba(1, 2, 3, 4), Color::new([1, 2, 3, 4]));
+        assert_eq!(Color::rgb(0, 0, 0), Color::black());
+    }
+
+    #[test]
+    fn colored_ean_13_as_svg() {
         let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
         let svg = SVG {
             height: 80,
@@ -285,6 +1807,17 @@ mod tests {
                 rgba: [0, 0, 255, 255],
             },
             xmlns: None,
+            border: None,
+            wide_ratio: None,
+            title: None,
+            desc: None,
+            quiet_zone: 0,
+            text: None,
+            units: None,
+            crisp_edges: false,
+            mirrored: false,
+            guard_color: None,
+            bar_style: BarStyle::Modules,
         };
         let generated = svg
             .generate(&ean13.encode()[..])
@@ -297,6 +1830,58 @@ mod tests {
         assert_eq!(generated.len(), 2890);
     }
 
+    #[test]
+    fn contrast_ratio() {
+        assert!((Color::black().contrast_ratio(Color::white()) - 21.0).abs() < 1e-9);
+        assert!((Color::white().contrast_ratio(Color::black()) - 21.0).abs() < 1e-9);
+        assert!((Color::rgb(90, 90, 90).contrast_ratio(Color::rgb(90, 90, 90)) - 1.0).abs() < 1e-9);
+        assert!(SVG::new(80).has_sufficient_contrast());
+        assert!(!SVG::new(80)
+            .foreground(Color::rgb(100, 100, 100))
+            .background(Color::rgb(150, 150, 150))
+            .has_sufficient_contrast());
+    }
+
+    #[test]
+    fn ensure_contrast() {
+        let mut svg = SVG::new(80);
+
+        assert!(!svg.ensure_contrast());
+        assert_eq!(svg.foreground, Color::black());
+        assert_eq!(svg.background, Color::white());
+
+        // A light background only needs the bars to be darkened.
+        let mut grays = SVG::new(80)
+            .foreground(Color::rgba(100, 100, 100, 200))
+            .background(Color::rgb(150, 150, 150));
+
+        assert!(grays.ensure_contrast());
+        assert!(grays.has_sufficient_contrast());
+        assert_eq!(grays.background, Color::rgb(150, 150, 150));
+        assert!(grays.foreground.rgba[0] < 100);
+        assert_eq!(grays.foreground.rgba[3], 200);
+
+        // A dark background is lightened once the bars are black.
+        let mut dark = SVG::new(80)
+            .foreground(Color::rgb(60, 60, 60))
+            .background(Color::rgb(80, 80, 80));
+
+        assert!(dark.ensure_contrast());
+        assert!(dark.has_sufficient_contrast());
+        assert_eq!(dark.foreground, Color::black());
+        assert!(dark.background.rgba[0] > 80);
+
+        // Light bars on a dark background are lightened instead.
+        let mut inverted = SVG::new(80)
+            .foreground(Color::rgb(150, 150, 150))
+            .background(Color::rgb(100, 100, 100));
+
+        assert!(inverted.ensure_contrast());
+        assert!(inverted.has_sufficient_contrast());
+        assert_eq!(inverted.background, Color::rgb(100, 100, 100));
+        assert!(inverted.foreground.rgba[0] > 150);
+    }
+
     #[test]
     fn colored_semi_transparent_ean_13_as_svg() {
         let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
@@ -310,6 +1895,17 @@ mod tests {
                 rgba: [0, 0, 255, 128],
             },
             xmlns: None,
+            border: None,
+            wide_ratio: None,
+            title: None,
+            desc: None,
+            quiet_zone: 0,
+            text: None,
+            units: None,
+            crisp_edges: false,
+            mirrored: false,
+            guard_color: None,
+            bar_style: BarStyle::Modules,
         };
         let generated = svg
             .generate(&ean13.encode()[..])
@@ -322,10 +1918,56 @@ mod tests {
         assert_eq!(generated.len(), 3940);
     }
 
+    #[test]
+    fn bordered_ean_13_as_svg() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .border(Color::new([255, 0, 0, 255]), 4);
+        let generated = svg
+            .generate(&ean13.encode()[..])
+            .expect("Failed to generate SVG");
+
+        if WRITE_TO_FILE {
+            write_file(&generated[..], "ean13_bordered.svg");
+        }
+
+        assert!(generated.contains("viewBox=\"0 0 103 88\""));
+        assert!(generated.contains("<rect x=\"2\" y=\"2\" width=\"99\" height=\"84\" fill=\"none\" stroke=\"#ff0000\" stroke-width=\"4\"/>"));
+        assert!(generated
+            .contains("<rect x=\"4\" y=\"4\" width=\"95\" height=\"80\" fill=\"#ffffff\"/>"));
+    }
+
+    #[test]
+    fn wide_ratio_code39_as_svg() {
+        let code39 = Code39::new("1234").expect("Failed to create Code39 barcode");
+        let encoded = code39.encode();
+        let width = |svg: SVG| {
+            let generated = svg.generate(&encoded[..]).expect("Failed to generate SVG");
+            let start = generated.find("viewBox=\"0 0 ").expect("Missing viewBox") + 13;
+            let end = start + generated[start..].find(' ').expect("Malformed viewBox");
+
+            generated[start..end]
+                .parse::<u32>()
+                .expect("Failed to parse SVG width")
+        };
+
+        // Six characters (including the guards), each with three wide elements.
+        assert_eq!(width(SVG::new(80).quiet_zone(0)), 77);
+        assert_eq!(width(SVG::new(80).quiet_zone(0).wide_ratio(2)), 77);
+        assert_eq!(width(SVG::new(80).quiet_zone(0).wide_ratio(3)), 77 + 18);
+        assert_eq!(
+            width(SVG::new(80).quiet_zone(0).xdim(2).wide_ratio(3)),
+            (77 + 18) * 2
+        );
+    }
+
     #[test]
     fn ean_8_as_svg() {
         let ean8 = EAN8::new("9998823").expect("Failed to create EAN8 barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&ean8.encode()[..])
             .expect("Failed to generate SVG");
@@ -340,7 +1982,9 @@ mod tests {
     #[test]
     fn code39_as_svg() {
         let code39 = Code39::new("IGOT99PROBLEMS").expect("Failed to create Code39 barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&code39.encode()[..])
             .expect("Failed to generate SVG");
@@ -355,7 +1999,9 @@ mod tests {
     #[test]
     fn code93_as_svg() {
         let code93 = Code93::new("IGOT99PROBLEMS").expect("Failed to create Code93 barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&code93.encode()[..])
             .expect("Failed to generate SVG");
@@ -370,7 +2016,9 @@ mod tests {
     #[test]
     fn codabar_as_svg() {
         let codabar = Codabar::new("A12----34A").expect("Failed to create Codabar barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&codabar.encode()[..])
             .expect("Failed to generate SVG");
@@ -386,7 +2034,9 @@ mod tests {
     fn code128_as_svg() {
         let code128 =
             Code128::new("HIĆ345678", CharacterSet::A).expect("Failed to create Code128 barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&code128.encode()[..])
             .expect("Failed to generate SVG");
@@ -401,7 +2051,9 @@ mod tests {
     #[test]
     fn ean_2_as_svg() {
         let ean2 = EANSUPP::new("78").expect("Failed to create EAN2 barcode");
-        let svg = SVG::new(80).xmlns("http://www.w3.org/2000/svg".to_string());
+        let svg = SVG::new(80)
+            .quiet_zone(0)
+            .xmlns("http://www.w3.org/2000/svg".to_string());
         let generated = svg
             .generate(&ean2.encode()[..])
             .expect("Failed to generate SVG");
@@ -423,6 +2075,17 @@ mod tests {
             background: Color::black(),
             foreground: Color::white(),
             xmlns: None,
+            border: None,
+            wide_ratio: None,
+            title: None,
+            desc: None,
+            quiet_zone: 0,
+            text: None,
+            units: None,
+            crisp_edges: false,
+            mirrored: false,
+            guard_color: None,
+            bar_style: BarStyle::Modules,
         };
         let generated = svg
             .generate(&itf.encode()[..])
@@ -444,6 +2107,17 @@ mod tests {
             background: Color::black(),
             foreground: Color::white(),
             xmlns: None,
+            border: None,
+            wide_ratio: None,
+            title: None,
+            desc: None,
+            quiet_zone: 0,
+            text: None,
+            units: None,
+            crisp_edges: false,
+            mirrored: false,
+            guard_color: None,
+            bar_style: BarStyle::Modules,
         };
         let generated = svg
             .generate(&code11.encode()[..])
@@ -0,0 +1,180 @@
+//! Functionality for generating Unicode block-character representations of barcodes.
+//!
+//! This is a terminal-friendly alternative to the ASCII generator: each bar is drawn with the
+//! full block character (`█`), which leaves no gaps between rows in most terminal fonts.
+//!
+//! The height is given in terminal rows. Enabling `Unicode::half_block` draws the last row with
+//! the upper half block character (`▀`), so barcodes can be drawn in steps of half a row.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::generators::unicode::*;
+//! use scanning::sym::ean13::*;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let unicode = Unicode::new(4).xdim(2);
+//! let rendered = unicode.generate(barcode.encode()).unwrap();
+//!
+//! assert_eq!(rendered.lines().count(), 4);
+//! ```
+
+use crate::error::Result;
+use crate::sym::helpers;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+/// The character used for a full row of a bar.
+const FULL_BLOCK: char = '█';
+
+/// The character used for the last row of a bar when `Unicode::half_block` is enabled.
+const UPPER_HALF_BLOCK: char = '▀';
+
+/// The Unicode barcode generator type.
+#[derive(Copy, Clone, Debug)]
+pub struct Unicode {
+    /// The height of the barcode (```self.height``` terminal rows high).
+    pub height: usize,
+    /// The X dimension. Specifies the width of the "narrow" bars, each of which will be
+    /// ```self.xdim``` terminal columns wide.
+    pub xdim: usize,
+    /// Whether the last row is drawn half as tall, with upper half blocks.
+    pub half_block: bool,
+}
+
+/// The height used by `Unicode::default()`.
+pub const DEFAULT_HEIGHT: usize = 5;
+
+impl Default for Unicode {
+    /// Returns a new Unicode with default values and a height of `DEFAULT_HEIGHT`.
+    fn default() -> Self {
+        Self::new(DEFAULT_HEIGHT)
+    }
+}
+
+impl Unicode {
+    /// Returns a new Unicode with default values.
+    #[must_use]
+    pub const fn new(height: usize) -> Self {
+        Self {
+            height,
+            xdim: 1,
+            half_block: false,
+        }
+    }
+
+    /// Set the x dimensional bar width
+    #[must_use]
+    pub const fn xdim(mut self, xdim: usize) -> Self {
+        self.xdim = xdim;
+        self
+    }
+
+    /// Set whether the last row is drawn half as tall
+    #[must_use]
+    pub const fn half_block(mut self, half_block: bool) -> Self {
+        self.half_block = half_block;
+        self
+    }
+
+    fn row(&self, barcode: &[u8], bar: char) -> String {
+        let mut row = String::with_capacity(barcode.len() * self.xdim * bar.len_utf8() + 1);
+
+        for &m in barcode {
+            let c = if m == 0 { ' ' } else { bar };
+
+            row.extend(core::iter::repeat_n(c, self.xdim));
+        }
+
+        row.push('\n');
+        row
+    }
+
+    /// Generates the given barcode as rows of Unicode block characters, each ending with a
+    /// newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Conversion` if the barcode contains non-binary modules.
+    pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        let full_rows = if self.half_block {
+            self.height.saturating_sub(1)
+        } else {
+            self.height
+        };
+        let full = self.row(barcode, FULL_BLOCK);
+        let mut rendered = full.repeat(full_rows);
+
+        if self.half_block && self.height > 0 {
+            rendered.push_str(&self.row(barcode, UPPER_HALF_BLOCK));
+        }
+
+        Ok(rendered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::generators::unicode::*;
+    use crate::sym::ean13::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    #[test]
+    fn ean_13_as_unicode() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let generated = Unicode::new(2)
+            .generate(ean13.encode())
+            .expect("Failed to generate Unicode");
+        let row = "█ █ ██   █ █  ███  ██  █ █  ███ ████ █ ██  ██ █ █ █    █ ██  ██ ██  ██ █    █ ███  █ ███ █  █ █";
+
+        assert_eq!(generated, format!("{row}\n{row}\n"));
+    }
+
+    #[test]
+    fn ean_13_as_half_block_unicode() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let generated = Unicode::new(3)
+            .xdim(2)
+            .half_block(true)
+            .generate(ean13.encode())
+            .expect("Failed to generate Unicode");
+        let full = "██  ██  ████      ██  ██    ██████    ████    ██  ██    ██████  ████████  ██  ████    ████  ██  ██  ██        ██  ████    ████  ████    ████  ██        ██  ██████    ██  ██████  ██    ██  ██";
+
+        assert_eq!(
+            generated,
+            format!("{full}\n{full}\n{}\n", full.replace('█', "▀"))
+        );
+    }
+
+    #[test]
+    fn small_unicode() {
+        assert_eq!(
+            Unicode::new(1)
+                .half_block(true)
+                .generate([1, 0, 1])
+                .expect("Failed to generate Unicode"),
+            "▀ ▀\n"
+        );
+        assert_eq!(
+            Unicode::new(0)
+                .generate([1, 0, 1])
+                .expect("Failed to generate Unicode"),
+            ""
+        );
+    }
+
+    #[test]
+    fn invalid_modules_as_unicode() {
+        let unicode = Unicode::default();
+        let empty: [u8; 0] = [];
+
+        assert_eq!(unicode.generate(empty), Err(Error::Length));
+        assert_eq!(unicode.generate([1, 0, 5]), Err(Error::Conversion));
+    }
+}
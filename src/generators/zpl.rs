@@ -0,0 +1,205 @@
+//! Functionality for generating ZPL representations of barcodes, for Zebra label printers.
+//!
+//! The barcode is drawn as a `^GFA` graphic field from the encoded modules, so any symbology can
+//! be printed, and positioned on the label with a `^FO` field origin. The output is a complete
+//! label format, from `^XA` to `^XZ`, which can be sent to the printer as-is.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::generators::zpl::*;
+//! use scanning::sym::ean13::*;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let zpl = ZPL::new(100).xdim(2).origin(50, 20);
+//! let label = zpl.generate(barcode.encode()).unwrap();
+//!
+//! assert!(label.starts_with("^XA^FO50,20^GFA,2400,2400,24,"));
+//! ```
+
+use crate::error::Result;
+use crate::sym::helpers;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// The hexadecimal digits of the graphic field data.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The ZPL barcode generator type.
+#[derive(Copy, Clone, Debug)]
+pub struct ZPL {
+    /// The height of the barcode (```self.height``` dots high).
+    pub height: usize,
+    /// The X dimension. Specifies the width of the "narrow" bars, each of which will be
+    /// ```self.xdim``` dots wide.
+    pub xdim: usize,
+    /// The x-coordinate (in dots) of the top-left corner of the barcode on the label.
+    pub x: u32,
+    /// The y-coordinate (in dots) of the top-left corner of the barcode on the label.
+    pub y: u32,
+}
+
+/// The height used by `ZPL::default()`.
+pub const DEFAULT_HEIGHT: usize = 100;
+
+impl Default for ZPL {
+    /// Returns a new ZPL with default values and a height of `DEFAULT_HEIGHT`.
+    fn default() -> Self {
+        Self::new(DEFAULT_HEIGHT)
+    }
+}
+
+impl ZPL {
+    /// Returns a new ZPL with default values, positioned at the top-left corner of the label.
+    #[must_use]
+    pub const fn new(height: usize) -> Self {
+        Self {
+            height,
+            xdim: 1,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Set the x dimensional bar width
+    #[must_use]
+    pub const fn xdim(mut self, xdim: usize) -> Self {
+        self.xdim = xdim;
+        self
+    }
+
+    /// Set the position (in dots) of the top-left corner of the barcode on the label
+    #[must_use]
+    pub const fn origin(mut self, x: u32, y: u32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Generates the given barcode as a ZPL label containing a `^GFA` graphic field.
+    ///
+    /// Each row of the field is packed eight dots to a byte, most significant bit first, and
+    /// written as ASCII hexadecimal. The `^GFA` header gives the total number of bytes of the
+    /// field (twice, as the data is uncompressed) and the number of bytes per row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the barcode is empty.
+    /// Returns an `Error::Conversion` if the barcode contains non-binary modules.
+    pub fn generate<T: AsRef<[u8]>>(&self, barcode: T) -> Result<String> {
+        let barcode = barcode.as_ref();
+        helpers::validate_modules(barcode)?;
+
+        let pixels: Vec<u8> = barcode
+            .iter()
+            .flat_map(|&m| core::iter::repeat_n(m, self.xdim))
+            .collect();
+        let row: String = helpers::pack_bits(&pixels)
+            .iter()
+            .flat_map(|&b| {
+                [
+                    HEX_DIGITS[usize::from(b >> 4)],
+                    HEX_DIGITS[usize::from(b & 0xF)],
+                ]
+            })
+            .map(char::from)
+            .collect();
+        let row_bytes = row.len() / 2;
+        let total_bytes = row_bytes * self.height;
+        let mut label = format!(
+            "^XA^FO{},{}^GFA,{total_bytes},{total_bytes},{row_bytes},",
+            self.x, self.y
+        );
+
+        label.reserve(row.len() * self.height + 6);
+
+        for _ in 0..self.height {
+            label.push_str(&row);
+        }
+
+        label.push_str("^FS^XZ");
+        Ok(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::generators::zpl::*;
+    use crate::sym::code39::*;
+    use crate::sym::ean13::*;
+
+    // Returns the fields of the ^GFA header, and the graphic field data.
+    fn graphic_field(label: &str) -> ([usize; 3], &str) {
+        let start = label.find("^GFA,").expect("Missing ^GFA command") + 5;
+        let end = label.find("^FS").expect("Missing ^FS command");
+        let mut fields = label[start..end].splitn(4, ',');
+        let mut header = [0; 3];
+
+        for h in &mut header {
+            *h = fields
+                .next()
+                .and_then(|f| f.parse().ok())
+                .expect("Invalid ^GFA header");
+        }
+
+        (header, fields.next().expect("Missing ^GFA data"))
+    }
+
+    #[test]
+    fn ean_13_as_zpl() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let zpl = ZPL::new(100).xdim(2).origin(50, 20);
+        let generated = zpl
+            .generate(ean13.encode())
+            .expect("Failed to generate ZPL");
+        let (header, data) = graphic_field(&generated);
+
+        assert!(generated.starts_with("^XA^FO50,20^GFA,"));
+        assert!(generated.ends_with("^FS^XZ"));
+        // 190 dots are packed into 24 bytes per row.
+        assert_eq!(header, [2400, 2400, 24]);
+        assert_eq!(data.len(), 2 * 2400);
+        assert!(data.starts_with("CCF0"));
+    }
+
+    #[test]
+    fn code39_as_zpl() {
+        let code39 = Code39::new("TEST8").expect("Failed to create Code39 barcode");
+        let modules = code39.encode();
+        let generated = ZPL::default()
+            .generate(&modules)
+            .expect("Failed to generate ZPL");
+        let (header, data) = graphic_field(&generated);
+        let row_bytes = modules.len().div_ceil(8);
+
+        assert!(generated.starts_with("^XA^FO0,0^GFA,"));
+        assert!(generated.ends_with("^FS^XZ"));
+        assert_eq!(
+            header,
+            [
+                row_bytes * DEFAULT_HEIGHT,
+                row_bytes * DEFAULT_HEIGHT,
+                row_bytes
+            ]
+        );
+        assert_eq!(data.len(), 2 * row_bytes * DEFAULT_HEIGHT);
+    }
+
+    #[test]
+    fn small_zpl() {
+        assert_eq!(
+            ZPL::new(2).xdim(3).generate([1, 0, 1]),
+            Ok(String::from("^XA^FO0,0^GFA,4,4,2,E380E380^FS^XZ"))
+        );
+    }
+
+    #[test]
+    fn invalid_modules_as_zpl() {
+        let zpl = ZPL::default();
+        let empty: [u8; 0] = [];
+
+        assert_eq!(zpl.generate(empty), Err(Error::Length));
+        assert_eq!(zpl.generate([1, 0, 5]), Err(Error::Conversion));
+    }
+}
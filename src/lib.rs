@@ -36,6 +36,9 @@
 //! * PNG (feature: `image`)
 //! * GIF (feature: `image`)
 //! * WEBP (feature: `image`)
+//! * PBM (feature: `pbm`)
+//! * ZPL (feature: `zpl`)
+//! * Unicode block characters (feature: `unicode`)
 //! * Or add your own
 //!
 //! ## Examples
@@ -76,3 +79,6 @@ extern crate alloc;
 pub mod error;
 pub mod generators;
 pub mod sym;
+
+/// The version of this crate.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -12,36 +12,182 @@
 //! ```
 //! Each encoder accepts a `String` to be encoded. Valid data is barcode-specific and thus
 //! constructors return an Option<T>.
+//!
+//! Each symbology also provides a `recommended_min_height` method, which gives the minimum bar
+//! height for an X dimension. It is a method even on the fixed-width symbologies, whose height
+//! does not depend on the data, so that every symbology is called in the same way.
 
 pub mod codabar;
 pub mod code11;
 pub mod code128;
 pub mod code39;
 pub mod code93;
+pub mod databar;
+pub mod decode;
 pub mod ean13;
 pub mod ean8;
 pub mod ean_supp;
-mod helpers;
+pub(crate) mod helpers;
+pub mod layout;
+pub mod msi;
 pub mod tf;
 pub mod upca;
+pub mod upce;
+pub mod validate;
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+pub use helpers::strip_check_digit;
 
 use crate::error::Error;
+#[cfg(feature = "ascii")]
+use crate::generators::ascii::ASCII;
 use core::iter::Iterator;
 use core::ops::Range;
 
+/// The identifiers of the supported symbologies, e.g. for populating a selection in a UI.
+pub const SUPPORTED_SYMBOLOGIES: &[&str] = &[
+    "ean13", "bookland", "jan", "upca", "upce", "ean8", "ean2", "ean5", "code11", "code39",
+    "code93", "code128", "itf", "itf14", "stf", "codabar", "databar", "msi",
+];
+
+/// The supported symbologies.
+///
+/// Bookland and JAN barcodes are EAN-13 barcodes, and so are represented by `EAN13`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Symbology {
+    /// EAN-13 (including Bookland and JAN).
+    EAN13,
+    /// UPC-A.
+    UPCA,
+    /// UPC-E.
+    UPCE,
+    /// EAN-8.
+    EAN8,
+    /// The 2-digit EAN supplement.
+    EAN2,
+    /// The 5-digit EAN supplement.
+    EAN5,
+    /// Code11.
+    Code11,
+    /// Code39.
+    Code39,
+    /// Code93.
+    Code93,
+    /// Code128.
+    Code128,
+    /// Interleaved 2-of-5.
+    ITF,
+    /// ITF-14, an Interleaved 2-of-5 GTIN-14.
+    ITF14,
+    /// Standard 2-of-5.
+    STF,
+    /// Codabar.
+    Codabar,
+    /// GS1 DataBar Omnidirectional.
+    DataBar14,
+    /// MSI (Modified Plessey).
+    MSI,
+}
+
+/// Returns the identifiers of the supported symbologies.
+#[must_use]
+pub const fn supported_symbologies() -> &'static [&'static str] {
+    SUPPORTED_SYMBOLOGIES
+}
+
+// Exposes `Parse::valid_chars_string` publicly on each symbology.
+macro_rules! impl_valid_chars_string {
+    ($($t:ty),+) => {
+        $(
+            impl $t {
+                /// Returns the characters this symbology accepts, for display in error messages
+                /// and UIs, e.g. "0-9 A-Z - . space $ / + %".
+                #[must_use]
+                pub fn valid_chars_string() -> String {
+                    <Self as Parse>::valid_chars_string()
+                }
+            }
+        )+
+    };
+}
+
+impl_valid_chars_string!(
+    codabar::Codabar,
+    code11::Code11,
+    code39::Code39,
+    code93::Code93,
+    databar::DataBar14,
+    ean13::EAN13,
+    ean8::EAN8,
+    ean_supp::EANSUPP,
+    msi::MSI,
+    tf::TF,
+    upca::UPCA,
+    upce::UPCE
+);
+
+/// The behaviour shared by every symbology.
+pub trait Barcode {
+    /// Encodes the barcode.
+    /// Returns a Vec<u8> of binary digits.
+    fn encode(&self) -> Vec<u8>;
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`.
+    ///
+    /// Every symbology computes this without encoding, so it can be used to size a canvas
+    /// ahead of time.
+    fn encoded_len(&self) -> usize {
+        self.encode().len()
+    }
+
+    /// Returns the encoded barcode as a string of `0` and `1` characters, such as
+    /// `"10100011010..."`, which is handy for logging, diffing and documentation.
+    fn to_module_string(&self) -> String {
+        self.encode()
+            .iter()
+            .map(|&m| if m == 0 { '0' } else { '1' })
+            .collect()
+    }
+
+    /// Generates the barcode as ASCII with the given height and X dimension, and the default
+    /// quiet zone.
+    ///
+    /// This is a shortcut for calling `ASCII::generate` with the encoded barcode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `ASCII::generate`.
+    #[cfg(feature = "ascii")]
+    fn to_ascii(&self, height: usize, xdim: usize) -> Result<String, Error> {
+        ASCII {
+            height,
+            xdim,
+            ..ASCII::new()
+        }
+        .generate(self.encode())
+    }
+}
+
 trait Parse {
     fn valid_chars() -> Vec<char>;
     fn valid_len() -> Range<u32>;
 
+    // The valid length is counted in characters, not UTF-8 bytes.
     fn parse(data: &str) -> Result<&str, Error> {
         let valid_chars = Self::valid_chars();
         let valid_len = Self::valid_len();
-        let data_len = u32::try_from(data.len()).map_err(|_| Error::Length)?;
+        let data_len = u32::try_from(data.chars().count()).map_err(|_| Error::TooLong)?;
 
-        if data_len < valid_len.start || data_len > valid_len.end {
-            return Err(Error::Length);
+        if data_len < valid_len.start {
+            return Err(Error::TooShort);
+        }
+
+        if data_len > valid_len.end {
+            return Err(Error::TooLong);
         }
 
         let bad_char = data.chars().find(|&c| !valid_chars.contains(&c));
@@ -51,4 +197,129 @@ trait Parse {
             None => Ok(data),
         }
     }
+
+    // The valid characters for display, e.g. "0-9 A-Z - . space $ / + %". Runs of three or more
+    // consecutive characters are shown as ranges.
+    fn valid_chars_string() -> String {
+        let chars = Self::valid_chars();
+        let mut groups: Vec<String> = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let run = chars[i..]
+                .windows(2)
+                .take_while(|w| u32::from(w[0]) + 1 == u32::from(w[1]))
+                .count();
+            let name = |c: char| match c {
+                ' ' => String::from("space"),
+                c => c.to_string(),
+            };
+
+            if run >= 2 {
+                groups.push(format!("{}-{}", name(chars[i]), name(chars[i + run])));
+                i += run + 1;
+            } else {
+                groups.push(name(chars[i]));
+                i += 1;
+            }
+        }
+
+        groups.join(" ")
+    }
+
+    fn parse_bytes(data: &[u8]) -> Result<&[u8], Error> {
+        let valid_chars = Self::valid_chars();
+        let valid_len = Self::valid_len();
+        let data_len = u32::try_from(data.len()).map_err(|_| Error::TooLong)?;
+
+        if data_len < valid_len.start {
+            return Err(Error::TooShort);
+        }
+
+        if data_len > valid_len.end {
+            return Err(Error::TooLong);
+        }
+
+        let bad_byte = data
+            .iter()
+            .find(|&&b| !b.is_ascii() || !valid_chars.contains(&char::from(b)));
+
+        match bad_byte {
+            Some(_) => Err(Error::Character),
+            None => Ok(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sym::*;
+
+    #[test]
+    fn ean13_to_module_string() {
+        let ean13 = ean13::EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+
+        assert_eq!(
+            ean13.to_module_string(),
+            "10101100010100111001100101001110111101011001101010100001011001101100110100001011100\
+             101110100101"
+        );
+        assert_eq!(ean13.to_module_string().len(), ean13.encode().len());
+    }
+
+    #[test]
+    fn barcode_trait_objects() {
+        #[cfg(not(feature = "std"))]
+        use alloc::{boxed::Box, vec};
+
+        let barcodes: Vec<Box<dyn Barcode>> = vec![
+            Box::new(ean13::EAN13::new("750103131130").expect("Failed to create EAN13 barcode")),
+            Box::new(ean8::EAN8::new("5512345").expect("Failed to create EAN8 barcode")),
+            Box::new(upca::UPCA::new("03600029145").expect("Failed to create UPCA barcode")),
+            Box::new(code39::Code39::new("TEST8052").expect("Failed to create Code39 barcode")),
+            Box::new(code93::Code93::new("TEST93").expect("Failed to create Code93 barcode")),
+            Box::new(code11::Code11::new("123-45").expect("Failed to create Code11 barcode")),
+            Box::new(
+                code128::Code128::new("HELLO", code128::CharacterSet::A)
+                    .expect("Failed to create Code128 barcode"),
+            ),
+            Box::new(codabar::Codabar::new("A40156B").expect("Failed to create Codabar barcode")),
+            Box::new(tf::TF::interleaved("1234567").expect("Failed to create ITF barcode")),
+            Box::new(ean_supp::EANSUPP::new("51234").expect("Failed to create EAN5 barcode")),
+        ];
+        let lengths: Vec<usize> = barcodes.iter().map(|b| b.encode().len()).collect();
+
+        assert_eq!(lengths, [95, 67, 95, 129, 91, 70, 90, 71, 80, 47]);
+        assert!(barcodes
+            .iter()
+            .all(|b| b.to_module_string().len() == b.encode().len()));
+        assert!(barcodes.iter().all(|b| b.encoded_len() == b.encode().len()));
+    }
+
+    #[test]
+    fn parse_counts_characters() {
+        // Six characters, but seven bytes.
+        assert_eq!(ean8::EAN8::new("12345é").err(), Some(Error::TooShort));
+        assert_eq!(ean8::EAN8::new("123456é").err(), Some(Error::Character));
+    }
+
+    #[test]
+    fn valid_chars_strings() {
+        assert_eq!(
+            code39::Code39::valid_chars_string(),
+            "0-9 A-Z - . space $ / + %"
+        );
+        assert_eq!(ean13::EAN13::valid_chars_string(), "0-9");
+        assert_eq!(
+            codabar::Codabar::valid_chars_string(),
+            "0-9 - / . : + $ A-D"
+        );
+    }
+
+    #[test]
+    fn supported_symbologies_listed() {
+        assert!(supported_symbologies().contains(&"ean13"));
+        assert!(supported_symbologies().contains(&"code128"));
+        assert_eq!(supported_symbologies(), SUPPORTED_SYMBOLOGIES);
+    }
 }
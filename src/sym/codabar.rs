@@ -4,12 +4,23 @@
 //!
 //! Codabar is used in the USA by `FedEx`, some Hospitals, and photo labs.
 //!
-//! Barcodes of this variant should start and end with either A, B, C, or D depending on
-//! the industry.
+//! Barcodes of this variant must start and end with either A, B, C, or D depending on
+//! the industry, and these may not appear anywhere else in the data.
+//!
+//! `Codabar::for_industry` enforces the start/stop characters conventionally used by an industry.
+//!
+//! An optional check character can be added with `Codabar::with_checksum` and verified when
+//! decoding with `Codabar::decode_verified`.
+//!
+//! Characters are 9 modules wide (digits, '-' and '$'), 10 modules wide (':', '/', '.' and the
+//! start/stop characters) or 12 modules wide ('+'), separated by a 1-module gap. An encoded barcode
+//! is `sum(width) + n - 1` modules wide for `n` characters, including the start/stop characters.
 
-use super::helpers::{vec, Vec};
+use super::helpers::{self, vec, Vec};
 use crate::error::{Error, Result};
-use crate::sym::Parse;
+use crate::sym::{Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::ops::Range;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -36,7 +47,136 @@ enum Unit {
     D,
 }
 
+// Units ordered by their checksum values.
+const UNITS: [Unit; 20] = [
+    Unit::Zero,
+    Unit::One,
+    Unit::Two,
+    Unit::Three,
+    Unit::Four,
+    Unit::Five,
+    Unit::Six,
+    Unit::Seven,
+    Unit::Eight,
+    Unit::Nine,
+    Unit::Dash,
+    Unit::Dollar,
+    Unit::Colon,
+    Unit::Slash,
+    Unit::Point,
+    Unit::Plus,
+    Unit::A,
+    Unit::B,
+    Unit::C,
+    Unit::D,
+];
+
+/// The check character schemes available for Codabar barcodes.
+///
+/// - `Modulo16`: The AIM scheme, summing the values of all characters (including the start
+///   and stop characters).
+/// - `Modulo10`: A 3-1 weighted modulo-10 digit over the numeric data (the rightmost digit
+///   weighted by 3), as used by some libraries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumScheme {
+    /// Modulo-16 over all characters.
+    Modulo16,
+    /// Weighted modulo-10 over the numeric data.
+    Modulo10,
+}
+
+/// Industries which mandate specific start/stop characters for Codabar barcodes.
+///
+/// - `FedEx`: Airbill tracking numbers start with `C` and stop with `D`.
+/// - `PhotoLab`: Photo-finishing envelopes start and stop with `A`.
+/// - `Library`: Library items start with `A` and stop with `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Industry {
+    /// `FedEx` airbills.
+    FedEx,
+    /// Photo-finishing labs.
+    PhotoLab,
+    /// Libraries.
+    Library,
+}
+
+impl Industry {
+    /// Returns the required start and stop characters.
+    #[must_use]
+    pub const fn delimiters(self) -> (char, char) {
+        match self {
+            Self::FedEx => ('C', 'D'),
+            Self::PhotoLab => ('A', 'A'),
+            Self::Library => ('A', 'B'),
+        }
+    }
+}
+
+impl ChecksumScheme {
+    fn check_unit(self, units: &[Unit]) -> Result<Unit> {
+        match self {
+            Self::Modulo16 => {
+                let sum: usize = units.iter().map(|u| u.value()).sum();
+
+                Ok(UNITS[(16 - sum % 16) % 16])
+            }
+            Self::Modulo10 => {
+                let digits = units
+                    .iter()
+                    .filter(|u| !u.is_guard())
+                    .map(|u| u8::try_from(u.value()).ok().filter(|&v| v < 10))
+                    .collect::<Option<Vec<u8>>>()
+                    .ok_or(Error::Character)?;
+
+                Ok(UNITS[usize::from(helpers::modulo_10_checksum(&digits))])
+            }
+        }
+    }
+}
+
 impl Unit {
+    const fn value(self) -> usize {
+        self as usize
+    }
+
+    const fn is_guard(self) -> bool {
+        matches!(self, Self::A | Self::B | Self::C | Self::D)
+    }
+
+    /// Returns the width in modules of the unit's encoding.
+    const fn width(self) -> usize {
+        match self {
+            Self::Colon | Self::Slash | Self::Point | Self::A | Self::B | Self::C | Self::D => 10,
+            Self::Plus => 12,
+            _ => 9,
+        }
+    }
+
+    const fn to_char(self) -> char {
+        match self {
+            Self::Zero => '0',
+            Self::One => '1',
+            Self::Two => '2',
+            Self::Three => '3',
+            Self::Four => '4',
+            Self::Five => '5',
+            Self::Six => '6',
+            Self::Seven => '7',
+            Self::Eight => '8',
+            Self::Nine => '9',
+            Self::Dash => '-',
+            Self::Dollar => '$',
+            Self::Colon => ':',
+            Self::Slash => '/',
+            Self::Point => '.',
+            Self::Plus => '+',
+            Self::A => 'A',
+            Self::B => 'B',
+            Self::C => 'C',
+            Self::D => 'D',
+        }
+    }
+
     fn lookup(self) -> Vec<u8> {
         match self {
             Self::Zero => vec![1, 0, 1, 0, 1, 0, 0, 1, 1],
@@ -97,23 +237,141 @@ impl Codabar {
     /// Creates a new barcode.
     ///
     /// # Errors
-    /// Returns an `Error::Length` if the input string is empty or exceeds the valid length.
-    /// Returns an `Error::Character` if the input string contains invalid characters.
+    /// Returns an `Error::TooShort` if the input string is empty.
+    /// Returns an `Error::TooLong` if the input string exceeds the valid length.
+    /// Returns an `Error::Character` if the input string contains invalid characters, does not
+    /// start and stop with a start/stop character (A-D), or contains one anywhere else.
     ///
     /// # Panics
     /// Panics if an invalid character is encountered during the conversion to `Unit`.
     ///
     /// Returns `Result<Codabar, Error>` indicating parse success.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
-        let d = Self::parse(data.as_ref())?;
+        Self::units(data.as_ref()).map(Codabar)
+    }
+
+    // Runs the same checks as `Codabar::new` without keeping the units.
+    pub(crate) fn validate(data: &str) -> Result<()> {
+        Self::units(data).map(|_| ())
+    }
+
+    fn units(data: &str) -> Result<Vec<Unit>> {
+        let d = Self::parse(data)?;
         let units = d
             .chars()
             .map(|c| Unit::from_char(c).ok_or(Error::Character))
             .collect::<Result<Vec<_>>>()?;
 
+        match &units[..] {
+            [first, inner @ .., last]
+                if first.is_guard() && last.is_guard() && !inner.iter().any(|u| u.is_guard()) =>
+            {
+                Ok(units)
+            }
+            _ => Err(Error::Character),
+        }
+    }
+
+    /// Creates a new barcode, enforcing the start/stop characters required by the given industry.
+    ///
+    /// The data must include the start and stop characters (see `Industry::delimiters`).
+    ///
+    /// # Errors
+    /// Returns the same errors as `Codabar::new`.
+    /// Returns an `Error::Character` if the data does not start and stop with the characters
+    /// required by the industry.
+    pub fn for_industry<T: AsRef<str>>(industry: Industry, data: T) -> Result<Self> {
+        let codabar = Self::new(data)?;
+        let (start, stop) = industry.delimiters();
+
+        match &codabar.0[..] {
+            [first, .., last] if first.to_char() == start && last.to_char() == stop => Ok(codabar),
+            _ => Err(Error::Character),
+        }
+    }
+
+    /// Creates a new barcode with a check character computed using the given scheme.
+    ///
+    /// The check character is placed before the stop character.
+    ///
+    /// # Errors
+    /// Returns the same errors as `Codabar::new`.
+    /// Returns an `Error::Character` if `ChecksumScheme::Modulo10` is used with non-numeric data.
+    pub fn with_checksum<T: AsRef<str>>(data: T, scheme: ChecksumScheme) -> Result<Self> {
+        let mut units = Self::new(data)?.0;
+        let check = scheme.check_unit(&units)?;
+        let at = Self::check_position(&units);
+        units.insert(at, check);
+
         Ok(Self(units))
     }
 
+    /// Decodes a sequence of bars (as produced by `encode`) back into its data.
+    ///
+    /// # Errors
+    /// Returns an `Error::TooShort` if there are no bars.
+    /// Returns an `Error::Character` if the bars contain an unknown character.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        Self::decode_units(bars).map(|units| units.iter().map(|u| u.to_char()).collect())
+    }
+
+    /// Decodes a sequence of bars and verifies the embedded check character.
+    ///
+    /// This is the counterpart of `Codabar::with_checksum`. The check character is removed
+    /// from the returned data.
+    ///
+    /// # Errors
+    /// Returns the same errors as `Codabar::decode`.
+    /// Returns an `Error::TooShort` if there is no check character.
+    /// Returns an `Error::Character` if `ChecksumScheme::Modulo10` is used with non-numeric data.
+    /// Returns an `Error::Checksum` if the check character does not match the data.
+    pub fn decode_verified(bars: &[u8], scheme: ChecksumScheme) -> Result<String> {
+        let mut units = Self::decode_units(bars)?;
+        let at = Self::check_position(&units)
+            .checked_sub(1)
+            .ok_or(Error::TooShort)?;
+        let check = units.remove(at);
+
+        if scheme.check_unit(&units)? != check {
+            return Err(Error::Checksum);
+        }
+
+        Ok(units.iter().map(|u| u.to_char()).collect())
+    }
+
+    // Returns the index just after the data, i.e. the stop character's index (if present).
+    fn check_position(units: &[Unit]) -> usize {
+        match units {
+            [_, .., stop] if stop.is_guard() => units.len() - 1,
+            _ => units.len(),
+        }
+    }
+
+    fn decode_units(bars: &[u8]) -> Result<Vec<Unit>> {
+        let mut units = vec![];
+        let mut rest = bars;
+
+        while !rest.is_empty() {
+            let (unit, len) = UNITS
+                .iter()
+                .map(|&u| (u, u.lookup()))
+                .find(|(_, enc)| {
+                    rest.starts_with(enc) && rest.get(enc.len()).is_none_or(|&b| b == 0)
+                })
+                .map(|(u, enc)| (u, enc.len()))
+                .ok_or(Error::Character)?;
+
+            rest = rest.get(len + 1..).unwrap_or_default();
+            units.push(unit);
+        }
+
+        if units.is_empty() {
+            return Err(Error::TooShort);
+        }
+
+        Ok(units)
+    }
+
     /// Encodes the barcode.
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
@@ -128,8 +386,34 @@ impl Codabar {
             }
         }
 
+        debug_assert_eq!(enc.len(), self.encoded_len());
         enc
     }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        self.0.iter().map(|u| u.width() + 1).sum::<usize>() - 1
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for Codabar {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
+    }
 }
 
 impl Parse for Codabar {
@@ -163,13 +447,20 @@ mod tests {
         chars.collect()
     }
 
+    #[test]
+    fn units_are_unique() {
+        for (i, unit) in UNITS.iter().enumerate() {
+            assert!(!UNITS[i + 1..].contains(unit), "Duplicate unit {unit:?}");
+        }
+    }
+
     #[test]
     fn invalid_length_codabar() {
         let codabar = Codabar::new("");
 
         assert_eq!(
-            codabar.expect_err("Expected an Error::Length but got None"),
-            Error::Length
+            codabar.expect_err("Expected an Error::TooShort but got None"),
+            Error::TooShort
         );
     }
 
@@ -183,6 +474,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inner_delimiters_codabar() {
+        assert_eq!(Codabar::new("A1B2C").err(), Some(Error::Character));
+        assert_eq!(Codabar::new("A12A34B").err(), Some(Error::Character));
+        assert!(Codabar::new("A12C").is_ok());
+        assert!(Codabar::new("AB").is_ok());
+    }
+
+    #[test]
+    fn missing_delimiters_codabar() {
+        for data in ["1234", "A1234", "1234B", "12C4", "A"] {
+            assert_eq!(Codabar::new(data).err(), Some(Error::Character), "{data}");
+        }
+    }
+
     #[test]
     fn codabar_encode() {
         let codabar_a =
@@ -199,4 +505,123 @@ mod tests {
             "10110010010101101001010101001101010110010110101001010010101101010010011"
         );
     }
+
+    #[test]
+    fn codabar_for_industry() {
+        let fedex = Codabar::for_industry(Industry::FedEx, "C1234567890D")
+            .expect("Failed to create FedEx Codabar");
+        let library = Codabar::for_industry(Industry::Library, "A31234567890B")
+            .expect("Failed to create library Codabar");
+
+        assert_eq!(
+            fedex.encode(),
+            Codabar::new("C1234567890D")
+                .expect("Failed to create Codabar")
+                .encode()
+        );
+        assert_eq!(library.0.len(), 13);
+        assert!(Codabar::for_industry(Industry::PhotoLab, "A123A").is_ok());
+    }
+
+    #[test]
+    fn invalid_delimiters_codabar_for_industry() {
+        for data in [
+            "A1234567890B",
+            "C1234567890",
+            "1234567890D",
+            "C12A34D",
+            "D1234C",
+            "C",
+        ] {
+            assert_eq!(
+                Codabar::for_industry(Industry::FedEx, data)
+                    .expect_err("Expected Error::Character"),
+                Error::Character
+            );
+        }
+
+        assert_eq!(
+            Codabar::for_industry(Industry::FedEx, "").expect_err("Expected Error::TooShort"),
+            Error::TooShort
+        );
+    }
+
+    #[test]
+    fn codabar_decode() {
+        let data = "A0123456789-$:/.+B";
+        let codabar = Codabar::new(data).expect("Failed to create Codabar instance");
+
+        assert_eq!(Codabar::decode(&codabar.encode()), Ok(String::from(data)));
+    }
+
+    #[test]
+    fn codabar_checksum() {
+        let codabar_a = Codabar::with_checksum("A37859B", ChecksumScheme::Modulo16)
+            .expect("Failed to create Codabar instance with modulo-16 checksum");
+        let codabar_b = Codabar::with_checksum("A1234B", ChecksumScheme::Modulo10)
+            .expect("Failed to create Codabar instance with modulo-10 checksum");
+
+        assert_eq!(
+            Codabar::decode(&codabar_a.encode()),
+            Ok(String::from("A37859+B"))
+        );
+        assert_eq!(
+            Codabar::decode(&codabar_b.encode()),
+            Ok(String::from("A12348B"))
+        );
+    }
+
+    #[test]
+    fn codabar_encode_with_checksum() {
+        // The AIM example: "A37859B" has the modulo-16 check character '+'.
+        let codabar = Codabar::with_checksum("A37859B", ChecksumScheme::Modulo16)
+            .expect("Failed to create Codabar instance with modulo-16 checksum");
+        let expected = Codabar::new("A37859+B").expect("Failed to create Codabar instance");
+
+        assert_eq!(codabar.encode(), expected.encode());
+        assert_eq!(
+            collapse_vec(&codabar.encode()),
+            "101100100101100101010100101101010011010101101010010110100101010110011001101010010011"
+        );
+    }
+
+    #[test]
+    fn codabar_decode_verified() {
+        let codabar_a = Codabar::with_checksum("A37859B", ChecksumScheme::Modulo16)
+            .expect("Failed to create Codabar instance with modulo-16 checksum");
+        let codabar_b = Codabar::with_checksum("A1234B", ChecksumScheme::Modulo10)
+            .expect("Failed to create Codabar instance with modulo-10 checksum");
+
+        assert_eq!(
+            Codabar::decode_verified(&codabar_a.encode(), ChecksumScheme::Modulo16),
+            Ok(String::from("A37859B"))
+        );
+        assert_eq!(
+            Codabar::decode_verified(&codabar_b.encode(), ChecksumScheme::Modulo10),
+            Ok(String::from("A1234B"))
+        );
+    }
+
+    #[test]
+    fn invalid_checksum_codabar_decode_verified() {
+        let codabar = Codabar::new("A37850+B").expect("Failed to create Codabar instance");
+
+        assert_eq!(
+            Codabar::decode_verified(&codabar.encode(), ChecksumScheme::Modulo16)
+                .expect_err("Expected an Error::Checksum but got None"),
+            Error::Checksum
+        );
+    }
+
+    #[test]
+    fn codabar_encoded_len() {
+        let codabar1 = Codabar::new("A1234B").expect("Failed to create barcode");
+        let codabar2 = Codabar::new("C$+:/.-D").expect("Failed to create barcode");
+        let codabar3 = Codabar::with_checksum("A1234B", ChecksumScheme::Modulo16)
+            .expect("Failed to create barcode");
+
+        assert_eq!(codabar1.encoded_len(), codabar1.encode().len());
+        assert_eq!(codabar2.encoded_len(), codabar2.encode().len());
+        assert_eq!(codabar3.encoded_len(), codabar3.encode().len());
+    }
 }
@@ -5,11 +5,17 @@
 //!
 //! Code11 is a discrete symbology. This encoder always provides a C checksum. For barcodes longer
 //! than 10 characters, a second checksum digit (K) is appended.
-
-use crate::error::Result;
-use crate::sym::{helpers, Parse};
+//!
+//! Characters are 6 modules wide ('0', '9' and '-') or 7 modules wide (all others), each followed
+//! by a 1-module separator. An encoded barcode is `15 + sum(width + 1)` modules wide, summed over
+//! the data and checksum characters, with two 7-module guards and a leading separator.
+
+use crate::error::{Error, Result};
+use crate::sym::{helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::ops::Range;
-use helpers::{vec, Vec};
+use helpers::{vec, CharTable, Modulo11, Vec};
 
 // Character -> Binary mappings for each of the allowable characters.
 // The special "full-ASCII" characters are represented with (, ), [, ].
@@ -27,6 +33,8 @@ const CHARS: [(char, &[u8]); 11] = [
     ('-', &[1, 0, 1, 1, 0, 1]),
 ];
 
+const TABLE: CharTable<&[u8]> = CharTable::new(&CHARS);
+
 // Code11 barcodes must start and end with a special character.
 const GUARD: [u8; 7] = [1, 0, 1, 1, 0, 0, 1];
 const SEPARATOR: [u8; 1] = [0];
@@ -52,40 +60,23 @@ impl Code11 {
     }
 
     fn char_encoding(c: char) -> &'static [u8] {
-        match CHARS.iter().find(|&ch| ch.0 == c) {
-            Some(&(_, enc)) => enc,
-            None => panic!("Unknown char: {c}"),
-        }
+        TABLE
+            .encoding(c)
+            .unwrap_or_else(|| panic!("Unknown char: {c}"))
     }
 
     /// Calculates a checksum character using a weighted modulo-11 algorithm.
-    fn checksum_char(data: &[char], weight_threshold: usize) -> Option<char> {
-        let get_char_pos = |&c| {
-            CHARS
-                .iter()
-                .position(|t| t.0 == c)
-                .expect("Character not found in CHARS mapping")
-        };
-        let weight = |i| match i % weight_threshold {
-            0 => weight_threshold,
-            n => n,
-        };
-        let positions = data.iter().map(&get_char_pos);
-        let index = positions
-            .rev()
-            .enumerate()
-            .fold(0, |acc, (i, pos)| acc + (weight(i + 1) * pos));
-
+    fn checksum_char(data: &[char], algorithm: Modulo11) -> Option<char> {
         // Some sources suggest that the C checksum should use modulo-11, whilst the K
         // checksum should use modulo-9. But most generators always use modulo-11.
         // This algorithm currently just uses 11 for both checksums, but can be easily
         // changed at a later date.
-        CHARS.get(index % CHARS.len()).map(|&(c, _)| c)
+        TABLE.checksum(data, &algorithm)
     }
 
     /// Calculates the C checksum character using a weighted modulo-11 algorithm.
     fn c_checksum_char(&self) -> Option<char> {
-        Self::checksum_char(&self.0, 10)
+        Self::checksum_char(&self.0, Modulo11::C)
     }
 
     /// Calculates the K checksum character using a weighted modulo-11 algorithm.
@@ -93,23 +84,21 @@ impl Code11 {
         let mut data: Vec<char> = self.0.clone();
         data.push(c_checksum);
 
-        Self::checksum_char(&data, 9)
+        Self::checksum_char(&data, Modulo11::K)
     }
 
-    fn push_encoding(into: &mut Vec<u8>, from: &[u8]) {
-        into.extend(from.iter().copied());
-        into.extend(&SEPARATOR);
+    /// Returns the width in modules of a character's encoding, without its separator.
+    const fn char_width(c: char) -> usize {
+        match c {
+            '0' | '9' | '-' => 6,
+            _ => 7,
+        }
     }
 
-    fn payload(&self) -> Vec<u8> {
-        let mut enc = vec![];
+    /// Returns the checksum characters: C, followed by K for data longer than 10 characters.
+    fn checksum_chars(&self) -> Vec<char> {
         let c_checksum = self.c_checksum_char().expect("Cannot compute checksum C");
-
-        for &c in &self.0 {
-            Self::push_encoding(&mut enc, Self::char_encoding(c));
-        }
-
-        Self::push_encoding(&mut enc, Self::char_encoding(c_checksum));
+        let mut checksums = vec![c_checksum];
 
         // K-checksum is only appended on barcodes greater than 10 characters.
         if self.0.len() > 10 {
@@ -117,7 +106,22 @@ impl Code11 {
                 .k_checksum_char(c_checksum)
                 .expect("Cannot compute checksum K");
 
-            Self::push_encoding(&mut enc, Self::char_encoding(k_checksum));
+            checksums.push(k_checksum);
+        }
+
+        checksums
+    }
+
+    fn push_encoding(into: &mut Vec<u8>, from: &[u8]) {
+        into.extend(from.iter().copied());
+        into.extend(&SEPARATOR);
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        let mut enc = vec![];
+
+        for &c in self.0.iter().chain(self.checksum_chars().iter()) {
+            Self::push_encoding(&mut enc, Self::char_encoding(c));
         }
 
         enc
@@ -125,11 +129,98 @@ impl Code11 {
 
     /// Encodes the barcode.
     /// Returns a Vec<u8> of encoded binary digits.
+    ///
+    /// The start guard and every character are followed by a narrow separating space. The stop
+    /// guard is not, so the barcode ends on its final bar and the quiet zone follows directly,
+    /// as in other encoders (e.g. Zint). A trailing space would be indistinguishable from the
+    /// quiet zone, so none is added.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
         let guard = &GUARD[..];
 
-        helpers::join_slices(&[guard, &SEPARATOR, &self.payload()[..], guard][..])
+        let enc = helpers::join_slices(&[guard, &SEPARATOR, &self.payload()[..], guard][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        self.0
+            .iter()
+            .chain(self.checksum_chars().iter())
+            .map(|&c| Self::char_width(c) + 1)
+            .sum::<usize>()
+            + 15
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its data.
+    ///
+    /// The C checksum (and the K checksum, for data longer than 10 characters) is verified and
+    /// removed from the returned data.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the guards are missing or a character pattern is unknown.
+    /// Returns an `Error::Length` if the number of characters is inconsistent with the checksums.
+    /// Returns an `Error::Checksum` if a checksum character does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        let mut rest = bars
+            .strip_prefix(&GUARD[..])
+            .and_then(|b| b.strip_prefix(&SEPARATOR[..]))
+            .and_then(|b| b.strip_suffix(&GUARD[..]))
+            .ok_or(Error::Character)?;
+        let mut chars: Vec<char> = vec![];
+
+        while !rest.is_empty() {
+            let is_next =
+                |enc: &[u8]| rest.starts_with(enc) && rest[enc.len()..].starts_with(&SEPARATOR);
+            let &(c, enc) = TABLE
+                .entries()
+                .iter()
+                .find(|(_, enc)| is_next(enc))
+                .ok_or(Error::Character)?;
+
+            chars.push(c);
+            rest = &rest[enc.len() + SEPARATOR.len()..];
+        }
+
+        let data_len = match chars.len() {
+            n @ 2..=11 => n - 1,
+            n if n > 12 => n - 2,
+            _ => return Err(Error::Length),
+        };
+        let code11 = Self(chars[..data_len].to_vec());
+        let c_checksum = code11.c_checksum_char().ok_or(Error::Checksum)?;
+
+        if chars[data_len] != c_checksum {
+            return Err(Error::Checksum);
+        }
+
+        if data_len > 10 && Some(chars[data_len + 1]) != code11.k_checksum_char(c_checksum) {
+            return Err(Error::Checksum);
+        }
+
+        Ok(code11.0.into_iter().collect())
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for Code11 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -142,8 +233,7 @@ impl Parse for Code11 {
 
     /// Returns the set of valid characters allowed in this type of barcode.
     fn valid_chars() -> Vec<char> {
-        let (chars, _): (Vec<_>, Vec<_>) = CHARS.iter().copied().unzip();
-        chars
+        TABLE.chars()
     }
 }
 
@@ -162,13 +252,37 @@ mod tests {
         chars.collect()
     }
 
+    #[test]
+    fn chars_are_unique() {
+        assert!(TABLE.is_unique());
+    }
+
+    #[test]
+    fn code11_termination_matches_reference() {
+        // Element widths from a reference encoder (Zint): the start guard and each character
+        // end with a narrow space, and the stop guard ends with a bar.
+        let widths = [
+            "112211", "211121", "121121", "221111", "112111", "112121", "212111", "212111", "11221",
+        ];
+        let reference: Vec<u8> = widths
+            .concat()
+            .bytes()
+            .zip([1, 0].into_iter().cycle())
+            .flat_map(|(w, module)| core::iter::repeat_n(module, usize::from(w - b'0')))
+            .collect();
+        let code11 = Code11::new("123-45").expect("Failed to create Code11 barcode");
+
+        assert_eq!(code11.encode(), reference);
+        assert_eq!(code11.encode().last(), Some(&1));
+    }
+
     #[test]
     fn invalid_length_code11() {
         let code11 = Code11::new("");
 
         assert_eq!(
-            code11.expect_err("Expected an Error::Length but got None"),
-            Error::Length
+            code11.expect_err("Expected an Error::TooShort but got None"),
+            Error::TooShort
         );
     }
 
@@ -209,4 +323,65 @@ mod tests {
 
         assert_eq!(collapse_vec(&code111.encode()), "101100101101011010010110110010101011011010110101101101010011010101001101101001010110101011011011001010100101101101011011011010100110101011001");
     }
+
+    #[test]
+    fn code11_checksum_char() {
+        let code11 = Code11::new("123-45").expect("Failed to create Code11 barcode for '123-45'");
+
+        // (5 * 1) + (4 * 2) + (10 * 3) + (3 * 4) + (2 * 5) + (1 * 6) = 71 (mod 11).
+        assert_eq!(code11.c_checksum_char(), Some('5'));
+    }
+
+    #[test]
+    fn code11_decode() {
+        for data in ["123-45", "666", "12-9", "1234-5678-4321"] {
+            let code11 = Code11::new(data).expect("Failed to create Code11 barcode");
+
+            assert_eq!(Code11::decode(&code11.encode()), Ok(String::from(data)));
+        }
+    }
+
+    #[test]
+    fn invalid_checksum_code11_decode() {
+        let bars = |chars: &[char]| {
+            let mut enc = vec![];
+
+            for &c in chars {
+                Code11::push_encoding(&mut enc, Code11::char_encoding(c));
+            }
+
+            helpers::join_slices(&[&GUARD[..], &SEPARATOR, &enc[..], &GUARD[..]][..])
+        };
+        let short = Code11::new("123-45").expect("Failed to create Code11 barcode");
+        let long = Code11::new("1234-5678-4321").expect("Failed to create Code11 barcode");
+        let c = long.c_checksum_char().expect("Cannot compute checksum C");
+        let k = long.k_checksum_char(c).expect("Cannot compute checksum K");
+        let wrong = |d| if d == '0' { '1' } else { '0' };
+        let mut short_chars = short.0.clone();
+        let mut long_chars = long.0;
+        short_chars.push(wrong(
+            short.c_checksum_char().expect("Cannot compute checksum C"),
+        ));
+        long_chars.extend([c, wrong(k)]);
+
+        assert_eq!(
+            Code11::decode(&bars(&short_chars))
+                .expect_err("Expected an Error::Checksum but got None"),
+            Error::Checksum
+        );
+        assert_eq!(
+            Code11::decode(&bars(&long_chars))
+                .expect_err("Expected an Error::Checksum but got None"),
+            Error::Checksum
+        );
+    }
+
+    #[test]
+    fn code11_encoded_len() {
+        let code111 = Code11::new("123-45").expect("Failed to create barcode");
+        let code112 = Code11::new("0123456789-99").expect("Failed to create barcode");
+
+        assert_eq!(code111.encoded_len(), code111.encode().len());
+        assert_eq!(code112.encoded_len(), code112.encode().len());
+    }
 }
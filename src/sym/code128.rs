@@ -35,6 +35,9 @@
 //!
 //! <ul><li>ÀHE@$AĆ123456</li></ul>
 //!
+//! Alternatively, `Code128::with_auto` takes the data without any of these characters and chooses
+//! the character sets itself, producing the shortest barcode for the data.
+//!
 //! ## Unicode characters
 //!
 //! The invisible unicode characters that are available in character set A should be represented as
@@ -51,13 +54,32 @@
 //! - FNC3: ```Ż``` (```\u{017B}```)
 //! - FNC4: ```ż``` (```\u{017C}```)
 //! - SHIFT: ```Ž``` (```\u{017D}```)
+//!
+//! Character-set C only supports FNC1.
+//!
+//! ## GS1-128
+//!
+//! GS1-128 barcodes start with FNC1, followed by GS1 application identifiers (AIs) and their
+//! data. `Code128::sscc` and `Code128::gtin14` build the two most common logistics barcodes, for
+//! AI (00) and AI (01), computing the check digit if it is not given:
+//!
+//! <ul><li>ĆŹ00106141411234567897</li></ul>
+//!
+//! ## Width
+//!
+//! An encoded barcode is `11 * symbols + 13` modules wide, where `symbols` counts the start
+//! symbol, each data symbol (including character-set switches and FNC characters) and the check
+//! symbol. The 13 modules are the STOP pattern and its terminating bar. See
+//! `Code128::symbol_count`.
 
 use crate::error::{Error, Result};
-use crate::sym::helpers;
+use crate::sym::{helpers, Barcode};
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::ToString};
-use core::cmp;
-use helpers::{vec, Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use helpers::{vec, Checksum, Modulo103, Vec};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct Unit {
@@ -77,6 +99,12 @@ enum UnitKind {
 
 type Encoding = [u8; 11];
 
+/// The number of digits in an SSCC, including the check digit.
+const SSCC_LEN: usize = 18;
+
+/// The number of digits in a GTIN-14, including the check digit.
+const GTIN14_LEN: usize = 14;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 /// Represents the character sets available in Code128 barcodes.
 /// Using special characters to switch between character sets is still supported.
@@ -230,12 +258,23 @@ const CHARS: [([&str; 3], Encoding); 106] = [
     ),
 ];
 
+// The number of leading entries in `CHARS` which are data characters in sets A and B.
+const DATA_CHARS: usize = 96;
+
 // Stop sequence.
 const STOP: Encoding = [1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 0];
 
 // Termination sequence.
 const TERM: [u8; 2] = [1, 1];
 
+// The character sets chosen between by `Code128::with_auto`, in order of preference when tied,
+// along with the characters which switch to them.
+const AUTO_SETS: [(CharacterSet, char); 3] = [
+    (CharacterSet::B, 'Ɓ'),
+    (CharacterSet::A, 'À'),
+    (CharacterSet::C, 'Ć'),
+];
+
 /// The Code128 barcode type.
 ///
 /// # Character sets
@@ -257,9 +296,48 @@ impl Unit {
     const fn index(&self) -> usize {
         self.index
     }
+
+    /// Returns the character set selected by this unit, if it is a start or CODE symbol.
+    const fn selected_set(&self) -> Option<CharacterSet> {
+        match (self.kind, self.index) {
+            (_, 103) | (UnitKind::B | UnitKind::C, 101) => Some(CharacterSet::A),
+            (_, 104) | (UnitKind::A | UnitKind::C, 100) => Some(CharacterSet::B),
+            (_, 105) | (UnitKind::A | UnitKind::B, 99) => Some(CharacterSet::C),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of input characters this unit encodes.
+    const fn char_count(&self) -> usize {
+        match (self.kind, self.index) {
+            (UnitKind::C, 0..=99) => 2,
+            _ => 1,
+        }
+    }
 }
 
 impl CharacterSet {
+    /// Returns whether the given data character can be encoded in this character set.
+    ///
+    /// Set C encodes pairs of digits, so any ASCII digit is encodable in it. The special
+    /// characters (shifts, set switches and function codes) are not data, and so are never
+    /// encodable. `None` encodes nothing.
+    #[must_use]
+    pub fn can_encode(self, c: char) -> bool {
+        let Ok(p) = self.index() else {
+            return false;
+        };
+
+        if self == Self::C {
+            return c.is_ascii_digit();
+        }
+
+        let mut buf = [0; 4];
+        let s: &str = c.encode_utf8(&mut buf);
+
+        CHARS[..DATA_CHARS].iter().any(|chars| chars.0[p] == s)
+    }
+
     const fn from_char(c: char) -> Result<Self> {
         match c {
             'À' => Ok(Self::A),
@@ -288,27 +366,21 @@ impl CharacterSet {
         }
     }
 
-    fn lookup(self, s: &str) -> Result<Unit> {
-        let p = self.index()?;
-
-        // Handle FNC characters explicitly - they work in any character set
-        if let Some(index) = match s {
-            "Ź" => Some(102), // FNC1 - maps to CHARS[102]
-            "ź" => Some(97),  // FNC2 - maps to CHARS[97] 
-            "Ż" => Some(96),  // FNC3 - maps to CHARS[96]
-            "ż" => Some(100), // FNC4 - maps to CHARS[100] (was 101, should be 100)
+    /// Returns the number of characters at the start of `rest` which this set encodes as a single
+    /// symbol, if it can encode them at all.
+    fn symbol_len(self, rest: &[char]) -> Option<usize> {
+        match (self, rest) {
+            (Self::C, [a, b, ..]) if a.is_ascii_digit() && b.is_ascii_digit() => Some(2),
+            (Self::C, ['\u{0179}', ..]) => Some(1),
+            (Self::C, _) => None,
+            (_, ['\u{0179}' | '\u{017A}' | '\u{017B}' | '\u{017C}', ..]) => Some(1),
+            (_, [c, ..]) if self.can_encode(*c) => Some(1),
             _ => None,
-        } {
-            return Ok(Unit { 
-                kind: match self {
-                    Self::A => UnitKind::A,
-                    Self::B => UnitKind::B, 
-                    Self::C => UnitKind::C,
-                    Self::None => return Err(Error::Character),
-                }, 
-                index 
-            });
         }
+    }
+
+    fn lookup(self, s: &str) -> Result<Unit> {
+        let p = self.index()?;
 
         CHARS
             .iter()
@@ -322,28 +394,154 @@ impl Code128 {
     ///
     /// # Errors
     ///
-    /// Returns an `Error::Length` if the input data is too short.
+    /// Returns an `Error::TooShort` if the input data is too short.
     /// Returns an `Error::Character` if the input data contains invalid characters or an invalid character set.
+    /// Returns an `Error::CharacterSet` if `character_set` is `CharacterSet::None` and the data
+    /// does not start with a character-set switch ("À", "Ɓ" or "Ć").
     ///
     /// # Returns
     ///
     /// A `Result` containing the `Code128` barcode on success.
     pub fn new<T: AsRef<str>>(data: T, character_set: CharacterSet) -> Result<Self> {
-        let data = data.as_ref();
+        Self::units(data.as_ref(), character_set).map(Code128)
+    }
 
-        if data.len() < 2 {
-            return Err(Error::Length);
+    // Runs the same checks as `Code128::new` without keeping the tokenized data.
+    pub(crate) fn validate(data: &str, character_set: CharacterSet) -> Result<()> {
+        Self::units(data, character_set).map(|_| ())
+    }
+
+    fn units(data: &str, character_set: CharacterSet) -> Result<Vec<Unit>> {
+        if data.chars().count() < 2 {
+            return Err(Error::TooShort);
+        }
+
+        if character_set == CharacterSet::None && !data.starts_with(['À', 'Ɓ', 'Ć']) {
+            return Err(Error::CharacterSet);
         }
 
         // Append a letter depending on the character-set, or nothing for CharacterSet::None.
         let data = match character_set {
-            CharacterSet::A => format!("À{data}"), // Character set A
-            CharacterSet::B => format!("Ɓ{data}"), // Character set B
-            CharacterSet::C => format!("Ć{data}"), // Character set C
+            CharacterSet::A => format!("À{data}"),  // Character set A
+            CharacterSet::B => format!("Ɓ{data}"),  // Character set B
+            CharacterSet::C => format!("Ć{data}"),  // Character set C
             CharacterSet::None => data.to_string(), // No character set
         };
 
-        Self::parse(data.chars().collect()).map(Code128)
+        Self::parse(data.chars().collect())
+    }
+
+    /// Creates a new barcode, choosing the character sets automatically.
+    ///
+    /// The data must not contain the character-set switches (or SHIFT), as the shortest sequence
+    /// of character sets for the data is chosen instead. In practice this means runs of four or
+    /// more digits are encoded in set C, lowercase letters in set B and control characters in
+    /// set A. FNC characters are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::TooShort` if the input data is empty.
+    /// Returns an `Error::Character` if the input data contains characters which no character set
+    /// can encode.
+    pub fn with_auto(data: &str) -> Result<Self> {
+        let chars: Vec<char> = data.chars().collect();
+
+        if chars.is_empty() {
+            return Err(Error::TooShort);
+        }
+
+        Self::parse(Self::auto_longhand(&chars)?).map(Code128)
+    }
+
+    /// Creates a new GS1-128 barcode for a Serial Shipping Container Code (SSCC), AI (00).
+    ///
+    /// The data is the 17 digits of the SSCC, in which case the check digit is computed, or all
+    /// 18 digits, in which case the check digit is verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the data is not 17 or 18 characters long.
+    /// Returns an `Error::Character` if the data contains characters that are not digits.
+    /// Returns an `Error::Checksum` if the check digit does not match the data.
+    pub fn sscc<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::gs1_fixed("00", data.as_ref(), SSCC_LEN)
+    }
+
+    /// Creates a new GS1-128 barcode for a Global Trade Item Number (GTIN-14), AI (01).
+    ///
+    /// The data is the 13 digits of the GTIN-14, in which case the check digit is computed, or
+    /// all 14 digits, in which case the check digit is verified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if the data is not 13 or 14 characters long.
+    /// Returns an `Error::Character` if the data contains characters that are not digits.
+    /// Returns an `Error::Checksum` if the check digit does not match the data.
+    pub fn gtin14<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::gs1_fixed("01", data.as_ref(), GTIN14_LEN)
+    }
+
+    // Encodes a fixed-length, modulo-10 checked AI in set C, after the leading FNC1.
+    fn gs1_fixed(ai: &str, data: &str, len: usize) -> Result<Self> {
+        if data.len() != len && data.len() != len - 1 {
+            return Err(Error::Length);
+        }
+
+        let digits = data
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+        let check = helpers::modulo_10_checksum(&digits[..len - 1]);
+
+        if digits.len() == len && digits[len - 1] != check {
+            return Err(Error::Checksum);
+        }
+
+        let body = &data[..len - 1];
+
+        Self::new(format!("Ź{ai}{body}{check}"), CharacterSet::C)
+    }
+
+    // Inserts the character-set switches which give the fewest symbols for the data.
+    fn auto_longhand(chars: &[char]) -> Result<Vec<char>> {
+        // best[i][k] is the fewest symbols encoding chars[i..] while in AUTO_SETS[k], along with
+        // the set to encode the next symbol in and the number of characters that symbol takes.
+        let mut best = vec![[None; 3]; chars.len() + 1];
+        best[chars.len()] = [Some((0, 0, 0)); 3];
+
+        for i in (0..chars.len()).rev() {
+            let stay: [Option<(usize, usize)>; 3] = core::array::from_fn(|k| {
+                let len = AUTO_SETS[k].0.symbol_len(&chars[i..])?;
+                best[i + len][k].map(|(cost, _, _)| (cost + 1, len))
+            });
+
+            // Staying in the current set is preferred over switching for the same cost.
+            best[i] = core::array::from_fn(|k| {
+                core::iter::once(k)
+                    .chain((0..3).filter(|&t| t != k))
+                    .filter_map(|t| stay[t].map(|(cost, len)| (cost + usize::from(t != k), t, len)))
+                    .min_by_key(|&(cost, _, _)| cost)
+            });
+        }
+
+        let (_, mut k) = (0..3)
+            .filter_map(|k| best[0][k].map(|(cost, _, _)| (cost, k)))
+            .min_by_key(|&(cost, _)| cost)
+            .ok_or(Error::Character)?;
+        let mut longhand = vec![AUTO_SETS[k].1];
+        let mut i = 0;
+
+        while let Some((_, next, len)) = best[i][k].filter(|_| i < chars.len()) {
+            if next != k {
+                longhand.push(AUTO_SETS[next].1);
+                k = next;
+            }
+
+            longhand.extend_from_slice(&chars[i..i + len]);
+            i += len;
+        }
+
+        Ok(longhand)
     }
 
     // Tokenizes and collects the data into the appropriate character-sets.
@@ -380,34 +578,9 @@ impl Code128 {
                         carry = None;
                     }
                 },
-                // Handle FNC characters explicitly - these can be used in any character set
-                'Ź' | 'ź' | 'Ż' | 'ż' => {
-                    // If no character set is set yet, we need to reject the input
-                    if char_set == CharacterSet::None {
-                        return Err(Error::Character);
-                    }
-                    
-                    // FNC characters can be used in any character set
-                    let index = match ch {
-                        'Ź' => 102, // FNC1
-                        'ź' => 97,  // FNC2
-                        'Ż' => 96,  // FNC3 
-                        'ż' => 100, // FNC4 - corrected from 101 to 100
-                        _ => unreachable!(),
-                    };
-                    
-                    let kind = match char_set {
-                        CharacterSet::A => UnitKind::A,
-                        CharacterSet::B => UnitKind::B,
-                        CharacterSet::C => UnitKind::C,
-                        CharacterSet::None => return Err(Error::Character),
-                    };
-                    
-                    units.push(Unit { kind, index });
-                }
                 _ => {
                     if char_set == CharacterSet::None {
-                        return Err(Error::Character);
+                        return Err(Error::CharacterSet);
                     }
                     let u = char_set.lookup(&ch.to_string())?;
                     units.push(u);
@@ -423,13 +596,10 @@ impl Code128 {
 
     /// Calculates the checksum index using a modulo-103 algorithm.
     fn checksum_value(&self) -> u8 {
-        let sum: usize = self
-            .0
-            .iter()
-            .zip(0..self.0.len())
-            .fold(0, |t, (u, i)| t + (u.index() * cmp::max(1, i)));
+        let indices: Vec<usize> = self.0.iter().map(Unit::index).collect();
 
-        (sum % 103)
+        Modulo103
+            .compute(&indices)
             .try_into()
             .expect("Checksum value should always be non-negative and fit in u8")
     }
@@ -456,14 +626,130 @@ impl Code128 {
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
-        helpers::join_slices(
+        let enc = helpers::join_slices(
             &[
                 &self.payload()[..],
                 &self.checksum_encoding()[..],
                 &STOP[..],
                 &TERM[..],
             ][..],
-        )
+        );
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        11 * self.symbol_count() + 13
+    }
+
+    /// Returns the number of symbols in the barcode: the start symbol, the data symbols
+    /// (including any character-set switches and FNC characters) and the check symbol.
+    ///
+    /// The STOP pattern is not counted. The encoded width is `11 * symbol_count() + 13` modules.
+    #[must_use]
+    pub const fn symbol_count(&self) -> usize {
+        self.0.len() + 1
+    }
+
+    /// Returns the character sets used by the barcode, in order, along with the number of input
+    /// characters encoded in each before the next switch.
+    ///
+    /// Set C counts both digits of each pair, and FNC and SHIFT characters count as one. This
+    /// is useful for understanding why a barcode is as wide as it is.
+    #[must_use]
+    pub fn encoding_plan(&self) -> Vec<(CharacterSet, usize)> {
+        let mut plan: Vec<(CharacterSet, usize)> = vec![];
+
+        for unit in &self.0 {
+            match (unit.selected_set(), plan.last_mut()) {
+                (Some(set), _) => plan.push((set, 0)),
+                (None, Some((_, count))) => *count += unit.char_count(),
+                (None, None) => {}
+            }
+        }
+
+        plan
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its data.
+    ///
+    /// The result uses the same Unicode syntax accepted by `new` with `CharacterSet::None`:
+    /// it starts with the character-set marker and retains any character-set switches,
+    /// FNC characters and SHIFT, so that decoding an encoded barcode yields the original data.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the bars do not form a whole number of symbols.
+    /// Returns an `Error::Character` if a symbol is unknown or out of place.
+    /// Returns an `Error::Checksum` if the check symbol does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        let body = bars
+            .strip_suffix(&TERM[..])
+            .and_then(|b| b.strip_suffix(&STOP[..]))
+            .ok_or(Error::Character)?;
+
+        if body.len() % STOP.len() != 0 {
+            return Err(Error::Length);
+        }
+
+        let indices = body
+            .chunks(STOP.len())
+            .map(|chunk| {
+                CHARS
+                    .iter()
+                    .position(|c| c.1[..] == *chunk)
+                    .ok_or(Error::Character)
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        let (&check, symbols) = indices.split_last().ok_or(Error::Length)?;
+        let (&start, data) = symbols.split_first().ok_or(Error::Length)?;
+        if Modulo103.compute(symbols) != check {
+            return Err(Error::Checksum);
+        }
+
+        let marker = CHARS[start].0[0]
+            .strip_prefix("START-")
+            .ok_or(Error::Character)?;
+        let mut char_set = CharacterSet::from_char(marker.parse().map_err(|_| Error::Character)?)?;
+        let mut decoded = String::from(marker);
+
+        for &i in data {
+            let s = CHARS[i].0[char_set.index()?];
+
+            if s.starts_with("START-") {
+                return Err(Error::Character);
+            }
+
+            if let Some(set) = s.parse().ok().and_then(|c| CharacterSet::from_char(c).ok()) {
+                char_set = set;
+            }
+
+            decoded.push_str(s);
+        }
+
+        Ok(decoded)
+    }
+}
+
+impl Barcode for Code128 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -482,6 +768,47 @@ mod tests {
         chars.collect()
     }
 
+    #[test]
+    fn chars_are_unique() {
+        for (i, (chars, encoding)) in CHARS.iter().enumerate() {
+            for (other_chars, other_encoding) in &CHARS[i + 1..] {
+                assert_ne!(encoding, other_encoding, "Duplicate encoding for {chars:?}");
+
+                for set in 0..3 {
+                    assert_ne!(
+                        chars[set], other_chars[set],
+                        "Duplicate character in set {set}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn character_set_can_encode() {
+        for c in 'a'..='z' {
+            assert!(CharacterSet::B.can_encode(c), "{c:?}");
+            assert!(!CharacterSet::A.can_encode(c), "{c:?}");
+            assert!(!CharacterSet::C.can_encode(c), "{c:?}");
+        }
+
+        for c in '\u{0000}'..='\u{001F}' {
+            assert!(CharacterSet::A.can_encode(c), "{c:?}");
+            assert!(!CharacterSet::B.can_encode(c), "{c:?}");
+            assert!(!CharacterSet::C.can_encode(c), "{c:?}");
+        }
+
+        assert!(CharacterSet::A.can_encode('Z'));
+        assert!(CharacterSet::B.can_encode('Z'));
+        assert!(CharacterSet::C.can_encode('7'));
+        assert!(!CharacterSet::C.can_encode('Z'));
+        // Special characters and unsupported characters are not data.
+        assert!(!CharacterSet::A.can_encode('Ć'));
+        assert!(!CharacterSet::B.can_encode('Ź'));
+        assert!(!CharacterSet::B.can_encode('é'));
+        assert!(!CharacterSet::None.can_encode('A'));
+    }
+
     #[test]
     fn new_code128() {
         let code128_a = Code128::new(" !! Ć0201", CharacterSet::A);
@@ -496,9 +823,24 @@ mod tests {
         let code128_a = Code128::new("", CharacterSet::None);
 
         assert_eq!(
-            code128_a.expect_err("Expected Error::Length but got None"),
-            Error::Length
+            code128_a.expect_err("Expected Error::TooShort but got None"),
+            Error::TooShort
+        );
+    }
+
+    #[test]
+    fn code128_length_counts_characters() {
+        // One character, but two bytes.
+        assert_eq!(
+            Code128::new("Ć", CharacterSet::None).err(),
+            Some(Error::TooShort)
         );
+        assert_eq!(
+            Code128::new("Ź", CharacterSet::A).err(),
+            Some(Error::TooShort)
+        );
+        assert!(Code128::new("Ć12", CharacterSet::None).is_ok());
+        assert!(Code128::new("ŹA", CharacterSet::A).is_ok());
     }
 
     #[test]
@@ -506,6 +848,7 @@ mod tests {
         let code128_a = Code128::new("☺ ", CharacterSet::A); // Unknown character.
         let code128_b = Code128::new("HELLOĆ12352", CharacterSet::A); // Trailing carry at the end.
         let code128_c = Code128::new("HELLO", CharacterSet::None); // No Character-Set specified.
+        let code128_d = Code128::new("12Ć34", CharacterSet::None); // Switch after the start.
 
         assert_eq!(
             code128_a.expect_err("Expected Error::Character but got None"),
@@ -516,8 +859,16 @@ mod tests {
             Error::Character
         );
         assert_eq!(
-            code128_c.expect_err("Expected Error::Character but got None"),
-            Error::Character
+            code128_c.expect_err("Expected Error::CharacterSet but got None"),
+            Error::CharacterSet
+        );
+        assert_eq!(
+            code128_d.expect_err("Expected Error::CharacterSet but got None"),
+            Error::CharacterSet
+        );
+        assert_eq!(
+            Error::CharacterSet.to_string(),
+            "No starting character set specified"
         );
     }
 
@@ -546,6 +897,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn code128_encode_control_chars() {
+        // Reference bar/space widths of the symbol values 64 (NUL) to 95 (US) in set A.
+        const WIDTHS: [&str; 32] = [
+            "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+            "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111",
+            "111242", "121142", "121241", "114212", "124112", "124211", "411212", "421112",
+            "421211", "212141", "214121", "412121", "111143", "111341", "131141", "114113",
+        ];
+        let modules = |widths: &str| -> Vec<u8> {
+            widths
+                .bytes()
+                .enumerate()
+                .flat_map(|(i, w)| {
+                    core::iter::repeat_n(u8::from(i % 2 == 0), usize::from(w - b'0'))
+                })
+                .collect()
+        };
+
+        for (c, widths) in ('\u{0000}'..='\u{001F}').zip(WIDTHS) {
+            let data = format!("À{c}");
+            let code128 = Code128::new(&data, CharacterSet::None)
+                .expect("Failed to create Code128 barcode with a control character");
+            let encoded = code128.encode();
+
+            assert_eq!(&encoded[11..22], &modules(widths)[..], "{c:?}");
+            assert_eq!(Code128::decode(&encoded), Ok(data));
+        }
+    }
+
     #[test]
     fn code128_encode_fnc_chars() {
         let code128_a = Code128::new("Ź4218402050À0", CharacterSet::C)
@@ -554,6 +935,259 @@ mod tests {
         assert_eq!(collapse_vec(&code128_a.encode()), "110100111001111010111010110111000110011100101100010100011001001110110001011101110101111010011101100101011110001100011101011");
     }
 
+    #[test]
+    fn code128_decode_fnc_chars() {
+        let data = "ĆŹ4218402050À0";
+        let code128_a = Code128::new(data, CharacterSet::None)
+            .expect("Failed to create Code128 barcode with FNC characters");
+        let code128_b = Code128::new("ƁAźBŻCżDŽEŹ", CharacterSet::None)
+            .expect("Failed to create Code128 barcode with FNC characters");
+        let code128_c = Code128::new("ÀAżB", CharacterSet::None)
+            .expect("Failed to create Code128 barcode with FNC4 in character-set A");
+
+        assert_eq!(Code128::decode(&code128_a.encode()), Ok(String::from(data)));
+        assert_eq!(
+            Code128::decode(&code128_b.encode()),
+            Ok(String::from("ƁAźBŻCżDŽEŹ"))
+        );
+        assert_eq!(
+            Code128::decode(&code128_c.encode()),
+            Ok(String::from("ÀAżB"))
+        );
+    }
+
+    #[test]
+    fn code128_decode_start_codes() {
+        // Builds the bars of the given start symbol followed by the symbol with value 65.
+        let bars = |start: usize| {
+            let check = (start + 65) % 103;
+
+            [
+                &CHARS[start].1[..],
+                &CHARS[65].1,
+                &CHARS[check].1,
+                &STOP,
+                &TERM,
+            ]
+            .concat()
+        };
+
+        // The same symbol is interpreted according to the set selected by the start symbol.
+        let starts = [
+            ("START-À", "À\u{0001}"),
+            ("START-Ɓ", "Ɓa"),
+            ("START-Ć", "Ć65"),
+        ];
+
+        for (marker, expected) in starts {
+            let start = CHARS
+                .iter()
+                .position(|c| c.0[0] == marker)
+                .expect("Unknown start symbol");
+
+            assert!((103..=105).contains(&start));
+            assert_eq!(Code128::decode(&bars(start)), Ok(String::from(expected)));
+        }
+
+        // A data symbol cannot take the place of the start symbol.
+        assert_eq!(Code128::decode(&bars(65)), Err(Error::Character));
+    }
+
+    #[test]
+    fn code128_decode_invalid_checksum() {
+        let code128_a = Code128::new("HELLO", CharacterSet::A)
+            .expect("Failed to create Code128 barcode with CharacterSet A");
+        let mut bars = code128_a.encode();
+        let check = bars.len() - 24;
+        bars[check..check + 11].copy_from_slice(&CHARS[0].1);
+
+        assert_eq!(
+            Code128::decode(&bars).expect_err("Expected Error::Checksum but got None"),
+            Error::Checksum
+        );
+    }
+
+    #[test]
+    fn code128_symbol_count() {
+        let code128_a = Code128::new("HELLO", CharacterSet::A)
+            .expect("Failed to create Code128 barcode with CharacterSet A");
+        let code128_b = Code128::new("ĆŹ4218402050À0", CharacterSet::None)
+            .expect("Failed to create Code128 barcode with FNC characters");
+
+        // START-A, H, E, L, L, O and the check symbol.
+        assert_eq!(code128_a.symbol_count(), 7);
+        // START-C, FNC1, 42, 18, 40, 20, 50, CODE-A, 0 and the check symbol.
+        assert_eq!(code128_b.symbol_count(), 10);
+
+        for code128 in [code128_a, code128_b] {
+            assert_eq!(
+                code128.encode().len(),
+                (code128.symbol_count() * 11) + STOP.len() + TERM.len()
+            );
+        }
+    }
+
+    #[test]
+    fn code128_encoding_plan() {
+        let digits = Code128::new("123456", CharacterSet::C)
+            .expect("Failed to create Code128 barcode with CharacterSet C");
+        let mixed = Code128::new("ÀHE@$AĆ123456Ɓxy", CharacterSet::None)
+            .expect("Failed to create Code128 barcode with mixed character sets");
+        let fnc = Code128::new("ĆŹ4218402050À0", CharacterSet::None)
+            .expect("Failed to create Code128 barcode with FNC characters");
+
+        assert_eq!(digits.encoding_plan(), vec![(CharacterSet::C, 6)]);
+        assert_eq!(
+            mixed.encoding_plan(),
+            vec![
+                (CharacterSet::A, 5),
+                (CharacterSet::C, 6),
+                (CharacterSet::B, 2)
+            ]
+        );
+        assert_eq!(
+            fnc.encoding_plan(),
+            vec![(CharacterSet::C, 11), (CharacterSet::A, 1)]
+        );
+    }
+
+    #[test]
+    fn code128_with_auto() {
+        let auto = Code128::with_auto("HELLO123456").expect("Failed to create Code128 barcode");
+        let reference = Code128::new("HELLOĆ123456", CharacterSet::B)
+            .expect("Failed to create Code128 barcode with CharacterSet B");
+
+        assert_eq!(auto.encode(), reference.encode());
+        assert_eq!(auto.symbol_count(), 11);
+        assert_eq!(
+            auto.encoding_plan(),
+            vec![(CharacterSet::B, 5), (CharacterSet::C, 6)]
+        );
+
+        for (data, plan) in [
+            ("12345678", vec![(CharacterSet::C, 8)]),
+            ("abc123", vec![(CharacterSet::B, 6)]),
+            (
+                "\u{0006}ab",
+                vec![(CharacterSet::A, 1), (CharacterSet::B, 2)],
+            ),
+            ("ŹŹ0102", vec![(CharacterSet::C, 6)]),
+            (
+                "ab1234\u{0006}",
+                vec![
+                    (CharacterSet::B, 2),
+                    (CharacterSet::C, 4),
+                    (CharacterSet::A, 1),
+                ],
+            ),
+        ] {
+            let auto = Code128::with_auto(data).expect("Failed to create Code128 barcode");
+
+            assert_eq!(auto.encoding_plan(), plan, "{data:?}");
+        }
+
+        // An odd run of digits leaves one digit outside set C.
+        let odd = Code128::with_auto("12345").expect("Failed to create Code128 barcode");
+        assert_eq!(odd.symbol_count(), 6);
+    }
+
+    #[test]
+    fn code128_sscc() {
+        let sscc = Code128::sscc("10614141123456789").expect("Failed to create SSCC barcode");
+        let checked = Code128::sscc("106141411234567897").expect("Failed to create SSCC barcode");
+
+        assert_eq!(
+            Code128::decode(&sscc.encode()),
+            Ok(String::from("ĆŹ00106141411234567897"))
+        );
+        assert_eq!(sscc.encode(), checked.encode());
+        // START-C, FNC1, the AI and nine pairs of digits, and the check symbol.
+        assert_eq!(sscc.symbol_count(), 13);
+        assert_eq!(sscc.encoding_plan(), vec![(CharacterSet::C, 21)]);
+    }
+
+    #[test]
+    fn code128_gtin14() {
+        let gtin = Code128::gtin14("1061414112345").expect("Failed to create GTIN-14 barcode");
+        let checked = Code128::gtin14("10614141123459").expect("Failed to create GTIN-14 barcode");
+
+        assert_eq!(
+            Code128::decode(&gtin.encode()),
+            Ok(String::from("ĆŹ0110614141123459"))
+        );
+        assert_eq!(gtin.encode(), checked.encode());
+        assert_eq!(
+            Code128::decode(
+                &Code128::gtin14("0001234567890")
+                    .expect("Failed to create GTIN-14 barcode")
+                    .encode()
+            ),
+            Ok(String::from("ĆŹ0100012345678905"))
+        );
+    }
+
+    #[test]
+    fn invalid_code128_gs1() {
+        assert_eq!(Code128::sscc("1061414112345678").err(), Some(Error::Length));
+        assert_eq!(
+            Code128::sscc("1061414112345678970").err(),
+            Some(Error::Length)
+        );
+        assert_eq!(
+            Code128::sscc("10614141123456789A").err(),
+            Some(Error::Character)
+        );
+        assert_eq!(
+            Code128::sscc("106141411234567890").err(),
+            Some(Error::Checksum)
+        );
+        assert_eq!(Code128::gtin14("106141411234").err(), Some(Error::Length));
+        assert_eq!(
+            Code128::gtin14("10614141123X5").err(),
+            Some(Error::Character)
+        );
+        assert_eq!(
+            Code128::gtin14("10614141123452").err(),
+            Some(Error::Checksum)
+        );
+    }
+
+    #[test]
+    fn invalid_code128_with_auto() {
+        assert_eq!(Code128::with_auto("").err(), Some(Error::TooShort));
+        assert_eq!(Code128::with_auto("ĆHELLO").err(), Some(Error::Character));
+        assert_eq!(Code128::with_auto("HELLO☺").err(), Some(Error::Character));
+    }
+
+    #[test]
+    fn code128_encode_long_mixed_sets() {
+        let segment = "Hello, World!Ć123456789012À\u{0006}ABCƁxyz";
+        let data: String = core::iter::repeat_n(segment, 6).collect();
+        let code128 =
+            Code128::new(&data, CharacterSet::B).expect("Failed to create long Code128 barcode");
+        let encoded = code128.encode();
+        let expected_sum = code128
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, u)| (u.index() as u64) * (core::cmp::max(1, i) as u64))
+            .sum::<u64>();
+        let check = encoded.len() - TERM.len() - STOP.len() - STOP.len();
+
+        assert!(code128.0.len() > 150);
+        assert_eq!(
+            encoded.len(),
+            ((code128.0.len() + 2) * STOP.len()) + TERM.len()
+        );
+        assert_eq!(u64::from(code128.checksum_value()), expected_sum % 103);
+        assert_eq!(
+            &encoded[check..check + STOP.len()],
+            &CHARS[usize::from(code128.checksum_value())].1[..]
+        );
+        assert!(encoded.ends_with(&[&STOP[..], &TERM[..]].concat()));
+        assert_eq!(Code128::decode(&encoded), Ok(format!("Ɓ{data}")));
+    }
+
     #[test]
     fn code128_encode_longhand() {
         let code128_a = Code128::new("\u{00C0}HELLO", CharacterSet::None)
@@ -567,4 +1201,17 @@ mod tests {
         assert_eq!(collapse_vec(&code128_b.encode()), "110100001001110001011011101101000101110111101101110010010111011110100111011001100011101011");
         assert_eq!(collapse_vec(&code128_c.encode()), "1101001000011110010010110110111101110110001011101011110100111001101110010110011100101100110011011001100100010010011100110100101111001100011101011");
     }
+
+    #[test]
+    fn code128_encoded_len() {
+        let code1281 =
+            Code128::new("ÀHELLO", CharacterSet::None).expect("Failed to create barcode");
+        let code1282 =
+            Code128::new("ÀHE@$AĆ123456", CharacterSet::None).expect("Failed to create barcode");
+        let code1283 = Code128::with_auto("HELLO123456").expect("Failed to create barcode");
+
+        assert_eq!(code1281.encoded_len(), code1281.encode().len());
+        assert_eq!(code1282.encoded_len(), code1282.encode().len());
+        assert_eq!(code1283.encoded_len(), code1283.encode().len());
+    }
 }
@@ -5,11 +5,20 @@
 //! Code39 is the standard barcode used by the United States Department of Defense and is also
 //! popular in non-retail environments. It was one of the first symbologies to support encoding
 //! of the ASCII alphabet.
-
-use crate::error::Result;
-use crate::sym::{helpers, Parse};
+//!
+//! Only 43 characters are encodable directly. Full ASCII (extended) Code39 encodes the remaining
+//! ASCII characters as pairs using the '$', '%', '/' and '+' shifts; see `Code39::new_extended`.
+//!
+//! An encoded barcode is `13n + 25` modules wide, where `n` is the number of characters including
+//! any check character: each character is 12 modules plus a separator, between two 12-module
+//! guards and a leading separator.
+
+use crate::error::{Error, Result};
+use crate::sym::{helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::ops::Range;
-use helpers::{vec, Vec};
+use helpers::{vec, CharTable, Modulo43, Vec};
 
 // Character -> Binary mappings for each of the 43 allowable character.
 const CHARS: [(char, [u8; 12]); 43] = [
@@ -58,6 +67,8 @@ const CHARS: [(char, [u8; 12]); 43] = [
     ('%', [1, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1]),
 ];
 
+const TABLE: CharTable<[u8; 12]> = CharTable::new(&CHARS);
+
 // Code39 barcodes must start and end with the '*' special character.
 const GUARD: [u8; 12] = [1, 0, 0, 1, 0, 1, 1, 0, 1, 1, 0, 1];
 
@@ -82,7 +93,7 @@ impl Code39 {
     /// # Errors
     ///
     /// Returns an `Error::Character` if the input contains invalid characters.
-    /// Returns an `Error::Length` if the input length is outside the valid range.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is outside the valid range.
     ///
     /// Returns Result<Code39, Error> indicating parse success.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
@@ -94,25 +105,107 @@ impl Code39 {
     /// # Errors
     ///
     /// Returns an `Error::Character` if the input contains invalid characters.
-    /// Returns an `Error::Length` if the input length is outside the valid range.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is outside the valid range.
     ///
     /// Returns Result<Code39, Error> indicating parse success.
     pub fn with_checksum<T: AsRef<str>>(data: T) -> Result<Self> {
         Self::init(data.as_ref(), true)
     }
 
+    /// Creates a new full ASCII (extended) barcode.
+    ///
+    /// Any ASCII character is accepted. Characters outside of the 43 Code39 characters are
+    /// encoded as a pair, e.g. 'a' is encoded as "+A".
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Character` if the input contains non-ASCII characters.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the encoded length is outside the valid range.
+    ///
+    /// Returns Result<Code39, Error> indicating parse success.
+    pub fn new_extended<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::init(&Self::full_ascii(data.as_ref())?, false)
+    }
+
+    /// Creates a new full ASCII (extended) barcode with an appended check-digit, calculated
+    /// using modulo-43 over the encoded pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Character` if the input contains non-ASCII characters.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the encoded length is outside the valid range.
+    ///
+    /// Returns Result<Code39, Error> indicating parse success.
+    pub fn with_checksum_extended<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::init(&Self::full_ascii(data.as_ref())?, true)
+    }
+
+    /// Splits data across as many barcodes as needed, in order, so that each holds at most
+    /// `max_len_per_code` characters.
+    ///
+    /// The barcodes carry no sequence indicators, so any numbering of the pieces is left to the
+    /// application. Decoding each barcode and concatenating the results gives back the data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Length` if `max_len_per_code` is zero.
+    /// Returns an `Error::TooShort` if the data is empty.
+    /// Returns an `Error::Character` if the input contains invalid characters.
+    /// Returns an `Error::TooLong` if `max_len_per_code` is longer than a barcode allows.
+    pub fn split<T: AsRef<str>>(data: T, max_len_per_code: usize) -> Result<Vec<Self>> {
+        if max_len_per_code == 0 {
+            return Err(Error::Length);
+        }
+
+        let chars: Vec<char> = data.as_ref().chars().collect();
+
+        if chars.is_empty() {
+            return Err(Error::TooShort);
+        }
+
+        chars
+            .chunks(max_len_per_code)
+            .map(|piece| Self::new(piece.iter().collect::<String>()))
+            .collect()
+    }
+
+    // Maps each ASCII character to its full ASCII representation of one or two characters.
+    fn full_ascii(data: &str) -> Result<String> {
+        let mut expanded = String::with_capacity(data.len() * 2);
+
+        for c in data.chars() {
+            let b = u8::try_from(c)
+                .ok()
+                .filter(u8::is_ascii)
+                .ok_or(Error::Character)?;
+
+            let (shift, base, offset) = match b {
+                b' ' | b'-' | b'.' | b'0'..=b'9' | b'A'..=b'Z' => {
+                    expanded.push(c);
+                    continue;
+                }
+                0 => ('%', 0, b'U'),
+                1..=26 => ('$', 1, b'A'),
+                27..=31 => ('%', 27, b'A'),
+                b'!'..=b',' | b'/' | b':' => ('/', b'!', b'A'),
+                b';'..=b'?' => ('%', b';', b'F'),
+                b'@' => ('%', b'@', b'V'),
+                b'['..=b'_' => ('%', b'[', b'K'),
+                b'`' => ('%', b'`', b'W'),
+                b'a'..=b'z' => ('+', b'a', b'A'),
+                _ => ('%', b'{', b'P'),
+            };
+
+            expanded.push(shift);
+            expanded.push(char::from(b - base + offset));
+        }
+
+        Ok(expanded)
+    }
+
     /// Calculates the checksum character using a modulo-43 algorithm.
     fn checksum_char(&self) -> Option<char> {
-        let get_char_pos = |&c| {
-            CHARS
-                .iter()
-                .position(|t| t.0 == c)
-                .expect("Character not found in CHARS mapping")
-        };
-        let indices = self.data.iter().map(&get_char_pos);
-        let index = indices.sum::<usize>() % CHARS.len();
-
-        CHARS.get(index).map(|&(c, _)| c)
+        TABLE.checksum(&self.data, &Modulo43)
     }
 
     fn checksum_encoding(&self) -> [u8; 12] {
@@ -121,10 +214,9 @@ impl Code39 {
     }
 
     pub(crate) fn char_encoding(c: char) -> [u8; 12] {
-        match CHARS.iter().find(|&ch| ch.0 == c) {
-            Some(&(_, enc)) => enc,
-            None => panic!("Unknown char: {c}"),
-        }
+        TABLE
+            .encoding(c)
+            .unwrap_or_else(|| panic!("Unknown char: {c}"))
     }
 
     // Encoded characters are separated by a single "narrow" bar in
@@ -134,9 +226,25 @@ impl Code39 {
         into.push(0);
     }
 
+    // The payload sits between the guards, so it has a separator at each end: one leading
+    // separator after the start guard, and the trailing separator of the last character before
+    // the end guard.
     fn payload(&self) -> Vec<u8> {
         let mut enc = vec![0];
 
+        enc.extend(self.payload_canonical());
+        enc
+    }
+
+    /// Returns the encoded characters (including the check character, if any) without the guards
+    /// or the leading separator.
+    ///
+    /// Each character is followed by its single separating space, so canonical payloads can be
+    /// concatenated or compared directly. `encode` adds the guards and leading separator.
+    #[must_use]
+    pub fn payload_canonical(&self) -> Vec<u8> {
+        let mut enc = Vec::new();
+
         for c in &self.data {
             Self::push_encoding(&mut enc, Self::char_encoding(*c));
         }
@@ -154,7 +262,78 @@ impl Code39 {
     pub fn encode(&self) -> Vec<u8> {
         let guard = &GUARD[..];
 
-        helpers::join_slices(&[guard, &self.payload()[..], guard][..])
+        let enc = helpers::join_slices(&[guard, &self.payload()[..], guard][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        13 * (self.data.len() + usize::from(self.checksum)) + 25
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its data.
+    ///
+    /// Every character is matched against its full pattern, so characters whose patterns
+    /// resemble one another (such as `.`, `-`, space and the digits) are never confused. Check
+    /// characters are not removed, as their presence cannot be detected from the bars alone.
+    /// Code39 can be scanned in either direction, so reversed bars are decoded too. The guard is
+    /// not a palindrome, so the direction is never ambiguous.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the guards are missing or a character pattern is unknown.
+    /// Returns an `Error::Length` if the bars do not form a whole number of characters.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        Self::decode_forward(bars)
+            .or_else(|e| Self::decode_forward(&helpers::reverse_modules(bars)).map_err(|_| e))
+    }
+
+    /// Decodes a sequence of bars read left-to-right.
+    fn decode_forward(bars: &[u8]) -> Result<String> {
+        let payload = bars
+            .strip_prefix(&GUARD[..])
+            .and_then(|b| b.strip_prefix(&[0][..]))
+            .and_then(|b| b.strip_suffix(&GUARD[..]))
+            .ok_or(Error::Character)?;
+        let width = GUARD.len() + 1;
+
+        if payload.is_empty() || payload.len() % width != 0 {
+            return Err(Error::Length);
+        }
+
+        payload
+            .chunks(width)
+            .map(|chunk| match chunk.split_last() {
+                Some((0, enc)) => TABLE
+                    .entries()
+                    .iter()
+                    .find(|&&(_, e)| e[..] == *enc)
+                    .map(|&(c, _)| c)
+                    .ok_or(Error::Character),
+                _ => Err(Error::Character),
+            })
+            .collect()
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for Code39 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -164,8 +343,7 @@ impl Parse for Code39 {
     }
 
     fn valid_chars() -> Vec<char> {
-        let (chars, _): (Vec<_>, Vec<_>) = CHARS.iter().copied().unzip();
-        chars
+        TABLE.chars()
     }
 }
 
@@ -174,7 +352,10 @@ mod tests {
     use crate::error::Error;
     use crate::sym::code39::*;
     #[cfg(not(feature = "std"))]
-    use alloc::string::String;
+    use alloc::{
+        format,
+        string::{String, ToString},
+    };
     use core::char;
 
     fn collapse_vec(v: &[u8]) -> String {
@@ -184,6 +365,11 @@ mod tests {
         chars.collect()
     }
 
+    #[test]
+    fn chars_are_unique() {
+        assert!(TABLE.is_unique());
+    }
+
     #[test]
     fn new_code39() {
         let code39 = Code39::new("12345");
@@ -205,7 +391,18 @@ mod tests {
     fn invalid_len_code39() {
         let code39 = Code39::new("");
 
-        assert_eq!(code39.expect_err("Expected Error::Length"), Error::Length);
+        assert_eq!(
+            code39.expect_err("Expected Error::TooShort"),
+            Error::TooShort
+        );
+    }
+
+    #[test]
+    fn code39_recommended_min_height() {
+        let code39 = Code39::new("1234").expect("Failed to create Code39 for '1234'");
+
+        assert_eq!(code39.recommended_min_height(1), 12);
+        assert_eq!(code39.recommended_min_height(2), 24);
     }
 
     #[test]
@@ -222,6 +419,133 @@ mod tests {
         assert_eq!(collapse_vec(&code393.encode()), "100101101101010101101100101101011001010101101011001010101101100101101001011010101001101101011010011010101011001010110100101101101");
     }
 
+    #[test]
+    fn code39_payload_canonical() {
+        let code39 = Code39::new("AB").expect("Failed to create Code39 for 'AB'");
+        // 'A' and 'B', each followed by a separator.
+        let canonical = "11010100101101011010010110";
+
+        // The start guard, the leading separator, the canonical payload and the end guard.
+        assert_eq!(
+            collapse_vec(&code39.encode()),
+            format!("1001011011010{canonical}100101101101")
+        );
+        assert_eq!(collapse_vec(&code39.payload_canonical()), canonical);
+    }
+
+    #[test]
+    fn code39_checksum_char() {
+        let code39 = Code39::with_checksum("1234").expect("Failed to create Code39 for '1234'");
+
+        // 1 + 2 + 3 + 4 = 10 (mod 43).
+        assert_eq!(code39.checksum_char(), Some('A'));
+    }
+
+    #[test]
+    fn code39_decode() {
+        for data in ["1234", "983RD512", "TEST8052"] {
+            let code39 = Code39::new(data).expect("Failed to create Code39");
+
+            assert_eq!(Code39::decode(&code39.encode()), Ok(String::from(data)));
+        }
+    }
+
+    #[test]
+    fn code39_decode_reversed() {
+        for data in ["1234", "983RD512", "A.B-C D$E/F+G%H"] {
+            let code39 = Code39::new(data).expect("Failed to create Code39");
+            let reversed = helpers::reverse_modules(&code39.encode());
+
+            assert_eq!(Code39::decode(&reversed), Ok(String::from(data)));
+        }
+    }
+
+    #[test]
+    fn code39_decode_special_chars() {
+        let data = "A.B-C D$E/F+G%H0.1-2 3";
+        let code39 = Code39::new(data).expect("Failed to create Code39 for special characters");
+        let with_checksum = Code39::with_checksum(data)
+            .expect("Failed to create Code39 with checksum for special characters");
+        let check = with_checksum
+            .checksum_char()
+            .expect("Cannot compute checksum");
+
+        assert_eq!(Code39::decode(&code39.encode()), Ok(String::from(data)));
+        assert_eq!(
+            Code39::decode(&with_checksum.encode()),
+            Ok(format!("{data}{check}"))
+        );
+
+        for &(c, _) in TABLE.entries() {
+            let code39 = Code39::new(c.to_string()).expect("Failed to create Code39");
+
+            assert_eq!(Code39::decode(&code39.encode()), Ok(c.to_string()));
+        }
+    }
+
+    #[test]
+    fn invalid_code39_decode() {
+        let code39 = Code39::new("1234").expect("Failed to create Code39 for '1234'");
+        let mut bars = code39.encode();
+        let len = bars.len();
+
+        assert_eq!(
+            Code39::decode(&bars[1..]).expect_err("Expected Error::Character"),
+            Error::Character
+        );
+        assert_eq!(
+            Code39::decode(&[&GUARD[..], &[0], &GUARD[..]].concat()),
+            Err(Error::Length)
+        );
+
+        // Corrupt the separator between the first two characters.
+        bars[GUARD.len() + 13] = 1;
+        assert_eq!(Code39::decode(&bars), Err(Error::Character));
+        assert_eq!(Code39::decode(&bars[..len - 1]), Err(Error::Character));
+    }
+
+    #[test]
+    fn code39_full_ascii() {
+        for (data, expected) in [
+            ("abc", "+A+B+C"),
+            ("\u{0007}", "$G"),
+            ("\0\u{001B}\u{007F}", "%U%A%T"),
+            ("!,/:;?@[_`{~", "/A/L/O/Z%F%J%V%K%O%W%P%S"),
+            ("A-1. Z", "A-1. Z"),
+        ] {
+            assert_eq!(Code39::full_ascii(data), Ok(String::from(expected)));
+        }
+
+        assert_eq!(Code39::full_ascii("é").err(), Some(Error::Character));
+        assert_eq!(Code39::new_extended("").err(), Some(Error::TooShort));
+    }
+
+    #[test]
+    fn code39_encode_extended() {
+        let code391 = Code39::new_extended("abc").expect("Failed to create extended Code39");
+        let code392 = Code39::new_extended("\u{0007}").expect("Failed to create extended Code39");
+        let code393 = Code39::with_checksum_extended("abc")
+            .expect("Failed to create extended Code39 with checksum");
+
+        assert_eq!(
+            code391.encode(),
+            Code39::new("+A+B+C")
+                .expect("Failed to create Code39")
+                .encode()
+        );
+        assert_eq!(
+            code393.encode(),
+            Code39::with_checksum("+A+B+C")
+                .expect("Failed to create Code39 with checksum")
+                .encode()
+        );
+        assert_eq!(collapse_vec(&code391.encode()), "1001011011010100101001001011010100101101001010010010101101001011010010100100101101101001010100101101101");
+        assert_eq!(
+            collapse_vec(&code392.encode()),
+            "100101101101010010010010101010100110110100101101101"
+        );
+    }
+
     #[test]
     fn code39_encode_with_checksum() {
         let code391 = Code39::with_checksum("1234")
@@ -232,4 +556,49 @@ mod tests {
         assert_eq!(collapse_vec(&code391.encode()), "100101101101011010010101101011001010110110110010101010100110101101101010010110100101101101");
         assert_eq!(collapse_vec(&code392.encode()), "1001011011010101100101101011010010110101101100101010110101011001010101100101101101001101010110100101011010110010101101011011010010100101101101");
     }
+
+    #[test]
+    fn code39_encoded_len() {
+        let code391 = Code39::new("1ISTHELONELIESTNUMBER").expect("Failed to create barcode");
+        let code392 = Code39::with_checksum("TEST8").expect("Failed to create barcode");
+        let code393 = Code39::new_extended("Hello").expect("Failed to create barcode");
+
+        assert_eq!(code391.encoded_len(), code391.encode().len());
+        assert_eq!(code392.encoded_len(), code392.encode().len());
+        assert_eq!(code393.encoded_len(), code393.encode().len());
+    }
+
+    #[test]
+    fn code39_split() {
+        let data = "SERIAL-0123456789-ABCDEFGHIJKL";
+        let pieces = Code39::split(data, 8).expect("Failed to split Code39 data");
+        let decoded: Vec<String> = pieces
+            .iter()
+            .map(|p| Code39::decode(&p.encode()).expect("Failed to decode Code39 barcode"))
+            .collect();
+
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(decoded, ["SERIAL-0", "12345678", "9-ABCDEF", "GHIJKL"]);
+        assert_eq!(decoded.concat(), data);
+        assert_eq!(
+            Code39::split("ABC", 8)
+                .expect("Failed to split Code39 data")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_code39_split() {
+        assert_eq!(Code39::split("ABC", 0).err(), Some(Error::Length));
+        assert_eq!(Code39::split("", 8).err(), Some(Error::TooShort));
+        assert_eq!(
+            Code39::split("ABCDEFGHIJ!", 4).err(),
+            Some(Error::Character)
+        );
+        assert_eq!(
+            Code39::split("A".repeat(300), 300).err(),
+            Some(Error::TooLong)
+        );
+    }
 }
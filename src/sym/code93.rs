@@ -5,12 +5,20 @@
 //!
 //! Code93 is a continuous, variable-length symbology.
 //!
-//! NOTE: This encoder currently only supports the basic Code93 implementation and not full-ASCII
-//! mode.
+//! Full-ASCII mode is supported via `Code93::new_extended`, which encodes characters outside of
+//! the basic set as pairs of a shift character and a basic character. Unlike Code39, Code93 has
+//! dedicated shift characters, so the literal `$`, `%`, `/` and `+` characters are always encoded
+//! as themselves rather than as shift sequences.
+//!
+//! An encoded barcode is `9(n + 4) + 1` modules wide, where `n` is the number of characters
+//! (counting each shift pair as two): each character, the two checksums and both guards are 9
+//! modules, followed by a 1-module terminator bar.
 
-use super::helpers::{vec, Vec};
-use crate::error::Result;
-use crate::sym::{helpers, Parse};
+use super::helpers::{vec, CharTable, Modulo47, Vec};
+use crate::error::{Error, Result};
+use crate::sym::{helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::ops::Range;
 
 // Character -> Binary mappings for each of the 47 allowable character.
@@ -65,6 +73,22 @@ const CHARS: [(char, [u8; 9]); 47] = [
     (']', [1, 0, 0, 1, 1, 0, 0, 1, 0]),
 ];
 
+// ASCII -> Code93 mappings for full-ASCII mode, indexed by ASCII code.
+// The shift characters are represented with ( = ($), ) = (%), [ = (/) and ] = (+).
+#[rustfmt::skip]
+const EXTENDED_CHARS: [&str; 128] = [
+    ")U", "(A", "(B", "(C", "(D", "(E", "(F", "(G", "(H", "(I", "(J", "(K", "(L", "(M", "(N", "(O",
+    "(P", "(Q", "(R", "(S", "(T", "(U", "(V", "(W", "(X", "(Y", "(Z", ")A", ")B", ")C", ")D", ")E",
+    " ", "[A", "[B", "[C", "$", "%", "[F", "[G", "[H", "[I", "[J", "+", "[L", "-", ".", "/",
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "[Z", ")F", ")G", ")H", ")I", ")J",
+    ")V", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O",
+    "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z", ")K", ")L", ")M", ")N", ")O",
+    ")W", "]A", "]B", "]C", "]D", "]E", "]F", "]G", "]H", "]I", "]J", "]K", "]L", "]M", "]N", "]O",
+    "]P", "]Q", "]R", "]S", "]T", "]U", "]V", "]W", "]X", "]Y", "]Z", ")P", ")Q", ")R", ")S", ")T",
+];
+
+const TABLE: CharTable<[u8; 9]> = CharTable::new(&CHARS);
+
 // Code93 barcodes must start and end with the '*' special character.
 const GUARD: [u8; 9] = [1, 0, 1, 0, 1, 1, 1, 1, 0];
 const TERMINATOR: [u8; 1] = [1];
@@ -80,42 +104,66 @@ impl Code93 {
     /// Returns `Result<Code93, Error>` indicating parse success.
     ///
     /// # Errors
-    /// Returns an `Error::Length` if the input data length is invalid.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input data length is invalid.
     /// Returns an `Error::Character` if the input data contains invalid characters.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
         Self::parse(data.as_ref()).map(|d| Self(d.chars().collect()))
     }
 
-    pub(crate) fn char_encoding(c: char) -> [u8; 9] {
-        match CHARS.iter().find(|&ch| ch.0 == c) {
-            Some(&(_, enc)) => enc,
-            None => panic!("Unknown char: {c}"),
+    /// Creates a new barcode in full-ASCII mode.
+    ///
+    /// Any ASCII character is accepted. Characters outside of the basic Code93 set are expanded
+    /// into shift sequences before encoding, and the checksums are computed over the expanded
+    /// sequence.
+    ///
+    /// # Returns
+    /// Returns `Result<Code93, Error>` indicating parse success.
+    ///
+    /// # Errors
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the expanded data length is invalid.
+    /// Returns an `Error::Character` if the input data contains non-ASCII characters.
+    pub fn new_extended<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::extended_chars(data.as_ref()).map(Code93)
+    }
+
+    // Runs the same checks as `Code93::new_extended` without keeping the expanded data.
+    pub(crate) fn validate_extended(data: &str) -> Result<()> {
+        Self::extended_chars(data).map(|_| ())
+    }
+
+    fn extended_chars(data: &str) -> Result<Vec<char>> {
+        let expanded = Self::full_ascii(data)?;
+
+        Self::parse(&expanded).map(|d| d.chars().collect())
+    }
+
+    // Expands each ASCII character into its full-ASCII representation of one or two characters.
+    fn full_ascii(data: &str) -> Result<String> {
+        let mut expanded = String::with_capacity(data.len() * 2);
+
+        for c in data.chars() {
+            let mapped = EXTENDED_CHARS.get(c as usize).ok_or(Error::Character)?;
+
+            expanded.push_str(mapped);
         }
+
+        Ok(expanded)
+    }
+
+    pub(crate) fn char_encoding(c: char) -> [u8; 9] {
+        TABLE
+            .encoding(c)
+            .unwrap_or_else(|| panic!("Unknown char: {c}"))
     }
 
     /// Calculates a checksum character using a weighted modulo-47 algorithm.
-    pub(crate) fn checksum_char(data: &[char], weight_threshold: usize) -> Option<char> {
-        let get_char_pos = |&c| {
-            CHARS
-                .iter()
-                .position(|t| t.0 == c)
-                .expect("Character not found in CHARS mapping")
-        };
-        let weight = |i| match (data.len() - i) % weight_threshold {
-            0 => weight_threshold,
-            n => n,
-        };
-        let positions = data.iter().map(get_char_pos);
-        let index = positions
-            .enumerate()
-            .fold(0, |acc, (i, pos)| acc + (weight(i) * pos));
-
-        CHARS.get(index % CHARS.len()).map(|&(c, _)| c)
+    pub(crate) fn checksum_char(data: &[char], algorithm: Modulo47) -> Option<char> {
+        TABLE.checksum(data, &algorithm)
     }
 
     /// Calculates the C checksum character using a weighted modulo-47 algorithm.
     pub(crate) fn c_checksum_char(data: &[char]) -> Option<char> {
-        Self::checksum_char(data, 20)
+        Self::checksum_char(data, Modulo47::C)
     }
 
     /// Calculates the K checksum character using a weighted modulo-47 algorithm.
@@ -123,7 +171,7 @@ impl Code93 {
         let mut extended_data: Vec<char> = data.to_vec();
         extended_data.push(c_checksum);
 
-        Self::checksum_char(&extended_data, 15)
+        Self::checksum_char(&extended_data, Modulo47::K)
     }
 
     fn push_encoding(into: &mut Vec<u8>, from: [u8; 9]) {
@@ -154,7 +202,81 @@ impl Code93 {
         let guard = &GUARD[..];
         let terminator = &TERMINATOR[..];
 
-        helpers::join_slices(&[guard, &self.payload()[..], guard, terminator][..])
+        let enc = helpers::join_slices(&[guard, &self.payload()[..], guard, terminator][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        9 * (self.0.len() + 4) + 1
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its data.
+    ///
+    /// The C and K checksums are verified and removed from the returned data. Shift characters
+    /// (represented with `(`, `)`, `[` and `]`) are returned as-is rather than being expanded.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the guards are missing or a character pattern is unknown.
+    /// Returns an `Error::Length` if the bars do not form a whole number of characters, or there
+    /// are too few characters to hold the checksums.
+    /// Returns an `Error::Checksum` if a checksum character does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        let payload = bars
+            .strip_prefix(&GUARD[..])
+            .and_then(|b| b.strip_suffix(&TERMINATOR[..]))
+            .and_then(|b| b.strip_suffix(&GUARD[..]))
+            .ok_or(Error::Character)?;
+
+        if payload.len() % GUARD.len() != 0 {
+            return Err(Error::Length);
+        }
+
+        let chars = payload
+            .chunks(GUARD.len())
+            .map(|chunk| {
+                TABLE
+                    .entries()
+                    .iter()
+                    .find(|&&(_, e)| e[..] == *chunk)
+                    .map(|&(c, _)| c)
+                    .ok_or(Error::Character)
+            })
+            .collect::<Result<Vec<char>>>()?;
+
+        let data_len = chars.len().checked_sub(2).ok_or(Error::Length)?;
+        let data = &chars[..data_len];
+        let c_checksum = Self::c_checksum_char(data).ok_or(Error::Checksum)?;
+
+        if chars[data_len] != c_checksum
+            || Some(chars[data_len + 1]) != Self::k_checksum_char(data, c_checksum)
+        {
+            return Err(Error::Checksum);
+        }
+
+        Ok(data.iter().collect())
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for Code93 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -167,8 +289,7 @@ impl Parse for Code93 {
 
     /// Returns the set of valid characters allowed in this type of barcode.
     fn valid_chars() -> Vec<char> {
-        let (chars, _): (Vec<_>, Vec<_>) = CHARS.iter().copied().unzip();
-        chars
+        TABLE.chars()
     }
 }
 
@@ -187,13 +308,49 @@ mod tests {
         chars.collect()
     }
 
+    #[test]
+    fn chars_are_unique() {
+        assert!(TABLE.is_unique());
+    }
+
+    #[test]
+    fn code93_decode() {
+        for data in ["TEST93", "9", "CODE 93-$/+%.", "ABCDEFGHIJKLMNOPQRSTUVWXYZ"] {
+            let code93 = Code93::new(data).expect("Failed to create Code93");
+
+            assert_eq!(Code93::decode(&code93.encode()), Ok(String::from(data)));
+        }
+
+        let extended = Code93::new_extended("a").expect("Failed to create extended Code93");
+
+        assert_eq!(Code93::decode(&extended.encode()), Ok(String::from("]A")));
+    }
+
+    #[test]
+    fn invalid_code93_decode() {
+        let encoded = Code93::new("TEST93")
+            .expect("Failed to create Code93")
+            .encode();
+        let mut swapped = encoded.clone();
+
+        // Replace the first 'T' with an 'S'.
+        swapped[9..18].copy_from_slice(&Code93::char_encoding('S'));
+
+        assert_eq!(Code93::decode(&swapped), Err(Error::Checksum));
+        assert_eq!(Code93::decode(&encoded[1..]), Err(Error::Character));
+        assert_eq!(
+            Code93::decode(&encoded[..encoded.len() - 2]),
+            Err(Error::Character)
+        );
+    }
+
     #[test]
     fn invalid_length_code93() {
         let code93 = Code93::new("");
 
         assert!(code93.is_err());
         if let Err(error) = code93 {
-            assert_eq!(error, Error::Length);
+            assert_eq!(error, Error::TooShort);
         }
     }
 
@@ -207,6 +364,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn invalid_length_code93_extended() {
+        let lowercase: String = core::iter::repeat_n('a', 200).collect();
+
+        assert_eq!(Code93::new_extended("").err(), Some(Error::TooShort));
+        assert_eq!(Code93::new_extended(&lowercase).err(), Some(Error::TooLong));
+        assert!(Code93::new_extended(&lowercase[..128]).is_ok());
+    }
+
+    #[test]
+    fn invalid_data_code93_extended() {
+        let code93 = Code93::new_extended("caf\u{00E9}");
+
+        assert_eq!(
+            code93.expect_err("Expected Error::Character"),
+            Error::Character
+        );
+    }
+
+    #[test]
+    fn code93_literal_punctuation() {
+        let code931 = Code93::new("A$B").expect("Failed to create Code93 for 'A$B'");
+        let code932 =
+            Code93::new_extended("A$B").expect("Failed to create extended Code93 for 'A$B'");
+        let code933 =
+            Code93::new_extended("+%/").expect("Failed to create extended Code93 for '+%/'");
+
+        assert_eq!(code931.0, ['A', '$', 'B']);
+        assert_eq!(code932.0, ['A', '$', 'B']);
+        assert_eq!(code933.0, ['+', '%', '/']);
+        assert_eq!(
+            collapse_vec(&code931.encode()),
+            collapse_vec(&code932.encode())
+        );
+        assert_eq!(
+            collapse_vec(&code931.encode()),
+            "1010111101101010001110010101101001001000101101011010001010111101"
+        );
+    }
+
+    #[test]
+    fn code93_extended_shift() {
+        let code931 = Code93::new_extended("a!\u{0001}").expect("Failed to create extended Code93");
+        let code932 = Code93::new("]A[A(A").expect("Failed to create Code93 for ']A[A(A'");
+
+        assert_eq!(code931.0, [']', 'A', '[', 'A', '(', 'A']);
+        assert_eq!(
+            collapse_vec(&code931.encode()),
+            collapse_vec(&code932.encode())
+        );
+    }
+
+    #[test]
+    fn code93_encode_extended_lowercase() {
+        let code931 = Code93::new_extended("abc").expect("Failed to create extended Code93");
+        let code932 = Code93::new_extended("code93").expect("Failed to create extended Code93");
+
+        // The checksums are computed over the shift pairs: "]A]B]C" has C = '-' and K = '8'.
+        assert_eq!(Code93::c_checksum_char(&code931.0), Some('-'));
+        assert_eq!(Code93::k_checksum_char(&code931.0, '-'), Some('8'));
+        assert_eq!(collapse_vec(&code931.encode()), "1010111101001100101101010001001100101101001001001100101101000101001011101000100101010111101");
+        assert_eq!(collapse_vec(&code932.encode()), "1010111101001100101101000101001100101001011001001100101100101001001100101100100101000010101010000101000101001001011001010111101");
+    }
+
+    #[test]
+    fn code93_checksum_chars() {
+        let code93 = Code93::new("TEST93").expect("Failed to create Code93 for 'TEST93'");
+        let c_checksum = Code93::c_checksum_char(&code93.0);
+
+        assert_eq!(c_checksum, Some('+'));
+        assert_eq!(Code93::k_checksum_char(&code93.0, '+'), Some('6'));
+    }
+
     #[test]
     fn code93_encode() {
         // Tests for data longer than 15, data longer than 20
@@ -227,4 +457,28 @@ mod tests {
         );
         assert_eq!(collapse_vec(&code934.encode()), "1010111101010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001000101101110010101010111101");
     }
+
+    #[test]
+    fn code93_decode_encode_fixtures() {
+        // The known-good encodings from `code93_encode`, which decode without their checksums.
+        for (bars, data) in [
+            ("1010111101101001101100100101101011001101001101000010101010000101011101101001000101010111101", "TEST93"),
+            ("1010111101100010101010110001101010001010011001001011001010011001010111101", "FLAM"),
+            ("1010111101000010101000010101101100101000101101010111101", "99"),
+            ("1010111101010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001010010001000101101110010101010111101", "1111111111111111111111"),
+        ] {
+            let bars: Vec<u8> = bars.bytes().map(|b| b - b'0').collect();
+
+            assert_eq!(Code93::decode(&bars), Ok(String::from(data)));
+        }
+    }
+
+    #[test]
+    fn code93_encoded_len() {
+        let code931 = Code93::new("TEST93").expect("Failed to create barcode");
+        let code932 = Code93::new_extended("Hello").expect("Failed to create barcode");
+
+        assert_eq!(code931.encoded_len(), code931.encode().len());
+        assert_eq!(code932.encoded_len(), code932.encode().len());
+    }
 }
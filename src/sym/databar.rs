@@ -0,0 +1,440 @@
+//! Encoder for GS1 DataBar Omnidirectional barcodes.
+//!
+//! GS1 DataBar (formerly Reduced Space Symbology, or RSS) is a family of symbologies which is
+//! distinct from EAN/UPC, despite also encoding GTINs. DataBar Omnidirectional (also known as
+//! DataBar-14 or RSS-14) encodes a GTIN-14 in 96 modules, and can be read in any direction.
+//!
+//! Only DataBar Omnidirectional is supported. The Truncated, Stacked, Limited and Expanded
+//! variants are not yet implemented.
+
+use crate::error::{Error, Result};
+use crate::sym::{helpers, Barcode, Parse};
+use core::ops::Range;
+use helpers::Vec;
+
+/// The number of values encoded by each pair of outside and inside data characters.
+const PAIR_VALUES: u64 = 4_537_077;
+
+/// The number of values encoded by each inside data character.
+const INSIDE_VALUES: u64 = 1597;
+
+/// The modulus of the checksum.
+const CHECKSUM_MODULUS: u32 = 79;
+
+/// The weights of each element of the four data characters, used to compute the checksum.
+const CHECKSUM_WEIGHTS: [u32; 32] = [
+    1, 3, 9, 27, 2, 6, 18, 54, 4, 12, 36, 29, 8, 24, 72, 58, 16, 48, 65, 37, 32, 17, 51, 74, 64,
+    34, 23, 69, 49, 68, 46, 59,
+];
+
+/// The element widths of the nine finder patterns, selected by the checksum.
+const FINDERS: [[u32; 5]; 9] = [
+    [3, 8, 2, 1, 1],
+    [3, 5, 5, 1, 1],
+    [3, 3, 7, 1, 1],
+    [3, 1, 9, 1, 1],
+    [2, 7, 4, 1, 1],
+    [2, 5, 6, 1, 1],
+    [2, 3, 8, 1, 1],
+    [1, 5, 7, 1, 1],
+    [1, 3, 9, 1, 1],
+];
+
+/// The left and right guards, each a narrow space and a narrow bar.
+const GUARD: [u32; 2] = [1, 1];
+
+/// The minimum bar height of an omnidirectional symbol, in multiples of the X dimension.
+pub(crate) const DATABAR_HEIGHT: u32 = 33;
+
+/// A group of data character values, which share the same number of modules in their odd and
+/// even elements.
+struct Group {
+    /// The first value in the group.
+    start: u32,
+    /// The number of combinations of the even (outside) or odd (inside) elements.
+    combinations: u32,
+    /// The total width of the odd elements.
+    odd_modules: u32,
+    /// The total width of the even elements.
+    even_modules: u32,
+    /// The widest permitted odd element.
+    odd_widest: u32,
+    /// The widest permitted even element.
+    even_widest: u32,
+}
+
+impl Group {
+    const fn new(start: u32, combinations: u32, modules: [u32; 2], widest: [u32; 2]) -> Self {
+        Self {
+            start,
+            combinations,
+            odd_modules: modules[0],
+            even_modules: modules[1],
+            odd_widest: widest[0],
+            even_widest: widest[1],
+        }
+    }
+}
+
+/// The groups of the 16-module outside data characters.
+const OUTSIDE_GROUPS: [Group; 5] = [
+    Group::new(0, 1, [12, 4], [8, 1]),
+    Group::new(161, 10, [10, 6], [6, 3]),
+    Group::new(961, 34, [8, 8], [4, 5]),
+    Group::new(2015, 70, [6, 10], [3, 6]),
+    Group::new(2715, 126, [4, 12], [1, 8]),
+];
+
+/// The groups of the 15-module inside data characters.
+const INSIDE_GROUPS: [Group; 4] = [
+    Group::new(0, 4, [5, 10], [2, 7]),
+    Group::new(336, 20, [7, 8], [4, 5]),
+    Group::new(1036, 48, [9, 6], [6, 3]),
+    Group::new(1516, 81, [11, 4], [8, 1]),
+];
+
+/// The GS1 DataBar Omnidirectional barcode type.
+#[derive(Debug)]
+pub struct DataBar14(Vec<u8>);
+
+impl DataBar14 {
+    /// Creates a new barcode from the first 13 digits of a GTIN-14, optionally followed by its
+    /// check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::Checksum` if the provided check digit is invalid.
+    /// Returns an `Error::Character` if the input contains invalid characters.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is not valid.
+    pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
+        let d = Self::parse(data.as_ref())?;
+        let digits = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+
+        let databar = Self(digits[0..13].to_vec());
+
+        // If the check digit is provided, check the checksum.
+        if digits.len() == 14 && databar.check_digit() != digits[13] {
+            return Err(Error::Checksum);
+        }
+
+        Ok(databar)
+    }
+
+    /// Calculates the GTIN-14 check digit, which is implied rather than encoded.
+    fn check_digit(&self) -> u8 {
+        helpers::modulo_10_checksum(&self.0[..])
+    }
+
+    /// Returns the values of the left outside, left inside, right outside and right inside data
+    /// characters.
+    fn data_characters(&self) -> [u32; 4] {
+        let value = self.0.iter().fold(0, |acc, &d| acc * 10 + u64::from(d));
+        let (left, right) = (value / PAIR_VALUES, value % PAIR_VALUES);
+
+        // Safe: 13 digits divide into values below 1597 * 2841, which all fit in a u32.
+        #[allow(clippy::cast_possible_truncation)]
+        [
+            left / INSIDE_VALUES,
+            left % INSIDE_VALUES,
+            right / INSIDE_VALUES,
+            right % INSIDE_VALUES,
+        ]
+        .map(|v| v as u32)
+    }
+
+    /// Calculates the checksum over the element widths of the four data characters.
+    fn checksum(widths: &[[u32; 8]; 4]) -> u32 {
+        let sum: u32 = widths
+            .iter()
+            .flatten()
+            .zip(CHECKSUM_WEIGHTS)
+            .map(|(w, weight)| w * weight)
+            .sum();
+
+        sum % CHECKSUM_MODULUS
+    }
+
+    /// Returns the element widths of the symbol, starting with the space of the left guard.
+    fn elements(&self) -> Vec<u32> {
+        let [lo, li, ro, ri] = self.data_characters();
+        let widths = [
+            char_widths(lo, true),
+            char_widths(li, false),
+            char_widths(ro, true),
+            char_widths(ri, false),
+        ];
+
+        // The checksum skips the two values which would select identical finder patterns
+        // (0 and 0, 8 and 8) on either side of the symbol.
+        let mut checksum = Self::checksum(&widths);
+
+        if checksum >= 8 {
+            checksum += 1;
+        }
+
+        if checksum >= 72 {
+            checksum += 1;
+        }
+
+        let (left_finder, right_finder) = (checksum as usize / 9, checksum as usize % 9);
+        let [lo, li, ro, ri] = widths;
+
+        // The right half of the symbol is the mirror image of the left half.
+        GUARD
+            .iter()
+            .chain(&lo)
+            .chain(&FINDERS[left_finder])
+            .chain(li.iter().rev())
+            .chain(&ri)
+            .chain(FINDERS[right_finder].iter().rev())
+            .chain(ro.iter().rev())
+            .chain(&GUARD)
+            .copied()
+            .collect()
+    }
+
+    /// Encodes the barcode.
+    /// Returns a Vec<u8> of binary digits.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let enc: Vec<u8> = self
+            .elements()
+            .iter()
+            .zip([0, 1].iter().cycle())
+            .flat_map(|(&w, &m)| (0..w).map(move |_| m))
+            .collect();
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is always 96.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        96
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 33 times the X dimension, the minimum for an omnidirectional symbol.
+    #[must_use]
+    pub const fn recommended_min_height(&self, xdim: u32) -> u32 {
+        xdim.saturating_mul(DATABAR_HEIGHT)
+    }
+}
+
+/// Returns the widths of the eight elements of the data character with the given value,
+/// alternating between odd and even elements.
+fn char_widths(value: u32, outside: bool) -> [u32; 8] {
+    let groups = if outside {
+        &OUTSIDE_GROUPS[..]
+    } else {
+        &INSIDE_GROUPS[..]
+    };
+    let group = groups
+        .iter()
+        .rfind(|g| g.start <= value)
+        .unwrap_or(&groups[0]);
+
+    let value = value - group.start;
+    let (odd, even) = if outside {
+        (value / group.combinations, value % group.combinations)
+    } else {
+        (value % group.combinations, value / group.combinations)
+    };
+
+    // Outside characters may have odd elements without a narrow element, and inside characters
+    // may have even elements without one.
+    let odd = element_widths(odd, group.odd_modules, group.odd_widest, outside);
+    let even = element_widths(even, group.even_modules, group.even_widest, !outside);
+
+    [
+        odd[0], even[0], odd[1], even[1], odd[2], even[2], odd[3], even[3],
+    ]
+}
+
+/// Returns the widths of the four elements with the given value, which total `modules` and are
+/// no wider than `widest`.
+///
+/// This is the RSS subset width algorithm: values enumerate the valid combinations of widths in
+/// order, so the widths are found by skipping past the combinations beginning with each
+/// narrower element. Unless `no_narrow` is set, combinations without a narrow element are not
+/// valid.
+fn element_widths(value: u32, modules: u32, widest: u32, no_narrow: bool) -> [u32; 4] {
+    let mut value = i64::from(value);
+    let mut modules = modules;
+    let mut widths = [0; 4];
+    let mut narrow = false;
+
+    // `rest` is the number of elements following each element.
+    for (rest, width) in (1..=3).rev().zip(widths.iter_mut()) {
+        let mut w = 1;
+
+        loop {
+            // All combinations of the following elements.
+            let mut sub = combinations(modules - w - 1, rest - 1);
+
+            // Less the combinations without a narrow element.
+            if !no_narrow && !narrow && w > 1 && modules - w - rest >= rest {
+                sub -= combinations(modules - w - rest - 1, rest - 1);
+            }
+
+            // Less the combinations with an element wider than the widest.
+            if rest > 1 {
+                let mut less = 0;
+
+                for wide in widest + 1..=modules - w - (rest - 1) {
+                    less += combinations(modules - w - wide - 1, rest - 2);
+                }
+
+                sub -= less * i64::from(rest);
+            } else if modules - w > widest {
+                sub -= 1;
+            }
+
+            if value < sub {
+                break;
+            }
+
+            value -= sub;
+            w += 1;
+        }
+
+        narrow = narrow || w == 1;
+        modules -= w;
+        *width = w;
+    }
+
+    widths[3] = modules;
+    widths
+}
+
+/// Returns the number of ways of choosing `r` items from `n`.
+fn combinations(n: u32, r: u32) -> i64 {
+    let r = r.min(n - r);
+
+    (0..r).fold(1, |acc, i| acc * i64::from(n - i) / i64::from(i + 1))
+}
+
+impl Barcode for DataBar14 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
+    }
+}
+
+impl Parse for DataBar14 {
+    fn valid_len() -> Range<u32> {
+        13..14
+    }
+
+    fn valid_chars() -> Vec<char> {
+        (0..10)
+            .map(|i| char::from_digit(i, 10).expect("Failed to convert digit to character"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::sym::databar::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+    use core::char;
+
+    fn collapse_vec(v: &[u8]) -> String {
+        let chars = v
+            .iter()
+            .map(|d| char::from_digit(u32::from(*d), 10).expect("Failed to convert digit to char"));
+        chars.collect()
+    }
+
+    #[test]
+    fn new_databar14() {
+        let databar = DataBar14::new("2001234567890");
+
+        assert!(databar.is_ok());
+    }
+
+    #[test]
+    fn invalid_data_databar14() {
+        assert_eq!(
+            DataBar14::new("20012345678A0").err(),
+            Some(Error::Character)
+        );
+        assert_eq!(DataBar14::new("200123456789").err(), Some(Error::TooShort));
+        assert_eq!(
+            DataBar14::new("200123456789090").err(),
+            Some(Error::TooLong)
+        );
+        assert_eq!(
+            DataBar14::new("20012345678908").err(),
+            Some(Error::Checksum)
+        );
+    }
+
+    #[test]
+    fn databar14_encode() {
+        // The example symbol of ISO/IEC 24724, (01)20012345678909.
+        let databar = DataBar14::new("2001234567890").expect("Failed to create DataBar14");
+        let with_check = DataBar14::new("20012345678909").expect("Failed to create DataBar14");
+        let encoded = databar.encode();
+
+        assert_eq!(databar.data_characters(), [276, 312, 1563, 311]);
+        assert_eq!(encoded.len(), 96);
+        assert_eq!(collapse_vec(&encoded), "010100011101000001001111111000010100110110111110110000010010100101100000000111000110110110001101");
+        assert_eq!(with_check.encode(), encoded);
+    }
+
+    #[test]
+    fn databar14_element_widths() {
+        // Every value of every group must have a distinct, valid combination of widths.
+        for (groups, count, outside) in [
+            (&OUTSIDE_GROUPS[..], 2841, true),
+            (&INSIDE_GROUPS[..], 1597, false),
+        ] {
+            let modules = if outside { 16 } else { 15 };
+            let mut seen = Vec::new();
+
+            for value in 0..count {
+                let widths = char_widths(value, outside);
+                let group = groups
+                    .iter()
+                    .rfind(|g| g.start <= value)
+                    .unwrap_or(&groups[0]);
+                let odd = [widths[0], widths[2], widths[4], widths[6]];
+                let even = [widths[1], widths[3], widths[5], widths[7]];
+
+                assert_eq!(widths.iter().sum::<u32>(), modules, "{value}");
+                assert_eq!(odd.iter().sum::<u32>(), group.odd_modules, "{value}");
+                assert_eq!(even.iter().sum::<u32>(), group.even_modules, "{value}");
+                assert!(
+                    odd.iter().all(|&w| w >= 1 && w <= group.odd_widest),
+                    "{value}"
+                );
+                assert!(
+                    even.iter().all(|&w| w >= 1 && w <= group.even_widest),
+                    "{value}"
+                );
+                assert!(!seen.contains(&widths), "{value}");
+                seen.push(widths);
+            }
+        }
+    }
+
+    #[test]
+    fn databar_encoded_len() {
+        let databar = DataBar14::new("2001234567890").expect("Failed to create barcode");
+
+        assert_eq!(databar.encoded_len(), databar.encode().len());
+    }
+}
@@ -0,0 +1,181 @@
+//! Decoding of barcodes whose symbology is not known in advance.
+//!
+//! Scanner applications often receive a stream of modules without knowing which symbology
+//! produced it. `auto` tries each of the available decoders in turn, starting with the
+//! fixed-length EAN/UPC symbologies, and returns the first successful decode.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::sym::decode;
+//! use scanning::sym::ean13::*;
+//! use scanning::sym::Symbology;
+//!
+//! let barcode = EAN13::new("750103131130").unwrap();
+//! let decoded = decode::auto(&barcode.encode()).unwrap();
+//!
+//! assert_eq!(decoded, (Symbology::EAN13, String::from("7501031311309")));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::sym::codabar::Codabar;
+use crate::sym::code11::Code11;
+use crate::sym::code128::Code128;
+use crate::sym::code39::Code39;
+use crate::sym::code93::Code93;
+use crate::sym::ean13::EAN13;
+use crate::sym::ean8::EAN8;
+use crate::sym::helpers::{self, Vec};
+use crate::sym::tf::TF;
+use crate::sym::Symbology;
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+type Decoder = fn(&[u8]) -> Result<String>;
+
+/// The decoders of the variable-length symbologies, in the order in which they are tried.
+///
+/// Each decoder only accepts its own start and stop patterns. Codabar is tried last, as its
+/// patterns are the least distinctive.
+const DECODERS: [(Symbology, Decoder); 7] = [
+    (Symbology::Code128, Code128::decode),
+    (Symbology::Code39, Code39::decode),
+    (Symbology::Code93, Code93::decode),
+    (Symbology::Code11, Code11::decode),
+    (Symbology::ITF, TF::decode_interleaved),
+    (Symbology::STF, TF::decode_standard),
+    (Symbology::Codabar, Codabar::decode),
+];
+
+/// Decodes a sequence of bars of an unknown symbology, returning the detected symbology along
+/// with the decoded data.
+///
+/// Symbols 95 modules wide are first tried as EAN-13, and reported as UPC-A if the leading
+/// digit is 0 (UPC-A symbols being EAN-13 symbols with a leading 0). Symbols 67 modules wide
+/// are first tried as EAN-8. Then Code128, Code39, Code93, Code11, Interleaved 2 of 5,
+/// Standard 2 of 5 and Codabar are tried in turn. Interleaved 2 of 5 symbols holding a GTIN-14
+/// with a valid check digit are reported as ITF-14. The data is returned in the form returned
+/// by the decoder of the detected symbology, e.g. including any check digits of EAN/UPC
+/// symbols.
+///
+/// # Errors
+/// Returns an `Error::Character` if the bars cannot be decoded as any supported symbology.
+pub fn auto(bars: &[u8]) -> Result<(Symbology, String)> {
+    let fixed = match bars.len() {
+        95 => EAN13::decode(bars).map(|data| {
+            let upca = data.strip_prefix('0').map(ToString::to_string);
+
+            upca.map_or((Symbology::EAN13, data), |upca| (Symbology::UPCA, upca))
+        }),
+        67 => EAN8::decode(bars).map(|data| (Symbology::EAN8, data)),
+        _ => Err(Error::Length),
+    };
+
+    fixed.or_else(|_| {
+        DECODERS
+            .iter()
+            .find_map(|&(sym, decode)| decode(bars).ok().map(|data| (sym, data)))
+            .map(|(sym, data)| match sym {
+                Symbology::ITF if is_gtin14(&data) => (Symbology::ITF14, data),
+                _ => (sym, data),
+            })
+            .ok_or(Error::Character)
+    })
+}
+
+// Whether the data is 14 digits ending with a valid GS1 check digit.
+fn is_gtin14(data: &str) -> bool {
+    data.len() == 14
+        && helpers::strip_check_digit(data).is_ok_and(|(body, check)| {
+            let digits: Vec<u8> = body.bytes().map(|b| b - b'0').collect();
+
+            helpers::modulo_10_checksum(&digits) == check
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sym::code128::CharacterSet;
+    use crate::sym::decode::*;
+    use crate::sym::upca::UPCA;
+
+    #[test]
+    fn auto_detects_fixed_length_symbologies() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let upca = UPCA::new("03600029145").expect("Failed to create UPCA barcode");
+        let ean8 = EAN8::new("5512345").expect("Failed to create EAN8 barcode");
+
+        assert_eq!(
+            auto(&ean13.encode()),
+            Ok((Symbology::EAN13, String::from("7501031311309")))
+        );
+        assert_eq!(
+            auto(&upca.encode()),
+            Ok((Symbology::UPCA, String::from("036000291452")))
+        );
+        assert_eq!(
+            auto(&ean8.encode()),
+            Ok((Symbology::EAN8, String::from("55123457")))
+        );
+    }
+
+    #[test]
+    fn auto_detects_variable_length_symbologies() {
+        let code128 = Code128::new("ƁHello", CharacterSet::None).expect("Failed to create Code128");
+        let code39 = Code39::new("CODE-39").expect("Failed to create Code39");
+        let code93 = Code93::new("TEST93").expect("Failed to create Code93");
+        let code11 = Code11::new("123-45").expect("Failed to create Code11");
+        let itf = TF::interleaved("1234567").expect("Failed to create ITF barcode");
+        let itf14 = TF::itf14("1540014128876").expect("Failed to create ITF-14 barcode");
+        let stf = TF::standard("1234567").expect("Failed to create STF barcode");
+        let codabar = Codabar::new("A40156B").expect("Failed to create Codabar");
+
+        assert_eq!(
+            auto(&code128.encode()),
+            Ok((Symbology::Code128, String::from("ƁHello")))
+        );
+        assert_eq!(
+            auto(&code39.encode()),
+            Ok((Symbology::Code39, String::from("CODE-39")))
+        );
+        assert_eq!(
+            auto(&code93.encode()),
+            Ok((Symbology::Code93, String::from("TEST93")))
+        );
+        assert_eq!(
+            auto(&code11.encode()),
+            Ok((Symbology::Code11, String::from("123-45")))
+        );
+        assert_eq!(
+            auto(&itf.encode()),
+            Ok((Symbology::ITF, String::from("12345670")))
+        );
+        assert_eq!(
+            auto(&itf14.encode()),
+            Ok((Symbology::ITF14, String::from("15400141288763")))
+        );
+        // Fourteen digits without a valid check digit are not a GTIN-14.
+        assert_eq!(
+            auto(
+                &TF::interleaved_no_pad("15400141288760")
+                    .expect("Failed to create ITF barcode")
+                    .encode()
+            ),
+            Ok((Symbology::ITF, String::from("15400141288760")))
+        );
+        assert_eq!(
+            auto(&stf.encode()),
+            Ok((Symbology::STF, String::from("1234567")))
+        );
+        assert_eq!(
+            auto(&codabar.encode()),
+            Ok((Symbology::Codabar, String::from("A40156B")))
+        );
+    }
+
+    #[test]
+    fn auto_rejects_unknown_bars() {
+        assert_eq!(auto(&[1, 0, 1, 1, 0, 0, 1]), Err(Error::Character));
+        assert_eq!(auto(&[]), Err(Error::Character));
+    }
+}
@@ -7,9 +7,14 @@
 //!   * EAN-13
 //!   * Bookland
 //!   * JAN
+//!
+//! An encoded EAN-13 barcode is always 95 modules wide: two 3-module outer guards, a 5-module
+//! middle guard and twelve 7-module digits (the first digit is encoded in the parity pattern).
 
 use crate::error::{Error, Result};
-use crate::sym::{helpers, Parse};
+use crate::sym::{helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::char;
 use core::ops::Range;
 use helpers::Vec;
@@ -75,6 +80,30 @@ const PARITY: [[usize; 5]; 10] = [
     [1, 1, 0, 1, 0],
 ];
 
+/// Weighted digit products used by the variable-measure price check digit.
+///
+/// The three indices are:
+/// * Weighting factor 2-.
+/// * Weighting factor 3.
+/// * Weighting factor 5-.
+const PRICE_WEIGHTS: [[u8; 10]; 3] = [
+    [0, 2, 4, 6, 8, 9, 1, 3, 5, 7],
+    [0, 3, 6, 9, 2, 5, 8, 1, 4, 7],
+    [0, 5, 9, 4, 8, 3, 7, 2, 6, 1],
+];
+
+/// Maps each digit of a 4-digit variable-measure value to its weighting factor.
+const PRICE_WEIGHT_MAPPING: [usize; 4] = [0, 0, 1, 2];
+
+/// The nominal bar height of an EAN-13 barcode, in hundredths of the X dimension.
+pub(crate) const EAN13_HEIGHT: u64 = 6924;
+
+/// The number of modules in an encoded EAN-13 barcode.
+const ENCODED_LEN: usize = 95;
+
+/// The number of modules in each group of six digits.
+const GROUP_LEN: usize = 6 * 7;
+
 /// The left-hand guard pattern.
 pub const LEFT_GUARD: [u8; 3] = [1, 0, 1];
 /// The middle guard pattern.
@@ -82,6 +111,21 @@ pub const MIDDLE_GUARD: [u8; 5] = [0, 1, 0, 1, 0];
 /// The right-hand guard pattern.
 pub const RIGHT_GUARD: [u8; 3] = [1, 0, 1];
 
+/// The layout of the human-readable text of a symbol, in modules from the start of the symbol.
+///
+/// This describes the familiar `7 501031 311309` grouping, so that renderers can place each
+/// digit group under the bars which encode it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextLayout {
+    /// The width of the slot immediately to the left of the left guard, within the quiet zone,
+    /// in which the leading (number system) digit is centered.
+    pub leading: usize,
+    /// The modules of the left group of digits, between the left and middle guards.
+    pub left: Range<usize>,
+    /// The modules of the right group of digits, between the middle and right guards.
+    pub right: Range<usize>,
+}
+
 /// The EAN-13 barcode type.
 #[derive(Debug)]
 pub struct EAN13(Vec<u8>);
@@ -95,36 +139,214 @@ pub type Bookland = EAN13;
 pub type JAN = EAN13;
 
 impl EAN13 {
+    /// The layout of the human-readable digits: the leading digit, then two groups of six.
+    pub const TEXT_LAYOUT: TextLayout = TextLayout {
+        leading: 7,
+        left: LEFT_GUARD.len()..(LEFT_GUARD.len() + GROUP_LEN),
+        right: (ENCODED_LEN - RIGHT_GUARD.len() - GROUP_LEN)..(ENCODED_LEN - RIGHT_GUARD.len()),
+    };
+
+    /// The modules of the left, middle and right guards.
+    pub const GUARDS: [Range<usize>; 3] = [
+        0..LEFT_GUARD.len(),
+        Self::TEXT_LAYOUT.left.end..Self::TEXT_LAYOUT.right.start,
+        (ENCODED_LEN - RIGHT_GUARD.len())..ENCODED_LEN,
+    ];
+
     /// Creates a new barcode.
     ///
     /// # Errors
     /// Returns an `Error::Checksum` if the checksum digit is invalid.
     /// Returns an `Error::Character` if the input contains invalid characters.
-    /// Returns an `Error::Length` if the input length is not valid.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is not valid.
     ///
-    /// # Panics
-    /// Panics if the input contains a character that cannot be converted to a digit.
+    /// # Examples
+    /// The returned `Result` must be used, so accidentally discarding it is caught:
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// scanning::sym::ean13::EAN13::new("750103131130");
+    /// ```
+    /// ```
+    /// #![deny(unused_must_use)]
+    /// let ean13 = scanning::sym::ean13::EAN13::new("750103131130");
+    /// assert!(ean13.is_ok());
+    /// ```
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
-        let d = Self::parse(data.as_ref())?;
-        #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-        let digits: Vec<u8> = d
+        Self::body(data.as_ref()).map(EAN13)
+    }
+
+    // Runs the same checks as `EAN13::new` without keeping the digits.
+    pub(crate) fn validate(data: &str) -> Result<()> {
+        Self::body(data).map(|_| ())
+    }
+
+    // Parses the data as digits, verifying and removing the check digit if one is provided.
+    fn body(data: &str) -> Result<Vec<u8>> {
+        let d = Self::parse(data)?;
+        let digits = d
             .chars()
-            .map(|c| c.to_digit(10).expect("Unknown character") as u8)
-            .collect();
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
 
-        let ean13 = Self(digits[0..12].to_vec());
+        Self::check(digits)
+    }
+
+    /// Creates a new barcode from data that may separate groups of digits with hyphens or spaces,
+    /// such as a GTIN entered by hand (e.g. "7-501031-311309").
+    ///
+    /// # Errors
+    /// Returns the same errors as `EAN13::new`, once the separators are removed.
+    pub fn new_formatted<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::new(helpers::strip_separators(data.as_ref()))
+    }
+
+    /// Creates a new barcode from a slice of ASCII digit bytes (e.g. `b"750103131130"`).
+    ///
+    /// This avoids the UTF-8 validation needed to build a `&str` when the data is already
+    /// available as raw bytes.
+    ///
+    /// # Errors
+    /// Returns an `Error::Checksum` if the checksum digit is invalid.
+    /// Returns an `Error::Character` if the input contains bytes other than ASCII digits.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is not valid.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let d = Self::parse_bytes(data)?;
+        let digits: Vec<u8> = d.iter().map(|b| b - b'0').collect();
 
+        Self::check(digits).map(EAN13)
+    }
+
+    fn check(mut digits: Vec<u8>) -> Result<Vec<u8>> {
         // If checksum digit is provided, check the checksum.
-        if digits.len() == 13 && ean13.checksum_digit() != digits[12] {
+        if digits.len() == 13 && helpers::modulo_10_checksum(&digits[0..12]) != digits[12] {
             return Err(Error::Checksum);
         }
 
-        Ok(ean13)
+        digits.truncate(12);
+        Ok(digits)
+    }
+
+    /// Creates a new variable-measure barcode (GS1 prefix 2) with an embedded price or weight.
+    ///
+    /// The `item` is the 7-digit prefix and item reference (e.g. "2012345"). The `value` is
+    /// embedded as 4 digits following a price check digit, which is computed using the common
+    /// GS1 weighting scheme.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if `item` contains non-digits or does not start with 2.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if `item` is not 7 digits.
+    /// Returns an `Error::TooLong` if `value` does not fit in 4 digits.
+    pub fn variable_measure<T: AsRef<str>>(item: T, value: u32) -> Result<Self> {
+        let item = item.as_ref();
+
+        if item.len() < 7 {
+            return Err(Error::TooShort);
+        }
+
+        if item.len() > 7 || value > 9999 {
+            return Err(Error::TooLong);
+        }
+
+        let mut digits = item
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+
+        if digits[0] != 2 {
+            return Err(Error::Character);
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // Safe: each digit is in 0..=9
+        let value_digits = [
+            (value / 1000) as u8,
+            (value / 100 % 10) as u8,
+            (value / 10 % 10) as u8,
+            (value % 10) as u8,
+        ];
+
+        digits.push(Self::price_check_digit(value_digits));
+        digits.extend(value_digits.iter().copied());
+
+        Ok(Self(digits))
+    }
+
+    /// Returns the embedded price or weight of a variable-measure barcode.
+    ///
+    /// Returns `None` if the barcode is not variable-measure (prefix 2) or if the embedded
+    /// price check digit does not match the value.
+    #[must_use]
+    pub fn variable_measure_value(&self) -> Option<u32> {
+        if self.0[0] != 2 {
+            return None;
+        }
+
+        let value_digits = [self.0[8], self.0[9], self.0[10], self.0[11]];
+
+        if Self::price_check_digit(value_digits) != self.0[7] {
+            return None;
+        }
+
+        Some(
+            value_digits
+                .iter()
+                .fold(0, |acc, &d| (acc * 10) + u32::from(d)),
+        )
+    }
+
+    /// Creates a new barcode from a GTIN-14 with an indicator digit of `0`.
+    ///
+    /// The indicator digit is stripped, leaving the 13-digit EAN-13 form.
+    ///
+    /// # Errors
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input is not 14 characters.
+    /// Returns an `Error::Character` if the input contains invalid characters.
+    /// Returns an `Error::Conversion` if the indicator digit is not `0`, as such a GTIN-14 has no
+    /// EAN-13 equivalent.
+    /// Returns an `Error::Checksum` if the checksum digit is invalid.
+    pub fn from_gtin14<T: AsRef<str>>(data: T) -> Result<Self> {
+        let data = data.as_ref();
+
+        match data.chars().count() {
+            n if n < 14 => Err(Error::TooShort),
+            n if n > 14 => Err(Error::TooLong),
+            _ => match data.strip_prefix('0') {
+                Some(ean13) => Self::new(ean13),
+                None if data.bytes().all(|b| b.is_ascii_digit()) => Err(Error::Conversion),
+                None => Err(Error::Character),
+            },
+        }
+    }
+
+    /// Returns the digits of the barcode, excluding the check digit.
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the GTIN-14 form of the barcode data, i.e. the 13 digits prefixed with a `0`
+    /// indicator digit and followed by a recomputed check digit.
+    #[must_use]
+    pub fn gtin14(&self) -> String {
+        let mut digits: Vec<u8> = core::iter::once(0).chain(self.0.iter().copied()).collect();
+        digits.push(helpers::modulo_10_checksum(&digits[..]));
+
+        digits.iter().map(|&d| char::from(b'0' + d)).collect()
+    }
+
+    /// Calculates the price check digit of a 4-digit variable-measure value.
+    fn price_check_digit(value: [u8; 4]) -> u8 {
+        let sum: u8 = value
+            .iter()
+            .zip(PRICE_WEIGHT_MAPPING.iter())
+            .map(|(&d, &w)| PRICE_WEIGHTS[w][d as usize])
+            .sum();
+
+        (sum * 3) % 10
     }
 
     /// Calculates the checksum digit using a modulo-10 weighting algorithm.
     fn checksum_digit(&self) -> u8 {
-        helpers::modulo_10_checksum(&self.0[..], true)
+        helpers::modulo_10_checksum(&self.0[..])
     }
 
     fn number_system_digit(&self) -> u8 {
@@ -155,42 +377,130 @@ impl EAN13 {
         PARITY[self.0[0] as usize]
     }
 
-    fn left_payload(&self) -> Vec<u8> {
-        let slices: Vec<[u8; 7]> = self
-            .left_digits()
-            .iter()
-            .zip(self.parity_mapping().iter())
-            .map(|(d, s)| Self::char_encoding(*s, *d))
-            .collect();
+    /// Encodes the barcode.
+    /// Returns a Vec<u8> of binary digits.
+    ///
+    /// EAN-13 barcodes are always 95 modules wide, so each encoding is written directly into a
+    /// fixed-size array rather than being joined from intermediate payloads.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut enc = [0; ENCODED_LEN];
+        let mut pos = 0;
+        let mut put = |from: &[u8]| {
+            enc[pos..pos + from.len()].copy_from_slice(from);
+            pos += from.len();
+        };
+
+        put(&LEFT_GUARD);
+        put(&self.number_system_encoding());
+
+        for (d, s) in self.left_digits().iter().zip(self.parity_mapping().iter()) {
+            put(&Self::char_encoding(*s, *d));
+        }
 
-        helpers::join_iters(slices.iter())
+        put(&MIDDLE_GUARD);
+
+        for d in self.right_digits() {
+            put(&Self::char_encoding(2, *d));
+        }
+
+        put(&self.checksum_encoding());
+        put(&RIGHT_GUARD);
+
+        debug_assert_eq!(pos, self.encoded_len());
+        enc.to_vec()
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is always 95.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        ENCODED_LEN
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its 13 digits, including
+    /// the check digit.
+    ///
+    /// The leading digit is recovered from the parity pattern of the left group of digits.
+    /// EAN-13 can be scanned in either direction, so reversed bars are decoded too. The first
+    /// digit of the left group always has odd parity, which reversed bars never have, so the
+    /// direction is never ambiguous.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the bars are not 95 modules wide.
+    /// Returns an `Error::Character` if a guard or digit pattern is unknown.
+    /// Returns an `Error::Checksum` if the check digit does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        Self::decode_forward(bars)
+            .or_else(|e| Self::decode_forward(&helpers::reverse_modules(bars)).map_err(|_| e))
     }
 
-    fn right_payload(&self) -> Vec<u8> {
-        let slices: Vec<[u8; 7]> = self
-            .right_digits()
+    /// Decodes a sequence of bars read left-to-right.
+    fn decode_forward(bars: &[u8]) -> Result<String> {
+        if bars.len() != ENCODED_LEN {
+            return Err(Error::Length);
+        }
+
+        let TextLayout { left, right, .. } = Self::TEXT_LAYOUT;
+
+        if bars[..left.start] != LEFT_GUARD
+            || bars[left.end..right.start] != MIDDLE_GUARD
+            || bars[right.end..] != RIGHT_GUARD
+        {
+            return Err(Error::Character);
+        }
+
+        // The first digit of the left group always has odd parity.
+        let mut digits = helpers::decode_digits(&ENCODINGS[0], &bars[left.start..left.start + 7])?;
+        let mut parity = [0; 5];
+
+        for (side, pattern) in parity
+            .iter_mut()
+            .zip(bars[left.start + 7..left.end].chunks(7))
+        {
+            let (s, d) = match helpers::decode_digits(&ENCODINGS[0], pattern) {
+                Ok(d) => (0, d),
+                Err(_) => (1, helpers::decode_digits(&ENCODINGS[1], pattern)?),
+            };
+
+            *side = s;
+            digits.extend(d);
+        }
+
+        let leading = PARITY
             .iter()
-            .map(|d| Self::char_encoding(2, *d))
-            .collect();
+            .zip(0..)
+            .find(|(p, _)| **p == parity)
+            .map(|(_, d)| d)
+            .ok_or(Error::Character)?;
 
-        helpers::join_iters(slices.iter())
+        digits.insert(0, leading);
+        digits.extend(helpers::decode_digits(&ENCODINGS[2], &bars[right])?);
+
+        let decoded = helpers::digits_to_string(&digits);
+
+        Self::new(&decoded).map(|_| decoded)
     }
 
-    /// Encodes the barcode.
-    /// Returns a Vec<u8> of binary digits.
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is derived from the nominal EAN-13 bar height of 22.85mm at an X dimension of 0.33mm.
     #[must_use]
-    pub fn encode(&self) -> Vec<u8> {
-        helpers::join_slices(
-            &[
-                &LEFT_GUARD[..],
-                &self.number_system_encoding()[..],
-                &self.left_payload()[..],
-                &MIDDLE_GUARD[..],
-                &self.right_payload()[..],
-                &self.checksum_encoding()[..],
-                &RIGHT_GUARD[..],
-            ][..],
-        )
+    #[allow(clippy::unused_self)]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim(xdim, EAN13_HEIGHT)
+    }
+}
+
+impl Barcode for EAN13 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -212,6 +522,7 @@ impl Parse for EAN13 {
 mod tests {
     use crate::error::Error;
     use crate::sym::ean13::*;
+    use crate::sym::helpers;
     #[cfg(not(feature = "std"))]
     use alloc::string::String;
     use core::char;
@@ -230,6 +541,37 @@ mod tests {
         assert!(ean13.is_ok());
     }
 
+    #[test]
+    fn new_ean13_formatted() {
+        let ean13 = EAN13::new("7501031311309").expect("Failed to create EAN13 barcode");
+
+        for data in ["7-501031-311309", "7 501031 311309", "750103131130"] {
+            let formatted = EAN13::new_formatted(data).expect("Failed to create EAN13 barcode");
+
+            assert_eq!(formatted.encode(), ean13.encode());
+        }
+
+        assert_eq!(
+            EAN13::new_formatted("7-50103A-311309").err(),
+            Some(Error::Character)
+        );
+        assert_eq!(
+            EAN13::new_formatted("7-501031-311308").err(),
+            Some(Error::Checksum)
+        );
+        assert_eq!(
+            EAN13::new_formatted("7-501031").err(),
+            Some(Error::TooShort)
+        );
+    }
+
+    #[test]
+    fn ean13_digits() {
+        let ean13 = EAN13::new("7501031311309").expect("Failed to create EAN13 barcode");
+
+        assert_eq!(ean13.digits(), &[7, 5, 0, 1, 0, 3, 1, 3, 1, 1, 3, 0]);
+    }
+
     #[test]
     fn new_bookland() {
         let bookland = Bookland::new("978456123456");
@@ -247,13 +589,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ean13_from_bytes_matches_new() {
+        let from_str = EAN13::new("7501031311309").expect("Failed to create EAN13");
+        let from_bytes =
+            EAN13::from_bytes(b"7501031311309").expect("Failed to create EAN13 from bytes");
+
+        assert_eq!(from_bytes.encode(), from_str.encode());
+        assert_eq!(from_bytes.checksum_digit(), from_str.checksum_digit());
+    }
+
+    #[test]
+    fn invalid_data_ean13_from_bytes() {
+        let ean13 = EAN13::from_bytes(&[
+            b'7', b'5', 0xC3, b'1', b'0', b'3', b'1', b'3', b'1', b'1', b'3', b'0',
+        ]);
+
+        assert_eq!(
+            ean13.expect_err("Expected an Error::Character but got None"),
+            Error::Character
+        );
+    }
+
+    #[test]
+    fn ean13_gtin14() {
+        let ean13 = EAN13::new("7501031311309").expect("Failed to create EAN13");
+        let from_gtin14 =
+            EAN13::from_gtin14("07501031311309").expect("Failed to create EAN13 from GTIN-14");
+
+        assert_eq!(ean13.gtin14(), "07501031311309");
+        assert_eq!(from_gtin14.encode(), ean13.encode());
+        assert_eq!(from_gtin14.gtin14(), "07501031311309");
+    }
+
+    #[test]
+    fn invalid_gtin14_ean13() {
+        assert_eq!(
+            EAN13::from_gtin14("17501031311306").expect_err("Expected an Error::Conversion"),
+            Error::Conversion
+        );
+        assert_eq!(
+            EAN13::from_gtin14("07501031311308").expect_err("Expected an Error::Checksum"),
+            Error::Checksum
+        );
+        assert_eq!(
+            EAN13::from_gtin14("0750103131130").expect_err("Expected an Error::TooShort"),
+            Error::TooShort
+        );
+        assert_eq!(
+            EAN13::from_gtin14("1750103131130A").expect_err("Expected an Error::Character"),
+            Error::Character
+        );
+    }
+
     #[test]
     fn invalid_len_ean13() {
         let ean13 = EAN13::new("1111112222222333333");
 
         assert_eq!(
-            ean13.expect_err("Expected an Error::Length but got None"),
-            Error::Length
+            ean13.expect_err("Expected an Error::TooLong but got None"),
+            Error::TooLong
+        );
+    }
+
+    #[test]
+    fn short_len_ean13() {
+        let ean13 = EAN13::new("1234567");
+
+        assert_eq!(
+            ean13.expect_err("Expected an Error::TooShort but got None"),
+            Error::TooShort
         );
     }
 
@@ -267,6 +672,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_variable_measure_ean13() {
+        let ean13 = EAN13::variable_measure("2012345", 2875)
+            .expect("Failed to create variable-measure EAN13 barcode with valid data");
+
+        assert_eq!(ean13.0, [2, 0, 1, 2, 3, 4, 5, 9, 2, 8, 7, 5]);
+        assert_eq!(ean13.checksum_digit(), 6);
+        assert_eq!(ean13.variable_measure_value(), Some(2875));
+    }
+
+    #[test]
+    fn invalid_variable_measure_ean13() {
+        let ean131 = EAN13::variable_measure("7012345", 2875);
+        let ean132 = EAN13::variable_measure("2012345", 10000);
+        let ean133 = EAN13::variable_measure("201234", 2875);
+
+        assert_eq!(
+            ean131.expect_err("Expected an Error::Character but got None"),
+            Error::Character
+        );
+        assert_eq!(
+            ean132.expect_err("Expected an Error::TooLong but got None"),
+            Error::TooLong
+        );
+        assert_eq!(
+            ean133.expect_err("Expected an Error::TooShort but got None"),
+            Error::TooShort
+        );
+    }
+
+    #[test]
+    fn non_variable_measure_ean13_value() {
+        let ean131 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let ean132 = EAN13::new("201234512875").expect("Failed to create EAN13 barcode");
+
+        assert_eq!(ean131.variable_measure_value(), None);
+        assert_eq!(ean132.variable_measure_value(), None);
+    }
+
+    #[test]
+    fn ean13_recommended_min_height() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+
+        assert_eq!(ean13.recommended_min_height(1), 70);
+        assert_eq!(ean13.recommended_min_height(3), 208);
+        assert_eq!(ean13.recommended_min_height(25), 1731);
+    }
+
     #[test]
     fn ean13_encode_as_bookland() {
         let bookland1 = Bookland::new("978345612345")
@@ -278,6 +731,111 @@ mod tests {
         assert_eq!(collapse_vec(&bookland2.encode()), "10101110110001001011001100110010001001000101101010111010011101001001110101000011001101001110101");
     }
 
+    #[test]
+    fn ean13_encode_matches_joined_payloads() {
+        for data in [
+            "750103131130",
+            "983465123499",
+            "000000000000",
+            "999999999999",
+        ] {
+            let ean13 = EAN13::new(data).expect("Failed to create EAN13 barcode with valid data");
+            let d = &ean13.0;
+            let left: Vec<[u8; 7]> = d[1..7]
+                .iter()
+                .zip(core::iter::once(&0).chain(PARITY[d[0] as usize].iter()))
+                .map(|(&n, &s)| ENCODINGS[s][n as usize])
+                .collect();
+            let right: Vec<[u8; 7]> = d[7..]
+                .iter()
+                .chain(core::iter::once(&ean13.checksum_digit()))
+                .map(|&n| ENCODINGS[2][n as usize])
+                .collect();
+            let joined = helpers::join_slices(
+                &[
+                    &LEFT_GUARD[..],
+                    &helpers::join_iters(left.iter())[..],
+                    &MIDDLE_GUARD[..],
+                    &helpers::join_iters(right.iter())[..],
+                    &RIGHT_GUARD[..],
+                ][..],
+            );
+
+            assert_eq!(ean13.encode(), joined);
+        }
+    }
+
+    #[test]
+    fn ean13_text_layout_aligns_with_guards() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let encoded = ean13.encode();
+        let TextLayout {
+            leading,
+            left,
+            right,
+        } = EAN13::TEXT_LAYOUT;
+
+        assert_eq!(leading, 7);
+        assert_eq!((left.clone(), right.clone()), (3..45, 50..92));
+        // Each group holds six 7-module digits, and is bounded by the guards.
+        assert_eq!(left.len(), 6 * 7);
+        assert_eq!(right.len(), 6 * 7);
+        assert_eq!(encoded[..left.start], LEFT_GUARD);
+        assert_eq!(encoded[left.end..right.start], MIDDLE_GUARD);
+        assert_eq!(encoded[right.end..], RIGHT_GUARD);
+    }
+
+    #[test]
+    fn ean13_decode() {
+        for data in [
+            "7501031311309",
+            "9781234567897",
+            "0036000291452",
+            "4901234567894",
+        ] {
+            let ean13 = EAN13::new(data).expect("Failed to create EAN13 barcode");
+
+            assert_eq!(EAN13::decode(&ean13.encode()), Ok(String::from(data)));
+        }
+    }
+
+    #[test]
+    fn invalid_ean13_decode() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let mut encoded = ean13.encode();
+
+        assert_eq!(EAN13::decode(&encoded[1..]), Err(Error::Length));
+        encoded[0] = 0;
+        assert_eq!(EAN13::decode(&encoded), Err(Error::Character));
+
+        // Swap the right-hand check digit '9' for a '8'.
+        let mut encoded = ean13.encode();
+        encoded[85..92].copy_from_slice(&ENCODINGS[2][8]);
+        assert_eq!(EAN13::decode(&encoded), Err(Error::Checksum));
+    }
+
+    #[test]
+    fn ean13_guards() {
+        let encoded = EAN13::new("750103131130")
+            .expect("Failed to create EAN13 barcode")
+            .encode();
+        let [left, middle, right] = EAN13::GUARDS;
+
+        assert_eq!(encoded[left], LEFT_GUARD);
+        assert_eq!(encoded[middle], MIDDLE_GUARD);
+        assert_eq!(encoded[right], RIGHT_GUARD);
+    }
+
+    #[test]
+    fn ean13_decode_reversed() {
+        for data in ["7501031311309", "9781234567897", "0036000291452"] {
+            let ean13 = EAN13::new(data).expect("Failed to create EAN13 barcode");
+            let reversed = helpers::reverse_modules(&ean13.encode());
+
+            assert_eq!(EAN13::decode(&reversed), Ok(String::from(data)));
+        }
+    }
+
     #[test]
     fn ean13_encode() {
         let ean131 =
@@ -288,4 +846,11 @@ mod tests {
         assert_eq!(collapse_vec(&ean131.encode()), "10101100010100111001100101001110111101011001101010100001011001101100110100001011100101110100101");
         assert_eq!(collapse_vec(&ean132.encode()), "10101101110100001001110101011110111001001100101010110110010000101011100111010011101001000010101");
     }
+
+    #[test]
+    fn ean13_encoded_len() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create barcode");
+
+        assert_eq!(ean13.encoded_len(), ean13.encode().len());
+    }
 }
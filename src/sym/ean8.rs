@@ -2,52 +2,108 @@
 //!
 //! EAN-8 barcodes are EAN style barcodes for smaller packages on products like
 //! cigaretts, chewing gum, etc where package space is limited.
+//!
+//! An encoded EAN-8 barcode is always 67 modules wide: two 3-module outer guards, a 5-module
+//! middle guard and eight 7-module digits.
 
 use crate::error::{Error, Result};
 use crate::sym::ean13::{ENCODINGS, LEFT_GUARD, MIDDLE_GUARD, RIGHT_GUARD};
-use crate::sym::{helpers, Parse};
+use crate::sym::{helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::char;
 use core::ops::Range;
 use helpers::{vec, Vec};
 
+/// The nominal bar height of an EAN-8 barcode, in hundredths of the X dimension.
+pub(crate) const EAN8_HEIGHT: u64 = 5524;
+
+/// The number of modules in an encoded EAN-8 barcode.
+const ENCODED_LEN: usize = 67;
+
 /// The EAN-8 barcode type.
 #[derive(Debug)]
 pub struct EAN8(Vec<u8>);
 
 impl EAN8 {
-    /// Creates a new barcode.
+    /// Creates a new barcode from either 7 digits, computing the check digit, or 8 digits,
+    /// verifying the check digit. Prefer `new_body` or `new_with_check` when the intent is known.
     ///
     /// # Errors
     /// Returns an `Error::Checksum` if the provided checksum digit is invalid.
     /// Returns an `Error::Character` if the input contains invalid characters.
-    /// Returns an `Error::Length` if the input length is not valid.
-    ///
-    /// # Panics
-    /// Panics if a character in the input cannot be converted to a digit.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is not valid.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
-        let d = Self::parse(data.as_ref())?;
-        #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-        let digits: Vec<u8> = d
-            .chars()
-            .map(|c| {
-                c.to_digit(10)
-                    .expect("Failed to convert character to digit") as u8
-            })
-            .collect();
+        Self::body(data.as_ref()).map(EAN8)
+    }
 
-        let ean8 = Self(digits[0..7].to_vec());
+    // Runs the same checks as `EAN8::new` without keeping the digits.
+    pub(crate) fn validate(data: &str) -> Result<()> {
+        Self::body(data).map(|_| ())
+    }
+
+    // Parses the data as digits, verifying and removing the check digit if one is provided.
+    fn body(data: &str) -> Result<Vec<u8>> {
+        let d = Self::parse(data)?;
+        let mut digits = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
 
         // If checksum digit is provided, check the checksum.
-        if digits.len() == 8 && ean8.checksum_digit() != digits[7] {
+        if digits.len() == 8 && helpers::modulo_10_checksum(&digits[0..7]) != digits[7] {
             return Err(Error::Checksum);
         }
 
-        Ok(ean8)
+        digits.truncate(7);
+        Ok(digits)
+    }
+
+    /// Creates a new barcode from 7 digits, computing the check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input is not 7 characters long.
+    /// Returns an `Error::Character` if the input contains invalid characters.
+    pub fn new_body<T: AsRef<str>>(data: T) -> Result<Self> {
+        match data.as_ref().chars().count() {
+            0..7 => Err(Error::TooShort),
+            7 => Self::new(data),
+            _ => Err(Error::TooLong),
+        }
+    }
+
+    /// Creates a new barcode from 8 digits, verifying the trailing check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input is not 8 characters long.
+    /// Returns an `Error::Checksum` if the check digit is invalid.
+    /// Returns an `Error::Character` if the input contains invalid characters.
+    pub fn new_with_check<T: AsRef<str>>(data: T) -> Result<Self> {
+        match data.as_ref().chars().count() {
+            0..8 => Err(Error::TooShort),
+            8 => Self::new(data),
+            _ => Err(Error::TooLong),
+        }
+    }
+
+    /// Creates a new barcode from data that may separate groups of digits with hyphens or spaces,
+    /// such as a GTIN entered by hand (e.g. "5512-3457").
+    ///
+    /// # Errors
+    /// Returns the same errors as `EAN8::new`, once the separators are removed.
+    pub fn new_formatted<T: AsRef<str>>(data: T) -> Result<Self> {
+        Self::new(helpers::strip_separators(data.as_ref()))
+    }
+
+    /// Returns the digits of the barcode, excluding the check digit.
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
+        &self.0
     }
 
     /// Calculates the checksum digit using a weighting algorithm.
     fn checksum_digit(&self) -> u8 {
-        helpers::modulo_10_checksum(&self.0[..], false)
+        helpers::modulo_10_checksum(&self.0[..])
     }
 
     fn number_system_digits(&self) -> &[u8] {
@@ -104,7 +160,7 @@ impl EAN8 {
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
-        helpers::join_slices(
+        let enc = helpers::join_slices(
             &[
                 &LEFT_GUARD[..],
                 &self.number_system_encoding()[..],
@@ -114,7 +170,68 @@ impl EAN8 {
                 &self.checksum_encoding()[..],
                 &RIGHT_GUARD[..],
             ][..],
-        )
+        );
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is always 67.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        ENCODED_LEN
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its 8 digits, including
+    /// the check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the bars are not 67 modules wide.
+    /// Returns an `Error::Character` if a guard or digit pattern is unknown.
+    /// Returns an `Error::Checksum` if the check digit does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        if bars.len() != ENCODED_LEN {
+            return Err(Error::Length);
+        }
+
+        let (left, right) = (3..31, 36..64);
+
+        if bars[..left.start] != LEFT_GUARD
+            || bars[left.end..right.start] != MIDDLE_GUARD
+            || bars[right.end..] != RIGHT_GUARD
+        {
+            return Err(Error::Character);
+        }
+
+        let mut digits = helpers::decode_digits(&ENCODINGS[0], &bars[left])?;
+
+        digits.extend(helpers::decode_digits(&ENCODINGS[2], &bars[right])?);
+
+        let decoded = helpers::digits_to_string(&digits);
+
+        Self::new(&decoded).map(|_| decoded)
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is derived from the nominal EAN-8 bar height of 18.23mm at an X dimension of 0.33mm.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim(xdim, EAN8_HEIGHT)
+    }
+}
+
+impl Barcode for EAN8 {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -154,6 +271,56 @@ mod tests {
         assert!(ean8.is_ok());
     }
 
+    #[test]
+    fn new_ean8_formatted() {
+        let ean8 = EAN8::new("55123457").expect("Failed to create EAN8 barcode");
+        let formatted = EAN8::new_formatted("5512-3457").expect("Failed to create EAN8 barcode");
+
+        assert_eq!(formatted.encode(), ean8.encode());
+        assert_eq!(
+            EAN8::new_formatted("5512 345").map(|e| e.encode()),
+            EAN8::new("5512345").map(|e| e.encode())
+        );
+        assert_eq!(
+            EAN8::new_formatted("55AB-3457").err(),
+            Some(Error::Character)
+        );
+    }
+
+    #[test]
+    fn ean8_digits() {
+        let ean8 = EAN8::new("55123457").expect("Failed to create EAN8 barcode");
+
+        assert_eq!(ean8.digits(), &[5, 5, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn new_ean8_explicit() {
+        let body = EAN8::new_body("5512345").expect("Failed to create EAN8 from its body");
+        let with_check =
+            EAN8::new_with_check("55123457").expect("Failed to create EAN8 with its check digit");
+
+        assert_eq!(body.encode(), with_check.encode());
+        assert_eq!(body.checksum_digit(), 7);
+
+        assert_eq!(EAN8::new_body("55123457").err(), Some(Error::TooLong));
+        assert_eq!(EAN8::new_body("551234").err(), Some(Error::TooShort));
+        assert_eq!(EAN8::new_body("55123A5").err(), Some(Error::Character));
+        assert_eq!(EAN8::new_with_check("5512345").err(), Some(Error::TooShort));
+        assert_eq!(
+            EAN8::new_with_check("551234570").err(),
+            Some(Error::TooLong)
+        );
+        assert_eq!(
+            EAN8::new_with_check("55123458").err(),
+            Some(Error::Checksum)
+        );
+        assert_eq!(
+            EAN8::new_with_check("5512345A").err(),
+            Some(Error::Character)
+        );
+    }
+
     #[test]
     fn invalid_data_ean8() {
         let ean8 = EAN8::new("1234er1");
@@ -169,8 +336,8 @@ mod tests {
         let ean8 = EAN8::new("1111112222222333333");
 
         assert_eq!(
-            ean8.expect_err("Expected an Error::Length but got None"),
-            Error::Length
+            ean8.expect_err("Expected an Error::TooLong but got None"),
+            Error::TooLong
         );
     }
 
@@ -184,6 +351,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ean8_decode() {
+        for data in ["12345670", "55123457", "96385074"] {
+            let ean8 = EAN8::new(data).expect("Failed to create EAN8 barcode");
+
+            assert_eq!(EAN8::decode(&ean8.encode()), Ok(String::from(data)));
+        }
+
+        let mut encoded = EAN8::new("1234567")
+            .expect("Failed to create EAN8 barcode")
+            .encode();
+
+        assert_eq!(EAN8::decode(&encoded[..66]), Err(Error::Length));
+        encoded[57..64].copy_from_slice(&ENCODINGS[2][1]);
+        assert_eq!(EAN8::decode(&encoded), Err(Error::Checksum));
+    }
+
     #[test]
     fn ean8_encode() {
         let ean81 = EAN8::new("5512345").expect("Failed to create EAN8 barcode for '5512345'"); // Check digit: 7
@@ -198,4 +382,11 @@ mod tests {
             "1010001011011011101111010100011010101010000100111011001101010000101"
         );
     }
+
+    #[test]
+    fn ean8_encoded_len() {
+        let ean8 = EAN8::new("1234567").expect("Failed to create barcode");
+
+        assert_eq!(ean8.encoded_len(), ean8.encode().len());
+    }
 }
@@ -5,16 +5,27 @@
 //! EAN-5 barcodes are often used to indicate the suggested retail price of books.
 //!
 //! These supplemental barcodes never appear without a full EAN-13 barcode alongside them.
+//! `EANSUPP::encode_checked` enforces this, unless rendering on its own is explicitly allowed.
+//!
+//! An encoded supplement of `n` digits is `9n + 2` modules wide: a 4-module guard, 7 modules per
+//! digit and a 2-module separator between each pair of digits. EAN-2 is 20 modules wide and EAN-5
+//! is 47.
 
 use crate::error::{Error, Result};
 use crate::sym::ean13::ENCODINGS;
-use crate::sym::{helpers, Parse};
+use crate::sym::{helpers, Barcode, Parse};
 use core::char;
 use core::ops::Range;
 use helpers::{vec, Vec};
 
 const LEFT_GUARD: [u8; 4] = [1, 0, 1, 1];
 
+/// The recommended gap (in modules) between a main symbol and its supplement.
+pub const DEFAULT_GAP: usize = 9;
+
+/// The nominal bar height of a supplemental barcode, in hundredths of the X dimension.
+pub(crate) const SUPPLEMENT_HEIGHT: u64 = 6636;
+
 /// Maps parity (odd/even) for the EAN-5 barcodes based on the check digit.
 const EAN5_PARITY: [[usize; 5]; 10] = [
     [0, 0, 1, 1, 1],
@@ -38,12 +49,31 @@ const EAN2_PARITY: [[usize; 5]; 4] = [
 ];
 
 /// The Supplemental EAN barcode type.
+///
+/// Each variant holds the digits of the barcode along with their parity pattern.
 #[derive(Debug)]
 pub enum EANSUPP {
     /// EAN-2 supplemental barcode type.
-    EAN2(Vec<u8>),
+    EAN2(Vec<u8>, [usize; 5]),
     /// EAN-5 supplemental barcode type.
-    EAN5(Vec<u8>),
+    EAN5(Vec<u8>, [usize; 5]),
+}
+
+/// A main symbol (e.g. EAN-13) combined with a supplemental symbol, separated by a gap of
+/// background modules.
+///
+/// The module ranges of each region are kept so that generators can place the human-readable
+/// text of each symbol correctly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Combined {
+    /// The combined module stream.
+    pub modules: Vec<u8>,
+    /// The modules of the main symbol.
+    pub main: Range<usize>,
+    /// The background modules separating the two symbols.
+    pub gap: Range<usize>,
+    /// The modules of the supplemental symbol.
+    pub supplement: Range<usize>,
 }
 
 impl EANSUPP {
@@ -54,33 +84,40 @@ impl EANSUPP {
     /// the length of `data`.
     ///
     /// # Errors
+    /// Returns `Error::TooShort` or `Error::TooLong` if the length of `data` is outside 2 to 5.
     /// Returns `Error::Length` if the length of `data` is not 2 or 5.
     /// Returns `Error::Character` if `data` contains invalid characters.
-    ///
-    /// # Panics
-    /// Panics if `data` contains a character that cannot be converted to a digit.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
-        Self::parse(data.as_ref()).and_then(|d| {
-            #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-            let digits: Vec<u8> = d
-                .chars()
-                .map(|c| {
-                    c.to_digit(10)
-                        .expect("Failed to convert character to digit") as u8
-                })
-                .collect();
-
-            match digits.len() {
-                2 => Ok(Self::EAN2(digits)),
-                5 => Ok(Self::EAN5(digits)),
-                _ => Err(Error::Length),
-            }
-        })
+        let (digits, parity) = Self::body(data.as_ref())?;
+
+        match digits.len() {
+            2 => Ok(Self::EAN2(digits, parity)),
+            _ => Ok(Self::EAN5(digits, parity)),
+        }
     }
 
-    fn raw_data(&self) -> &[u8] {
+    // Runs the same checks as `EANSUPP::new` without keeping the digits.
+    pub(crate) fn validate(data: &str) -> Result<()> {
+        Self::body(data).map(|_| ())
+    }
+
+    // Parses the data as digits, along with their parity pattern.
+    fn body(data: &str) -> Result<(Vec<u8>, [usize; 5])> {
+        let d = Self::parse(data)?;
+        let digits = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+        let parity = Self::parity(&digits)?;
+
+        Ok((digits, parity))
+    }
+
+    /// Returns the digits of the barcode.
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
         match *self {
-            Self::EAN2(ref d) | Self::EAN5(ref d) => &d[..],
+            Self::EAN2(ref d, _) | Self::EAN5(ref d, _) => &d[..],
         }
     }
 
@@ -90,10 +127,9 @@ impl EANSUPP {
 
     /// Calculates the checksum digit using a modified modulo-10 weighting
     /// algorithm. This only makes sense for EAN5 barcodes.
-    fn checksum_digit(&self) -> u8 {
+    fn checksum_digit(data: &[u8]) -> u8 {
         let mut odds = 0;
         let mut evens = 0;
-        let data = self.raw_data();
 
         for (i, d) in data.iter().enumerate() {
             match i % 2 {
@@ -108,25 +144,31 @@ impl EANSUPP {
         }
     }
 
-    fn parity(&self) -> [usize; 5] {
-        match *self {
-            Self::EAN2(ref d) => {
-                let modulo = ((d[0] * 10) + d[1]) % 4;
-                EAN2_PARITY[modulo as usize]
+    // Looks up the parity pattern of the given EAN-2 or EAN-5 digits.
+    fn parity(digits: &[u8]) -> Result<[usize; 5]> {
+        match *digits {
+            [a, b] => {
+                let modulo = ((a * 10) + b) % 4;
+                EAN2_PARITY
+                    .get(usize::from(modulo))
+                    .copied()
+                    .ok_or(Error::Conversion)
             }
-            Self::EAN5(ref _d) => {
-                let check = self.checksum_digit() as usize;
-                EAN5_PARITY[check]
+            [_, _, _, _, _] => {
+                let check = usize::from(Self::checksum_digit(digits));
+                EAN5_PARITY.get(check).copied().ok_or(Error::Conversion)
             }
+            _ => Err(Error::Length),
         }
     }
 
     fn payload(&self) -> Vec<u8> {
         let mut p = vec![];
+        let (Self::EAN2(_, parity) | Self::EAN5(_, parity)) = self;
         let slices: Vec<[u8; 7]> = self
-            .raw_data()
+            .digits()
             .iter()
-            .zip(self.parity().iter())
+            .zip(parity.iter())
             .map(|(d, s)| Self::char_encoding(*s, *d))
             .collect();
 
@@ -146,7 +188,77 @@ impl EANSUPP {
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
-        helpers::join_slices(&[&LEFT_GUARD[..], &self.payload()[..]][..])
+        let enc = helpers::join_slices(&[&LEFT_GUARD[..], &self.payload()[..]][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is 20 for EAN-2 and 47 for EAN-5.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        9 * self.digits().len() + 2
+    }
+
+    /// Combines the encoded main symbol with this supplement, separated by `gap` background
+    /// modules (see `DEFAULT_GAP`).
+    #[must_use]
+    pub fn combine(&self, main: &[u8], gap: usize) -> Combined {
+        let supplement = self.encode();
+        let gap_start = main.len();
+        let supplement_start = gap_start + gap;
+        let mut modules = Vec::with_capacity(supplement_start + supplement.len());
+
+        modules.extend_from_slice(main);
+        modules.resize(supplement_start, 0);
+        modules.extend_from_slice(&supplement);
+
+        Combined {
+            main: 0..gap_start,
+            gap: gap_start..supplement_start,
+            supplement: supplement_start..modules.len(),
+            modules,
+        }
+    }
+
+    /// Encodes the barcode combined with the given encoded main symbol, separated by
+    /// `DEFAULT_GAP` modules, or on its own if there is no main symbol and `allow_standalone` is
+    /// set.
+    ///
+    /// Unlike `encode`, this catches a supplement accidentally rendered without its main symbol.
+    ///
+    /// # Errors
+    /// Returns an `Error::Generate` if there is no main symbol and `allow_standalone` is not set.
+    /// Returns an `Error::Length` if the main symbol is empty.
+    pub fn encode_checked(&self, main: Option<&[u8]>, allow_standalone: bool) -> Result<Vec<u8>> {
+        match main {
+            Some([]) => Err(Error::Length),
+            Some(main) => Ok(self.combine(main, DEFAULT_GAP).modules),
+            None if allow_standalone => Ok(self.encode()),
+            None => Err(Error::Generate),
+        }
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is derived from the nominal EAN supplemental bar height of 21.90mm at an X dimension of 0.33mm.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim(xdim, SUPPLEMENT_HEIGHT)
+    }
+}
+
+impl Barcode for EANSUPP {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -167,6 +279,7 @@ impl Parse for EANSUPP {
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
+    use crate::sym::ean13::EAN13;
     use crate::sym::ean_supp::*;
     #[cfg(not(feature = "std"))]
     use alloc::string::String;
@@ -193,6 +306,24 @@ mod tests {
         assert!(ean5.is_ok());
     }
 
+    #[test]
+    fn ean_supp_stores_parity() {
+        let ean2 = EANSUPP::new("34").expect("Failed to create EAN2 barcode");
+        let ean5 = EANSUPP::new("51234").expect("Failed to create EAN5 barcode");
+
+        assert!(matches!(ean2, EANSUPP::EAN2(_, p) if p == EAN2_PARITY[2]));
+        assert!(matches!(ean5, EANSUPP::EAN5(_, p) if p == EAN5_PARITY[9]));
+    }
+
+    #[test]
+    fn ean_supp_digits() {
+        let ean2 = EANSUPP::new("34").expect("Failed to create EAN2 barcode");
+        let ean5 = EANSUPP::new("51234").expect("Failed to create EAN5 barcode");
+
+        assert_eq!(ean2.digits(), &[3, 4]);
+        assert_eq!(ean5.digits(), &[5, 1, 2, 3, 4]);
+    }
+
     #[test]
     fn invalid_data_ean2() {
         let ean2 = EANSUPP::new("AT");
@@ -213,6 +344,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ean2_parity_all_modulo_values() {
+        for n in 0..100 {
+            let data = [n / 10, n % 10];
+
+            assert_eq!(EANSUPP::parity(&data), Ok(EAN2_PARITY[usize::from(n % 4)]));
+        }
+    }
+
+    #[test]
+    fn ean5_parity_all_check_digits() {
+        let mut seen = [false; 10];
+
+        for n in 0..10 {
+            for m in 0..10 {
+                let data = [n, m, 0, 0, 0];
+                let check = EANSUPP::checksum_digit(&data);
+
+                assert_eq!(EANSUPP::parity(&data), Ok(EAN5_PARITY[usize::from(check)]));
+                seen[usize::from(check)] = true;
+            }
+        }
+
+        assert!(seen.iter().all(|&s| s));
+    }
+
     #[test]
     fn ean2_encode() {
         let ean21 = EANSUPP::new("34").expect("Failed to create EAN2 barcode from input '34'");
@@ -220,6 +377,36 @@ mod tests {
         assert_eq!(collapse_vec(&ean21.encode()), "10110100001010100011");
     }
 
+    #[test]
+    fn ean5_combine() {
+        let ean51 =
+            EANSUPP::new("51234").expect("Failed to create EAN5 barcode from input '51234'");
+        let main = [1, 0, 1, 1, 0, 1];
+        let combined = ean51.combine(&main, 3);
+
+        assert_eq!(combined.main, 0..6);
+        assert_eq!(combined.gap, 6..9);
+        assert_eq!(combined.supplement, 9..56);
+        assert_eq!(&combined.modules[..9], &[1, 0, 1, 1, 0, 1, 0, 0, 0]);
+        assert_eq!(&combined.modules[9..], &ean51.encode()[..]);
+    }
+
+    #[test]
+    fn ean_supp_encode_checked() {
+        let ean5 = EANSUPP::new("51234").expect("Failed to create EAN5 barcode");
+        let main = EAN13::new("750103131130")
+            .expect("Failed to create EAN13 barcode")
+            .encode();
+
+        assert_eq!(
+            ean5.encode_checked(Some(&main), false),
+            Ok(ean5.combine(&main, DEFAULT_GAP).modules)
+        );
+        assert_eq!(ean5.encode_checked(None, true), Ok(ean5.encode()));
+        assert_eq!(ean5.encode_checked(None, false), Err(Error::Generate));
+        assert_eq!(ean5.encode_checked(Some(&[]), true), Err(Error::Length));
+    }
+
     #[test]
     fn ean5_encode() {
         let ean51 =
@@ -230,4 +417,13 @@ mod tests {
             "10110110001010011001010011011010111101010011101"
         );
     }
+
+    #[test]
+    fn ean_supp_encoded_len() {
+        let ean_supp1 = EANSUPP::new("12").expect("Failed to create barcode");
+        let ean_supp2 = EANSUPP::new("51234").expect("Failed to create barcode");
+
+        assert_eq!(ean_supp1.encoded_len(), ean_supp1.encode().len());
+        assert_eq!(ean_supp2.encoded_len(), ean_supp2.encode().len());
+    }
 }
@@ -1,7 +1,11 @@
+use crate::error::{Error, Result};
 #[cfg(not(feature = "std"))]
-pub(crate) use alloc::vec;
+use alloc::string::String;
 #[cfg(not(feature = "std"))]
-pub(crate) use alloc::vec::Vec;
+pub use alloc::vec;
+#[cfg(not(feature = "std"))]
+pub use alloc::vec::Vec;
+use core::cmp;
 #[cfg(feature = "std")]
 pub use std::vec;
 #[cfg(feature = "std")]
@@ -14,35 +18,625 @@ pub fn join_slices(slices: &[&[u8]]) -> Vec<u8> {
 }
 
 /// Joins and flattens the given iterator of iterables into a Vec<u8>.
+///
+/// `flatten` is used rather than `flat_map`, as it is several times faster for the short, fixed
+/// size encodings joined here (see the `join_iters` benchmark).
 pub fn join_iters<'a, T: Iterator>(iters: T) -> Vec<u8>
 where
     T::Item: IntoIterator<Item = &'a u8>,
 {
-    iters.flat_map(IntoIterator::into_iter).copied().collect()
+    iters.flatten().copied().collect()
 }
 
-/// Calculates the checksum digit using a modulo-10 weighting algorithm.
-pub fn modulo_10_checksum(data: &[u8], even_start: bool) -> u8 {
-    let mut odds = 0;
-    let mut evens = 0;
+/// Returns the given modules in reverse order, as seen by a scanner reading right-to-left.
+pub fn reverse_modules(modules: &[u8]) -> Vec<u8> {
+    modules.iter().rev().copied().collect()
+}
 
-    for (i, d) in data.iter().enumerate() {
-        match i % 2 {
-            1 => odds += *d,
-            _ => evens += *d,
-        }
+/// Returns whether the module stream contains only binary digits (0 or 1).
+#[cfg(any(
+    feature = "ascii",
+    feature = "json",
+    feature = "svg",
+    feature = "pbm",
+    feature = "zpl",
+    feature = "unicode"
+))]
+pub fn is_binary(modules: &[u8]) -> bool {
+    modules.iter().all(|&m| m <= 1)
+}
+
+/// Ensures the module stream is non-empty and contains only binary digits (0 or 1).
+///
+/// Generators call this before rendering so that malformed input produces an error rather
+/// than a panic or a silently corrupted image. An empty stream is an `Error::Length`, as an
+/// empty barcode is meaningless, whilst a non-binary stream is an `Error::Conversion`.
+#[cfg(any(
+    feature = "ascii",
+    feature = "json",
+    feature = "svg",
+    feature = "pbm",
+    feature = "zpl",
+    feature = "unicode"
+))]
+pub fn validate_modules(modules: &[u8]) -> Result<()> {
+    if modules.is_empty() {
+        return Err(Error::Length);
+    }
+
+    if !is_binary(modules) {
+        return Err(Error::Conversion);
+    }
+
+    Ok(())
+}
+
+/// Packs the given row of pixels (0 or 1) into bytes, eight to a byte with the first pixel in
+/// the most significant bit, as used by bitmap formats. The last byte is padded with zeros.
+#[cfg(any(feature = "pbm", feature = "zpl"))]
+pub fn pack_bits(pixels: &[u8]) -> Vec<u8> {
+    pixels
+        .chunks(8)
+        .map(|byte| {
+            byte.iter()
+                .enumerate()
+                .fold(0, |packed, (i, &p)| packed | (p << (7 - i)))
+        })
+        .collect()
+}
+
+/// Renders every element that is wider than a single module as `wide` modules, leaving the
+/// narrow elements untouched. This adjusts the wide/narrow ratio of two-width symbologies.
+#[cfg(feature = "svg")]
+pub fn stretch_wide(barcode: &[u8], wide: usize) -> Vec<u8> {
+    let mut stretched = Vec::with_capacity(barcode.len());
+    let mut rest = barcode;
+
+    while let Some(&module) = rest.first() {
+        let run = rest.iter().take_while(|&&m| m == module).count();
+        let width = if run > 1 { wide } else { 1 };
+
+        stretched.extend(core::iter::repeat_n(module, width));
+        rest = &rest[run..];
+    }
+
+    stretched
+}
+
+/// Scales the X dimension by the given factor (in hundredths), rounding up.
+pub fn scale_xdim(xdim: u32, hundredths: u64) -> u32 {
+    u32::try_from((u64::from(xdim) * hundredths).div_ceil(100)).unwrap_or(u32::MAX)
+}
+
+/// Scales the X dimension to 15% of the width of a symbol with the given number of modules,
+/// rounding up.
+pub fn scale_xdim_by_width(xdim: u32, modules: usize) -> u32 {
+    scale_xdim(
+        xdim,
+        u64::try_from(modules)
+            .unwrap_or(u64::MAX)
+            .saturating_mul(15),
+    )
+}
+
+/// A mapping of characters to their encodings, in the order of their checksum values.
+///
+/// This is shared by the linear symbologies (Code11, Code39 and Code93) which look up each
+/// character's pattern and compute weighted checksums from each character's position.
+#[derive(Debug)]
+pub struct CharTable<E: 'static>(&'static [(char, E)]);
+
+impl<E: Copy> CharTable<E> {
+    /// Creates a table from the given mappings.
+    pub const fn new(chars: &'static [(char, E)]) -> Self {
+        Self(chars)
+    }
+
+    /// Returns the set of characters in the table.
+    pub fn chars(&self) -> Vec<char> {
+        self.0.iter().map(|&(c, _)| c).collect()
+    }
+
+    /// Returns the position (i.e. the checksum value) of the given character.
+    pub fn position(&self, c: char) -> Option<usize> {
+        self.0.iter().position(|&(ch, _)| ch == c)
+    }
+
+    /// Returns the encoding of the given character.
+    pub fn encoding(&self, c: char) -> Option<E> {
+        self.0.iter().find(|&&(ch, _)| ch == c).map(|&(_, enc)| enc)
+    }
+
+    /// Returns whether every character and every encoding in the table is distinct.
+    ///
+    /// Lookups return the first match, so a duplicate would silently produce the wrong
+    /// encoding or checksum.
+    #[cfg(test)]
+    pub fn is_unique(&self) -> bool
+    where
+        E: PartialEq,
+    {
+        self.0.iter().enumerate().all(|(i, (c, enc))| {
+            self.0[i + 1..]
+                .iter()
+                .all(|(other_c, other_enc)| c != other_c && enc != other_enc)
+        })
+    }
+
+    /// Returns the mappings of the table.
+    pub const fn entries(&self) -> &'static [(char, E)] {
+        self.0
+    }
+
+    /// Calculates a checksum character from the positions of the given characters, using the
+    /// given checksum algorithm.
+    ///
+    /// Returns `None` if the data contains a character that is not in the table.
+    pub fn checksum<C: Checksum>(&self, data: &[char], algorithm: &C) -> Option<char> {
+        let symbols = data
+            .iter()
+            .map(|&c| self.position(c))
+            .collect::<Option<Vec<usize>>>()?;
+
+        self.0.get(algorithm.compute(&symbols)).map(|&(c, _)| c)
+    }
+}
+
+/// A checksum algorithm, computing a check value from the values of a sequence of symbols.
+///
+/// Symbologies differ only in how symbols are weighted and in the modulus, so each algorithm
+/// is shared by every symbology which uses it.
+pub trait Checksum {
+    /// Returns the check value of the given symbol values.
+    fn compute(&self, symbols: &[usize]) -> usize;
+}
+
+/// The GS1 modulo-10 algorithm, used by EAN/UPC, ITF and Codabar.
+///
+/// Weights alternate between 3 and 1, starting with 3 for the rightmost symbol, and the check
+/// value is the amount needed to round the sum up to a multiple of 10.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulo10;
+
+/// The weighted modulo-11 algorithm used by Code11.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulo11 {
+    /// The weight after which weights wrap back to 1 (see `weighted_sum`).
+    pub weight_threshold: usize,
+}
+
+/// The unweighted modulo-43 algorithm used by Code39.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulo43;
+
+/// The weighted modulo-47 algorithm used by Code93.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulo47 {
+    /// The weight after which weights wrap back to 1 (see `weighted_sum`).
+    pub weight_threshold: usize,
+}
+
+/// The modulo-103 algorithm used by Code128.
+///
+/// The first symbol (the start character) has a weight of 1, and every following symbol is
+/// weighted by its position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modulo103;
+
+impl Modulo11 {
+    /// The C check character.
+    pub const C: Self = Self {
+        weight_threshold: 10,
+    };
+    /// The K check character.
+    pub const K: Self = Self {
+        weight_threshold: 9,
+    };
+}
+
+impl Modulo47 {
+    /// The C check character.
+    pub const C: Self = Self {
+        weight_threshold: 20,
+    };
+    /// The K check character.
+    pub const K: Self = Self {
+        weight_threshold: 15,
+    };
+}
+
+impl Checksum for Modulo10 {
+    fn compute(&self, symbols: &[usize]) -> usize {
+        let sum = symbols
+            .iter()
+            .rev()
+            .enumerate()
+            .fold(0, |acc, (i, &s)| (acc + (s * (3 - (2 * (i % 2))))) % 10);
+
+        (10 - sum) % 10
     }
+}
+
+impl Checksum for Modulo11 {
+    fn compute(&self, symbols: &[usize]) -> usize {
+        weighted_sum(symbols, self.weight_threshold, 11)
+    }
+}
+
+impl Checksum for Modulo43 {
+    fn compute(&self, symbols: &[usize]) -> usize {
+        weighted_sum(symbols, 1, 43)
+    }
+}
+
+impl Checksum for Modulo47 {
+    fn compute(&self, symbols: &[usize]) -> usize {
+        weighted_sum(symbols, self.weight_threshold, 47)
+    }
+}
 
-    // EAN-13 (and some others?) barcodes use EVEN-first weighting to maintain
-    // backwards compatibility.
-    if even_start {
-        odds *= 3;
-    } else {
-        evens *= 3;
+impl Checksum for Modulo103 {
+    // The sum is reduced as it is accumulated so that it cannot overflow, however long the data.
+    fn compute(&self, symbols: &[usize]) -> usize {
+        symbols
+            .iter()
+            .enumerate()
+            .fold(0, |t, (i, &s)| (t + (s * (cmp::max(1, i) % 103))) % 103)
     }
+}
 
-    match 10 - ((odds + evens) % 10) {
-        10 => 0,
+/// Calculates a weighted sum modulo `modulus`.
+///
+/// Weights are assigned from the rightmost symbol, starting at 1 and wrapping back to 1
+/// after `weight_threshold`. A `weight_threshold` of 1 gives an unweighted sum.
+fn weighted_sum(symbols: &[usize], weight_threshold: usize, modulus: usize) -> usize {
+    let weight = |i| match i % weight_threshold {
+        0 => weight_threshold,
         n => n,
+    };
+
+    symbols
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (i, &s)| (acc + (weight(i + 1) * s)) % modulus)
+}
+
+/// Converts a decimal digit character into its value.
+///
+/// # Errors
+/// Returns an `Error::Character` if the character is not an ASCII digit.
+pub fn char_to_digit(c: char) -> Result<u8> {
+    u8::try_from(c)
+        .ok()
+        .filter(u8::is_ascii_digit)
+        .map(|b| b - b'0')
+        .ok_or(Error::Character)
+}
+
+/// Removes the hyphens and spaces that separate groups of digits in human-entered data, such as
+/// "7-501031-311300". Any other character is kept, so that it is rejected by the parser.
+pub fn strip_separators(data: &str) -> String {
+    data.chars().filter(|c| !matches!(c, '-' | ' ')).collect()
+}
+
+/// Decodes a sequence of 7-module EAN/UPC digit patterns, using the given table of patterns.
+///
+/// # Errors
+/// Returns an `Error::Character` if a pattern is not in the table.
+pub fn decode_digits(table: &[[u8; 7]; 10], bars: &[u8]) -> Result<Vec<u8>> {
+    bars.chunks(7)
+        .map(|pattern| {
+            table
+                .iter()
+                .zip(0..)
+                .find(|(enc, _)| enc[..] == *pattern)
+                .map(|(_, d)| d)
+                .ok_or(Error::Character)
+        })
+        .collect()
+}
+
+/// Converts a sequence of digit values into a string of decimal characters.
+pub fn digits_to_string(digits: &[u8]) -> String {
+    digits.iter().map(|&d| char::from(b'0' + d)).collect()
+}
+
+/// Calculates the checksum digit using the GS1 modulo-10 algorithm (see `Modulo10`).
+pub fn modulo_10_checksum(data: &[u8]) -> u8 {
+    let symbols: Vec<usize> = data.iter().map(|&d| usize::from(d)).collect();
+
+    u8::try_from(Modulo10.compute(&symbols)).expect("Modulo-10 check digit should fit in u8")
+}
+
+/// Calculates the checksum digit using the Luhn modulo-10 algorithm, as used by MSI.
+///
+/// Every other digit is doubled, starting with the rightmost, and the digits of the products
+/// are summed. The check digit is the amount needed to round the sum up to a multiple of 10.
+pub fn luhn_checksum(data: &[u8]) -> u8 {
+    let sum = data.iter().rev().enumerate().fold(0, |acc, (i, &d)| {
+        let d = if i % 2 == 0 { d * 2 } else { d };
+
+        (acc + (d / 10) + (d % 10)) % 10
+    });
+
+    (10 - sum) % 10
+}
+
+/// Calculates the check value using the IBM modulo-11 algorithm, as used by MSI.
+///
+/// Weights cycle from 2 to 7, starting with 2 for the rightmost digit. The check value is the
+/// amount needed to round the sum up to a multiple of 11, so it may be 10.
+pub fn modulo_11_checksum(data: &[u8]) -> u8 {
+    let sum = data
+        .iter()
+        .rev()
+        .zip((2..=7).cycle())
+        .fold(0, |acc, (&d, w)| (acc + (d * w)) % 11);
+
+    (11 - sum) % 11
+}
+
+/// Splits a GTIN-8, GTIN-12, GTIN-13 or GTIN-14 into its body and its check digit.
+///
+/// The check digit is separated but not verified, so that callers can validate it against
+/// `modulo_10_checksum` or re-render the body.
+///
+/// For example:
+///
+/// ```rust
+/// use scanning::sym::strip_check_digit;
+///
+/// assert_eq!(strip_check_digit("7501031311309"), Ok(("750103131130", 9)));
+/// ```
+///
+/// # Errors
+/// Returns an `Error::Length` if the input is not 8, 12, 13 or 14 characters long.
+/// Returns an `Error::Character` if the input contains characters that are not digits.
+pub fn strip_check_digit(gtin: &str) -> Result<(&str, u8)> {
+    if !matches!(gtin.len(), 8 | 12 | 13 | 14) {
+        return Err(Error::Length);
+    }
+
+    if !gtin.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::Character);
+    }
+
+    let (body, check) = gtin.split_at(gtin.len() - 1);
+
+    Ok((body, check.as_bytes()[0] - b'0'))
+}
+
+/// The standard base64 alphabet.
+#[cfg(feature = "svg")]
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes the given bytes as standard, padded base64.
+#[cfg(feature = "svg")]
+pub fn base64(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &b)| acc | (u32::from(b) << (16 - (i * 8))));
+
+        for i in 0..4 {
+            if i > chunk.len() {
+                output.push('=');
+            } else {
+                let index = (n >> (18 - (i * 6))) & 0x3F;
+                output.push(char::from(BASE64_CHARS[index as usize]));
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sym::helpers::*;
+
+    const TABLE: CharTable<[u8; 2]> =
+        CharTable::new(&[('0', [1, 0]), ('1', [1, 1]), ('2', [0, 1]), ('3', [0, 0])]);
+
+    #[test]
+    fn join_iters_matches_flat_map() {
+        let flat_map = |slices: &[[u8; 7]]| -> Vec<u8> {
+            slices
+                .iter()
+                .flat_map(IntoIterator::into_iter)
+                .copied()
+                .collect()
+        };
+        let encodings: Vec<[u8; 7]> = (0..13).map(|i| [1, 0, i % 2, 1, 1, i % 3, 0]).collect();
+        let nested: Vec<Vec<u8>> = vec![vec![1, 0], vec![], vec![1, 1, 0]];
+
+        assert_eq!(join_iters(encodings.iter()), flat_map(&encodings));
+        assert_eq!(join_iters(encodings.iter()).len(), 91);
+        assert_eq!(join_iters(nested.iter()), [1, 0, 1, 1, 0]);
+        assert!(join_iters(core::iter::empty::<&[u8; 7]>()).is_empty());
+    }
+
+    #[test]
+    fn char_table_lookup() {
+        assert_eq!(TABLE.chars(), ['0', '1', '2', '3']);
+        assert_eq!(TABLE.position('2'), Some(2));
+        assert_eq!(TABLE.position('4'), None);
+        assert_eq!(TABLE.encoding('1'), Some([1, 1]));
+        assert_eq!(TABLE.encoding('4'), None);
+    }
+
+    #[test]
+    fn char_table_is_unique() {
+        const DUPLICATE_CHAR: CharTable<[u8; 2]> = CharTable::new(&[('0', [1, 0]), ('0', [1, 1])]);
+        const DUPLICATE_ENCODING: CharTable<[u8; 2]> =
+            CharTable::new(&[('0', [1, 0]), ('1', [1, 0])]);
+
+        assert!(TABLE.is_unique());
+        assert!(!DUPLICATE_CHAR.is_unique());
+        assert!(!DUPLICATE_ENCODING.is_unique());
+    }
+
+    // A weighted modulo-4 algorithm, matching the size of the test table.
+    struct Modulo4(usize);
+
+    impl Checksum for Modulo4 {
+        fn compute(&self, symbols: &[usize]) -> usize {
+            weighted_sum(symbols, self.0, 4)
+        }
+    }
+
+    #[test]
+    fn char_table_weighted_checksum() {
+        // Unweighted: 1 + 2 + 3 = 6 % 4 = 2.
+        assert_eq!(TABLE.checksum(&['1', '2', '3'], &Modulo4(1)), Some('2'));
+        // Weights 1, 2, 1 from the right: (1 * 1) + (2 * 2) + (3 * 1) = 8 % 4 = 0.
+        assert_eq!(TABLE.checksum(&['1', '2', '3'], &Modulo4(2)), Some('0'));
+        // Weights 1, 2, 3 from the right: (1 * 3) + (2 * 2) + (3 * 1) = 10 % 4 = 2.
+        assert_eq!(TABLE.checksum(&['1', '2', '3'], &Modulo4(3)), Some('2'));
+        assert_eq!(TABLE.checksum(&['1', '4'], &Modulo4(3)), None);
+    }
+
+    #[test]
+    fn reverse_modules_values() {
+        assert_eq!(reverse_modules(&[1, 1, 0, 1, 0, 0]), vec![0, 0, 1, 0, 1, 1]);
+        assert_eq!(reverse_modules(&[1, 0, 1]), vec![1, 0, 1]);
+        assert!(reverse_modules(&[]).is_empty());
+    }
+
+    #[test]
+    fn char_to_digit_values() {
+        assert_eq!(char_to_digit('0'), Ok(0));
+        assert_eq!(char_to_digit('9'), Ok(9));
+        assert_eq!(char_to_digit('A'), Err(Error::Character));
+        assert_eq!(char_to_digit('/'), Err(Error::Character));
+        assert_eq!(char_to_digit('\u{0660}'), Err(Error::Character));
+    }
+
+    #[test]
+    fn modulo_10_checksums() {
+        // EAN-13, UPC-A and EAN-8 bodies, all weighted 3 from the rightmost digit.
+        assert_eq!(Modulo10.compute(&[7, 5, 0, 1, 0, 3, 1, 3, 1, 1, 3, 0]), 9);
+        assert_eq!(Modulo10.compute(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]), 2);
+        assert_eq!(Modulo10.compute(&[1, 2, 3, 4, 5, 6, 7]), 0);
+        assert_eq!(modulo_10_checksum(&[7, 5, 0, 1, 0, 3, 1, 3, 1, 1, 3, 0]), 9);
+        // Long inputs must not overflow.
+        assert_eq!(modulo_10_checksum(&[9; 100]), 0);
+    }
+
+    #[test]
+    fn modulo_11_checksums() {
+        // Code11 "123-45", where '-' has the value 10.
+        assert_eq!(Modulo11::C.compute(&[1, 2, 3, 10, 4, 5]), 5);
+        assert_eq!(Modulo11::K.compute(&[1, 2, 3, 10, 4, 5, 5]), 2);
+    }
+
+    #[test]
+    fn msi_checksums() {
+        assert_eq!(luhn_checksum(&[1, 2, 3, 4, 5, 6, 7]), 4);
+        assert_eq!(luhn_checksum(&[1, 2, 3, 4, 5, 6, 7, 4]), 1);
+        assert_eq!(luhn_checksum(&[9; 100]), 0);
+        assert_eq!(modulo_11_checksum(&[1, 2, 3, 4, 5, 6, 7]), 4);
+        assert_eq!(modulo_11_checksum(&[6]), 10);
+        assert_eq!(modulo_11_checksum(&[9; 100]), 1);
+    }
+
+    #[test]
+    fn modulo_43_checksums() {
+        // Code39 "1234" has the check character 'A'.
+        assert_eq!(Modulo43.compute(&[1, 2, 3, 4]), 10);
+    }
+
+    #[test]
+    fn modulo_47_checksums() {
+        // Code93 "TEST93" has the check characters '+' and '6'.
+        assert_eq!(Modulo47::C.compute(&[29, 14, 28, 29, 9, 3]), 41);
+        assert_eq!(Modulo47::K.compute(&[29, 14, 28, 29, 9, 3, 41]), 6);
+    }
+
+    #[test]
+    fn modulo_103_checksums() {
+        // Code128 "PJJ123C" in set B (START-B has the value 104):
+        // 104 + (48 * 1) + (42 * 2) + (42 * 3) + (17 * 4) + (18 * 5) + (19 * 6) + (35 * 7) = 879.
+        assert_eq!(
+            Modulo103.compute(&[104, 48, 42, 42, 17, 18, 19, 35]),
+            879 % 103
+        );
+    }
+
+    #[test]
+    fn strip_separators_keeps_other_characters() {
+        assert_eq!(strip_separators("7-501031-311300"), "7501031311300");
+        assert_eq!(strip_separators("5512 3457"), "55123457");
+        assert_eq!(strip_separators("55-12A3457"), "5512A3457");
+    }
+
+    #[test]
+    fn strip_check_digit_gtins() {
+        assert_eq!(strip_check_digit("12345670"), Ok(("1234567", 0)));
+        assert_eq!(strip_check_digit("036000291452"), Ok(("03600029145", 2)));
+        assert_eq!(strip_check_digit("7501031311309"), Ok(("750103131130", 9)));
+        assert_eq!(
+            strip_check_digit("07501031311309"),
+            Ok(("0750103131130", 9))
+        );
+        assert_eq!(strip_check_digit("1234567"), Err(Error::Length));
+        assert_eq!(strip_check_digit("750103131130912"), Err(Error::Length));
+        assert_eq!(strip_check_digit("75010313113O9"), Err(Error::Character));
+    }
+
+    #[cfg(any(
+        feature = "ascii",
+        feature = "json",
+        feature = "svg",
+        feature = "pbm",
+        feature = "zpl",
+        feature = "unicode"
+    ))]
+    #[test]
+    fn validate_binary_modules() {
+        assert!(is_binary(&[1, 0, 1, 1]));
+        assert!(!is_binary(&[1, 0, 5, 1]));
+        assert_eq!(validate_modules(&[1, 0, 1, 1]), Ok(()));
+        assert_eq!(validate_modules(&[1, 0, 5, 1]), Err(Error::Conversion));
+        assert_eq!(validate_modules(&[]), Err(Error::Length));
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn stretch_wide_runs() {
+        assert_eq!(
+            stretch_wide(&[1, 0, 1, 1, 0, 0, 1], 3),
+            [1, 0, 1, 1, 1, 0, 0, 0, 1]
+        );
+        assert_eq!(stretch_wide(&[1, 1, 1, 0, 1], 2), [1, 1, 0, 1]);
+        assert!(stretch_wide(&[], 3).is_empty());
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn base64_encode() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xFF, 0xEF, 0x00]), "/+8A");
+    }
+
+    #[cfg(any(feature = "pbm", feature = "zpl"))]
+    #[test]
+    fn pack_bits_msb_first() {
+        assert_eq!(pack_bits(&[1, 0, 1, 1, 0, 0, 0, 1]), [0b1011_0001]);
+        assert_eq!(
+            pack_bits(&[1, 1, 1, 0, 0, 0, 1, 1, 1]),
+            [0b1110_0011, 0b1000_0000]
+        );
+        assert!(pack_bits(&[]).is_empty());
     }
 }
@@ -0,0 +1,179 @@
+//! Recommended layout defaults for each symbology.
+//!
+//! Generators require a height, which should be chosen so that the barcode remains scannable.
+//! The functions in this module encapsulate the heights recommended by each specification, so
+//! that callers (and generators) can fall back to them when no height is given. They also
+//! convert physical sizes into whole pixels for a given print or screen resolution.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::sym::layout;
+//! use scanning::sym::Symbology;
+//!
+//! assert_eq!(layout::default_height_modules(Symbology::EAN13, 95), 70);
+//! ```
+
+use crate::sym::databar::DATABAR_HEIGHT;
+use crate::sym::ean13::EAN13_HEIGHT;
+use crate::sym::ean8::EAN8_HEIGHT;
+use crate::sym::ean_supp::SUPPLEMENT_HEIGHT;
+use crate::sym::helpers;
+use crate::sym::upca::UPCA_HEIGHT;
+use crate::sym::Symbology;
+
+/// The minimum bar height of a variable-length symbology, in hundredths of the X dimension.
+///
+/// This is 0.25in (6.35mm) at the commonly used X dimension of 0.25mm.
+const MIN_LINEAR_HEIGHT: u64 = 2540;
+
+/// The number of micrometres in an inch.
+const MICROMETRES_PER_INCH: u64 = 25_400;
+
+/// How a physical size that is not a whole number of pixels is rounded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest pixel, rounding halves up.
+    #[default]
+    Nearest,
+    /// Round up, guaranteeing that the rendered size is at least the physical size.
+    Up,
+    /// Round down, guaranteeing that the rendered size fits within the physical size.
+    Down,
+}
+
+/// Returns the number of pixels covering `micrometres` at a resolution of `dpi` dots per inch,
+/// rounded according to `rounding`.
+///
+/// This is typically used to pick the X dimension (in pixels) for a physical X dimension, such
+/// as the nominal 330µm of EAN-13. Rounding down may return 0 for sizes below one pixel.
+/// Results which do not fit in a `u32` saturate at `u32::MAX`.
+#[must_use]
+pub fn physical_to_pixels(micrometres: u32, dpi: u32, rounding: RoundingMode) -> u32 {
+    let dots = u64::from(micrometres) * u64::from(dpi);
+    let pixels = match rounding {
+        RoundingMode::Nearest => (dots + (MICROMETRES_PER_INCH / 2)) / MICROMETRES_PER_INCH,
+        RoundingMode::Up => dots.div_ceil(MICROMETRES_PER_INCH),
+        RoundingMode::Down => dots / MICROMETRES_PER_INCH,
+    };
+
+    u32::try_from(pixels).unwrap_or(u32::MAX)
+}
+
+/// Returns the recommended bar height, in modules, of a symbol of the given symbology that is
+/// `width_modules` modules wide.
+///
+/// The EAN/UPC symbologies and GS1 DataBar have a fixed nominal height relative to the X
+/// dimension, so the width is ignored for them. For every other symbology this is 15% of the
+/// width of the symbol or 0.25in (see `MIN_LINEAR_HEIGHT`), whichever is greater.
+#[must_use]
+pub fn default_height_modules(sym: Symbology, width_modules: usize) -> u32 {
+    match sym {
+        Symbology::EAN13 => helpers::scale_xdim(1, EAN13_HEIGHT),
+        // UPC-E shares the nominal height of UPC-A.
+        Symbology::UPCA | Symbology::UPCE => helpers::scale_xdim(1, UPCA_HEIGHT),
+        Symbology::EAN8 => helpers::scale_xdim(1, EAN8_HEIGHT),
+        Symbology::EAN2 | Symbology::EAN5 => helpers::scale_xdim(1, SUPPLEMENT_HEIGHT),
+        Symbology::DataBar14 => DATABAR_HEIGHT,
+        Symbology::Code11
+        | Symbology::Code39
+        | Symbology::Code93
+        | Symbology::Code128
+        | Symbology::ITF
+        | Symbology::ITF14
+        | Symbology::STF
+        | Symbology::Codabar
+        | Symbology::MSI => core::cmp::max(
+            helpers::scale_xdim_by_width(1, width_modules),
+            helpers::scale_xdim(1, MIN_LINEAR_HEIGHT),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sym::code128::*;
+    use crate::sym::databar::*;
+    use crate::sym::ean13::*;
+    use crate::sym::layout::*;
+
+    #[test]
+    fn ean13_default_height() {
+        let ean13 = EAN13::new("750103131130").expect("Failed to create EAN13 barcode");
+        let width = ean13.encode().len();
+
+        // 22.85mm at an X dimension of 0.33mm, regardless of the width.
+        assert_eq!(default_height_modules(Symbology::EAN13, width), 70);
+        assert_eq!(default_height_modules(Symbology::EAN13, 1000), 70);
+        assert_eq!(
+            default_height_modules(Symbology::EAN13, width),
+            ean13.recommended_min_height(1)
+        );
+    }
+
+    #[test]
+    fn upce_default_height() {
+        assert_eq!(
+            default_height_modules(Symbology::UPCE, 51),
+            default_height_modules(Symbology::UPCA, 95)
+        );
+    }
+
+    #[test]
+    fn databar_default_height() {
+        let databar = DataBar14::new("2001234567890").expect("Failed to create DataBar barcode");
+
+        assert_eq!(default_height_modules(Symbology::DataBar14, 96), 33);
+        assert_eq!(
+            default_height_modules(Symbology::DataBar14, 96),
+            databar.recommended_min_height(1)
+        );
+    }
+
+    #[test]
+    fn physical_to_pixels_rounding() {
+        // 330µm at 300dpi is 3.9 pixels.
+        assert_eq!(physical_to_pixels(330, 300, RoundingMode::Nearest), 4);
+        assert_eq!(physical_to_pixels(330, 300, RoundingMode::Up), 4);
+        assert_eq!(physical_to_pixels(330, 300, RoundingMode::Down), 3);
+        // 127µm at 300dpi is exactly 1.5 pixels.
+        assert_eq!(physical_to_pixels(127, 300, RoundingMode::Nearest), 2);
+        assert_eq!(physical_to_pixels(127, 300, RoundingMode::Up), 2);
+        assert_eq!(physical_to_pixels(127, 300, RoundingMode::Down), 1);
+        // 254µm at 300dpi is exactly 3 pixels, whichever the rounding.
+        assert_eq!(physical_to_pixels(254, 300, RoundingMode::Nearest), 3);
+        assert_eq!(physical_to_pixels(254, 300, RoundingMode::Up), 3);
+        assert_eq!(physical_to_pixels(254, 300, RoundingMode::Down), 3);
+        // Just over a pixel boundary: 3.0012 pixels.
+        assert_eq!(physical_to_pixels(2541, 30, RoundingMode::Up), 4);
+        assert_eq!(physical_to_pixels(2541, 30, RoundingMode::Down), 3);
+        assert_eq!(
+            physical_to_pixels(u32::MAX, u32::MAX, RoundingMode::Up),
+            u32::MAX
+        );
+        assert_eq!(RoundingMode::default(), RoundingMode::Nearest);
+    }
+
+    #[test]
+    fn code128_default_height() {
+        let short = Code128::new("HELLO", CharacterSet::A).expect("Failed to create Code128");
+        let long = Code128::new(
+            "Ć12345678901234567890123456789012345678901234",
+            CharacterSet::None,
+        )
+        .expect("Failed to create Code128");
+        let long_width = long.encode().len();
+
+        // Short symbols are held at the 0.25in minimum.
+        assert_eq!(
+            default_height_modules(Symbology::Code128, short.encode().len()),
+            26
+        );
+        // Long symbols are 15% of their width.
+        assert_eq!(
+            default_height_modules(Symbology::Code128, long_width),
+            long.recommended_min_height(1)
+        );
+        assert!(default_height_modules(Symbology::Code128, long_width) > 26);
+    }
+}
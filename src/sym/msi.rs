@@ -0,0 +1,246 @@
+//! Encoder for MSI (Modified Plessey) barcodes.
+//!
+//! MSI is a continuous, variable-length, numeric-only symbology used mainly for inventory
+//! control, such as marking warehouse shelves.
+//!
+//! Each digit is encoded as its four bits, most significant first, where each bit is a bar
+//! followed by a space: a wide bar and narrow space for 1, and a narrow bar and wide space for 0.
+//! MSI has no mandatory check digit, so the scheme must be chosen with `MsiCheck`.
+//!
+//! An encoded barcode is `12n + 7` modules wide, where `n` is the number of digits including the
+//! check digits, between a 3-module start and a 4-module stop pattern.
+
+use crate::error::Result;
+use crate::sym::{helpers, Barcode, Parse};
+use core::char;
+use core::ops::Range;
+use helpers::Vec;
+
+// MSI barcodes must start and end with special characters.
+const START: [u8; 3] = [1, 1, 0];
+const STOP: [u8; 4] = [1, 0, 0, 1];
+
+// Bit -> Binary mappings.
+const ZERO: [u8; 3] = [1, 0, 0];
+const ONE: [u8; 3] = [1, 1, 0];
+
+/// The check digit schemes available for MSI barcodes.
+///
+/// - `None`: No check digit.
+/// - `Mod10`: A Luhn modulo-10 check digit.
+/// - `Mod11`: An IBM modulo-11 check digit, encoded as "10" when its value is 10.
+/// - `Mod1010`: A modulo-10 check digit, followed by a second modulo-10 check digit over the data
+///   and the first.
+/// - `Mod1110`: A modulo-11 check digit, followed by a modulo-10 check digit over the data and
+///   the first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MsiCheck {
+    /// No check digit.
+    None,
+    /// Modulo-10.
+    Mod10,
+    /// Modulo-11.
+    Mod11,
+    /// Modulo-10, then modulo-10.
+    Mod1010,
+    /// Modulo-11, then modulo-10.
+    Mod1110,
+}
+
+/// The MSI barcode type.
+#[derive(Debug)]
+pub struct MSI {
+    data: Vec<u8>,
+    check: MsiCheck,
+}
+
+impl MSI {
+    /// Creates a new barcode, using the given check digit scheme.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the input contains characters that are not digits.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is outside the valid
+    /// range.
+    pub fn new<T: AsRef<str>>(data: T, check: MsiCheck) -> Result<Self> {
+        let d = Self::parse(data.as_ref())?;
+        let data = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+
+        Ok(Self { data, check })
+    }
+
+    /// Returns the check digit scheme the barcode was created with.
+    #[must_use]
+    pub const fn check(&self) -> MsiCheck {
+        self.check
+    }
+
+    /// Returns the check digits for the data, according to the check digit scheme.
+    fn check_digits(&self) -> Vec<u8> {
+        let mut digits = self.data.clone();
+
+        match self.check {
+            MsiCheck::None => {}
+            MsiCheck::Mod10 => digits.push(helpers::luhn_checksum(&digits)),
+            MsiCheck::Mod11 => Self::push_modulo_11(&mut digits),
+            MsiCheck::Mod1010 => {
+                digits.push(helpers::luhn_checksum(&digits));
+                digits.push(helpers::luhn_checksum(&digits));
+            }
+            MsiCheck::Mod1110 => {
+                Self::push_modulo_11(&mut digits);
+                digits.push(helpers::luhn_checksum(&digits));
+            }
+        }
+
+        digits.split_off(self.data.len())
+    }
+
+    // A modulo-11 check value of 10 is encoded as the two digits "10".
+    fn push_modulo_11(digits: &mut Vec<u8>) {
+        match helpers::modulo_11_checksum(digits) {
+            10 => digits.extend([1, 0]),
+            d => digits.push(d),
+        }
+    }
+
+    fn char_encoding(d: u8) -> Vec<u8> {
+        let bits: Vec<[u8; 3]> = (0..4)
+            .rev()
+            .map(|i| if (d >> i) & 1 == 1 { ONE } else { ZERO })
+            .collect();
+
+        helpers::join_iters(bits.iter())
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        let encodings: Vec<Vec<u8>> = self
+            .data
+            .iter()
+            .chain(self.check_digits().iter())
+            .map(|&d| Self::char_encoding(d))
+            .collect();
+
+        helpers::join_iters(encodings.iter())
+    }
+
+    /// Encodes the barcode.
+    /// Returns a Vec<u8> of binary digits.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let enc = helpers::join_slices(&[&START[..], &self.payload()[..], &STOP[..]][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        12 * (self.data.len() + self.check_digits().len()) + 7
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for MSI {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
+    }
+}
+
+impl Parse for MSI {
+    /// Returns the valid length of data acceptable in this type of barcode.
+    fn valid_len() -> Range<u32> {
+        1..256
+    }
+
+    /// Returns the set of valid characters allowed in this type of barcode.
+    fn valid_chars() -> Vec<char> {
+        (0..10)
+            .map(|i| char::from_digit(i, 10).expect("Failed to convert digit to character"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::sym::msi::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+    use core::char;
+
+    fn collapse_vec(v: &[u8]) -> String {
+        let chars = v.iter().map(|d| {
+            char::from_digit(u32::from(*d), 10).expect("Failed to convert digit to character")
+        });
+        chars.collect()
+    }
+
+    #[test]
+    fn new_msi() {
+        let msi = MSI::new("1234567", MsiCheck::Mod10).expect("Failed to create MSI barcode");
+
+        assert_eq!(msi.check(), MsiCheck::Mod10);
+    }
+
+    #[test]
+    fn invalid_msi() {
+        assert_eq!(
+            MSI::new("12A4", MsiCheck::None).err(),
+            Some(Error::Character)
+        );
+        assert_eq!(MSI::new("", MsiCheck::None).err(), Some(Error::TooShort));
+    }
+
+    #[test]
+    fn msi_check_digits() {
+        let check_digits = |data, check| {
+            MSI::new(data, check)
+                .expect("Failed to create MSI barcode")
+                .check_digits()
+        };
+
+        assert!(check_digits("1234567", MsiCheck::None).is_empty());
+        assert_eq!(check_digits("1234567", MsiCheck::Mod10), [4]);
+        assert_eq!(check_digits("1234567", MsiCheck::Mod11), [4]);
+        assert_eq!(check_digits("1234567", MsiCheck::Mod1010), [4, 1]);
+        assert_eq!(check_digits("1234567", MsiCheck::Mod1110), [4, 1]);
+        // A modulo-11 check value of 10 is encoded as two digits.
+        assert_eq!(check_digits("6", MsiCheck::Mod11), [1, 0]);
+        assert_eq!(check_digits("6", MsiCheck::Mod1110), [1, 0, 6]);
+    }
+
+    #[test]
+    fn msi_encode() {
+        let msi1 = MSI::new("1234567", MsiCheck::None).expect("Failed to create MSI barcode");
+        let msi2 = MSI::new("1234567", MsiCheck::Mod10).expect("Failed to create MSI barcode"); // Check digit: 4
+
+        assert_eq!(msi1.encode().len(), 91);
+        assert_eq!(collapse_vec(&msi1.encode()), "1101001001001101001001101001001001101101001101001001001101001101001101101001001101101101001");
+        assert_eq!(collapse_vec(&msi2.encode()), "1101001001001101001001101001001001101101001101001001001101001101001101101001001101101101001101001001001");
+    }
+
+    #[test]
+    fn msi_encoded_len() {
+        let msi1 = MSI::new("1234567", MsiCheck::None).expect("Failed to create barcode");
+        let msi2 = MSI::new("1234567", MsiCheck::Mod1110).expect("Failed to create barcode");
+
+        assert_eq!(msi1.encoded_len(), msi1.encode().len());
+        assert_eq!(msi2.encoded_len(), msi2.encode().len());
+    }
+}
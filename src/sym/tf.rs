@@ -6,10 +6,22 @@
 //! groups of products (cartons of Cola, etc).
 //!
 //! Most of the time you will want to use the interleaved barcode over the standard option.
+//!
+//! Interleaved barcodes must contain an even number of digits, so `TF::interleaved` appends a
+//! check digit to odd-length data. Use `TF::interleaved_no_pad` if your data is already complete
+//! and must never be modified; it rejects odd-length data instead.
+//!
+//! ITF-14 barcodes (`TF::itf14`), used on shipping cartons, are interleaved barcodes of exactly 14
+//! digits, normally framed by bearer bars (see `TF::encode_with_bearer`).
+//!
+//! An encoded standard barcode of `n` digits is `14n + 16` modules wide, and an interleaved barcode
+//! is `9n + 8` modules wide.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::sym::helpers;
-use crate::sym::Parse;
+use crate::sym::{Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::char;
 use core::ops::Range;
 use helpers::{vec, Vec};
@@ -27,6 +39,31 @@ const ITF_STOP: [u8; 4] = [1, 1, 0, 1];
 const STF_START: [u8; 8] = [1, 1, 0, 1, 1, 0, 1, 0];
 const STF_STOP: [u8; 8] = [1, 1, 0, 1, 0, 1, 1, 0];
 
+// Each interleaved pair of digits has two wide and three narrow bars, and the same of spaces.
+const ITF_PAIR_LEN: usize = 18;
+
+/// The thickness of ITF-14 bearer bars, in multiples of the X dimension.
+pub const BEARER_WIDTH: u32 = 5;
+
+/// The minimum quiet zone of ITF-14 barcodes, in multiples of the X dimension.
+pub const BEARER_QUIET_ZONE: u32 = 10;
+
+/// An encoded barcode along with the bearer bars framing it, as returned by
+/// `TF::encode_with_bearer`.
+///
+/// Bearer bars are a rendering concern, so generators which support them (such as
+/// `SVG::generate_with_bearer`) draw the frame around the bars and quiet zones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bearer {
+    /// The encoded barcode.
+    pub modules: Vec<u8>,
+    /// The thickness of the bearer bars, in multiples of the X dimension.
+    pub width: u32,
+    /// The minimum quiet zone between the bars and the bearer bars, in multiples of the X
+    /// dimension.
+    pub quiet_zone: u32,
+}
+
 /// The 2-of-5 barcode type.
 #[derive(Debug)]
 pub enum TF {
@@ -44,31 +81,68 @@ impl TF {
     /// # Errors
     /// This function returns an error if the input data contains invalid characters that are not digits.
     ///
-    /// # Panics
-    /// This function will panic if a character in the input data cannot be converted to a digit.
-    ///
     /// Returns Result<`TF::Interleaved`, Error> indicating parse success.
     pub fn interleaved<T: AsRef<str>>(data: T) -> Result<Self> {
-        Self::parse(data.as_ref()).map(|d| {
-            #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-            let mut digits: Vec<u8> = d
+        Self::parse(data.as_ref()).and_then(|d| {
+            let mut digits = d
                 .chars()
-                .map(|c| {
-                    c.to_digit(10)
-                        .expect("Failed to convert character to digit") as u8
-                })
-                .collect();
+                .map(helpers::char_to_digit)
+                .collect::<Result<Vec<u8>>>()?;
             let checksum_required = digits.len() % 2 == 1;
 
             if checksum_required {
-                let check_digit = helpers::modulo_10_checksum(&digits[..], false);
+                let check_digit = helpers::modulo_10_checksum(&digits[..]);
                 digits.push(check_digit);
             }
 
-            Self::Interleaved(digits)
+            Ok(Self::Interleaved(digits))
         })
     }
 
+    /// Creates a new ITF barcode without ever appending a check digit.
+    ///
+    /// Unlike `TF::interleaved`, the data is encoded exactly as given.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the input data contains characters that are not digits.
+    /// Returns an `Error::Length` if the input data has an odd number of digits.
+    ///
+    /// Returns Result<`TF::Interleaved`, Error> indicating parse success.
+    pub fn interleaved_no_pad<T: AsRef<str>>(data: T) -> Result<Self> {
+        let d = Self::parse(data.as_ref())?;
+
+        if d.len() % 2 == 1 {
+            return Err(Error::Length);
+        }
+
+        Self::interleaved(d)
+    }
+
+    /// Creates a new ITF-14 barcode from either 13 digits, computing the check digit, or 14
+    /// digits, verifying the check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the input is not 13 or 14 characters long.
+    /// Returns an `Error::Character` if the input contains characters that are not digits.
+    /// Returns an `Error::Checksum` if the provided check digit is invalid.
+    ///
+    /// Returns Result<`TF::Interleaved`, Error> indicating parse success.
+    pub fn itf14<T: AsRef<str>>(data: T) -> Result<Self> {
+        let data = data.as_ref();
+
+        if !matches!(data.len(), 13 | 14) {
+            return Err(Error::Length);
+        }
+
+        let d = Self::parse(data)?;
+        let itf14 = Self::interleaved(&d[..13])?;
+
+        match d.as_bytes().get(13) {
+            Some(&check) if check - b'0' != itf14.digits()[13] => Err(Error::Checksum),
+            _ => Ok(itf14),
+        }
+    }
+
     /// Creates a new STF barcode.
     ///
     /// Creates a new STF barcode.
@@ -76,25 +150,21 @@ impl TF {
     /// # Errors
     /// This function returns an error if the input data contains invalid characters that are not digits.
     ///
-    /// # Panics
-    /// This function will panic if a character in the input data cannot be converted to a digit.
-    ///
     /// Returns Result<`TF::Standard`, Error> indicating parse success.
     pub fn standard<T: AsRef<str>>(data: T) -> Result<Self> {
-        Self::parse(data.as_ref()).map(|d| {
-            #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-            let digits: Vec<u8> = d
+        Self::parse(data.as_ref()).and_then(|d| {
+            let digits = d
                 .chars()
-                .map(|c| {
-                    c.to_digit(10)
-                        .expect("Failed to convert character to digit") as u8
-                })
-                .collect();
-            Self::Standard(digits)
+                .map(helpers::char_to_digit)
+                .collect::<Result<Vec<u8>>>()?;
+            Ok(Self::Standard(digits))
         })
     }
 
-    fn raw_data(&self) -> &[u8] {
+    /// Returns the digits of the barcode, including the check digit appended to odd-length
+    /// interleaved data.
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
         match *self {
             Self::Standard(ref d) | Self::Interleaved(ref d) => &d[..],
         }
@@ -136,7 +206,7 @@ impl TF {
     fn stf_payload(&self) -> Vec<u8> {
         let mut encodings = vec![];
 
-        for d in self.raw_data() {
+        for d in self.digits() {
             encodings.extend(Self::char_encoding(*d).iter().copied());
         }
 
@@ -145,7 +215,7 @@ impl TF {
 
     fn itf_payload(&self) -> Vec<u8> {
         let weaves: Vec<Vec<u8>> = self
-            .raw_data()
+            .digits()
             .chunks(2)
             .map(|c| Self::interleave(c[0], c[1]))
             .collect();
@@ -153,19 +223,159 @@ impl TF {
         helpers::join_iters(weaves.iter())
     }
 
+    /// Encodes the barcode, along with the ITF-14 bearer bars which should frame it.
+    #[must_use]
+    pub fn encode_with_bearer(&self) -> Bearer {
+        Bearer {
+            modules: self.encode(),
+            width: BEARER_WIDTH,
+            quiet_zone: BEARER_QUIET_ZONE,
+        }
+    }
+
     /// Encodes the barcode.
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
-        match *self {
+        let enc = match *self {
             Self::Standard(_) => {
                 helpers::join_slices(&[&STF_START[..], &self.stf_payload()[..], &STF_STOP[..]][..])
             }
             Self::Interleaved(_) => {
                 helpers::join_slices(&[&ITF_START[..], &self.itf_payload()[..], &ITF_STOP[..]][..])
             }
+        };
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        match *self {
+            Self::Standard(ref d) => 14 * d.len() + 16,
+            Self::Interleaved(ref d) => 9 * d.len() + 8,
         }
     }
+
+    /// Decodes a sequence of STF bars (as produced by `encode`) back into its digits.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the start/stop framing is missing or a digit pattern is
+    /// unknown.
+    /// Returns an `Error::TooShort` if there are no digits between the start and stop patterns.
+    pub fn decode_standard(bars: &[u8]) -> Result<String> {
+        let mut rest = bars
+            .strip_prefix(&STF_START[..])
+            .and_then(|b| b.strip_suffix(&STF_STOP[..]))
+            .ok_or(Error::Character)?;
+        let mut decoded = String::new();
+
+        while !rest.is_empty() {
+            let mut widths = ['N'; 5];
+
+            for w in &mut widths {
+                if let Some(r) = rest.strip_prefix(&[1, 1, 1, 0][..]) {
+                    *w = 'W';
+                    rest = r;
+                } else {
+                    rest = rest.strip_prefix(&[1, 0][..]).ok_or(Error::Character)?;
+                }
+            }
+
+            let digit = WIDTHS
+                .iter()
+                .position(|ws| ws.chars().eq(widths.iter().copied()))
+                .and_then(|d| char::from_digit(u32::try_from(d).ok()?, 10))
+                .ok_or(Error::Character)?;
+
+            decoded.push(digit);
+        }
+
+        if decoded.is_empty() {
+            return Err(Error::TooShort);
+        }
+
+        Ok(decoded)
+    }
+
+    /// Decodes a sequence of ITF bars (as produced by `encode`) back into its digits, including
+    /// any check digit appended by `TF::interleaved`.
+    ///
+    /// Each pair of digits is read from 18 modules in which the bars encode the first digit and
+    /// the interleaved spaces encode the second.
+    ///
+    /// # Errors
+    /// Returns an `Error::Character` if the start/stop framing is missing or a digit pattern is
+    /// unknown.
+    /// Returns an `Error::Length` if the modules between the start and stop patterns do not form
+    /// a whole number of digit pairs.
+    /// Returns an `Error::TooShort` if there are no digits between the start and stop patterns.
+    pub fn decode_interleaved(bars: &[u8]) -> Result<String> {
+        let payload = bars
+            .strip_prefix(&ITF_START[..])
+            .and_then(|b| b.strip_suffix(&ITF_STOP[..]))
+            .ok_or(Error::Character)?;
+
+        if payload.is_empty() {
+            return Err(Error::TooShort);
+        }
+
+        if payload.len() % ITF_PAIR_LEN != 0 {
+            return Err(Error::Length);
+        }
+
+        let mut decoded = String::with_capacity(payload.len() / ITF_PAIR_LEN * 2);
+
+        for pair in payload.chunks(ITF_PAIR_LEN) {
+            let mut widths = [['N'; 5]; 2];
+            let mut rest = pair;
+
+            for i in 0..10 {
+                let module = u8::from(i % 2 == 0);
+                let run = rest.iter().take_while(|&&m| m == module).count();
+
+                widths[i % 2][i / 2] = match run {
+                    1 => 'N',
+                    3 => 'W',
+                    _ => return Err(Error::Character),
+                };
+                rest = &rest[run..];
+            }
+
+            for ws in &widths {
+                let digit = WIDTHS
+                    .iter()
+                    .position(|w| w.chars().eq(ws.iter().copied()))
+                    .and_then(|d| char::from_digit(u32::try_from(d).ok()?, 10))
+                    .ok_or(Error::Character)?;
+
+                decoded.push(digit);
+            }
+        }
+
+        Ok(decoded)
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is 15% of the width of the symbol, as recommended for linear symbologies.
+    #[must_use]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim_by_width(xdim, self.encoded_len())
+    }
+}
+
+impl Barcode for TF {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
+    }
 }
 
 impl Parse for TF {
@@ -188,7 +398,7 @@ mod tests {
     use crate::error::Error;
     use crate::sym::tf::*;
     #[cfg(not(feature = "std"))]
-    pub(crate) use alloc::string::{String, ToString};
+    use alloc::string::{String, ToString};
     use core::char;
 
     fn collapse_vec(v: &[u8]) -> String {
@@ -227,10 +437,29 @@ mod tests {
     }
 
     #[test]
-    fn itf_raw_data() {
+    fn itf_no_pad() {
+        let itf = TF::interleaved_no_pad("12345679").expect("Failed to create interleaved barcode");
+
+        assert_eq!(itf.digits(), &[1, 2, 3, 4, 5, 6, 7, 9]);
+    }
+
+    #[test]
+    fn invalid_len_itf_no_pad() {
+        let itf = TF::interleaved_no_pad("1234567");
+
+        assert_eq!(itf.expect_err("Expected an error"), Error::Length);
+    }
+
+    #[test]
+    fn tf_digits() {
         let itf = TF::interleaved("12345679").expect("Failed to create interleaved barcode");
+        let itf_odd = TF::interleaved("1234567").expect("Failed to create interleaved barcode");
+        let stf = TF::standard("12345").expect("Failed to create standard barcode");
 
-        assert_eq!(itf.raw_data(), &[1, 2, 3, 4, 5, 6, 7, 9]);
+        assert_eq!(itf.digits(), &[1, 2, 3, 4, 5, 6, 7, 9]);
+        // The check digit 0 is appended to odd-length interleaved data.
+        assert_eq!(itf_odd.digits(), &[1, 2, 3, 4, 5, 6, 7, 0]);
+        assert_eq!(stf.digits(), &[1, 2, 3, 4, 5]);
     }
 
     #[test]
@@ -244,10 +473,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_itf14() {
+        let itf14 = TF::itf14("1540014128876").expect("Failed to create ITF-14 barcode");
+
+        assert_eq!(itf14.digits(), &[1, 5, 4, 0, 0, 1, 4, 1, 2, 8, 8, 7, 6, 3]);
+        assert!(TF::itf14("15400141288763").is_ok());
+        assert_eq!(TF::itf14("15400141288764").err(), Some(Error::Checksum));
+        assert_eq!(TF::itf14("154001412887").err(), Some(Error::Length));
+        assert_eq!(TF::itf14("154001412887630").err(), Some(Error::Length));
+        assert_eq!(TF::itf14("15400141288A6").err(), Some(Error::Character));
+    }
+
+    #[test]
+    fn itf14_encode() {
+        let itf14 = TF::itf14("15400141288763").expect("Failed to create ITF-14 barcode");
+        let bearer = itf14.encode_with_bearer();
+
+        assert_eq!(collapse_vec(&itf14.encode()), "10101110001010001011101010111000100011101000101110111010001000101110101110001000111010100011101110101011100010001000111000111010101101");
+        assert_eq!(bearer.modules, itf14.encode());
+        assert_eq!(bearer.width, BEARER_WIDTH);
+        assert_eq!(bearer.quiet_zone, BEARER_QUIET_ZONE);
+    }
+
     #[test]
     fn stf_encode() {
         let stf = TF::standard("1234567").expect("Failed to create standard barcode");
 
         assert_eq!(collapse_vec(&stf.encode()), "110110101110101010111010111010101110111011101010101010111010111011101011101010101110111010101010101110111011010110".to_string());
     }
+
+    #[test]
+    fn stf_decode() {
+        let stf = TF::standard("1234567").expect("Failed to create standard barcode");
+
+        assert_eq!(
+            TF::decode_standard(&stf.encode()),
+            Ok("1234567".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_data_stf_decode() {
+        let itf = TF::interleaved("1234567").expect("Failed to create interleaved barcode");
+        let mut bars = TF::standard("1234567")
+            .expect("Failed to create standard barcode")
+            .encode();
+        bars.insert(STF_START.len(), 0);
+
+        assert_eq!(
+            TF::decode_standard(&itf.encode()).expect_err("Expected an error"),
+            Error::Character
+        );
+        assert_eq!(
+            TF::decode_standard(&bars).expect_err("Expected an error"),
+            Error::Character
+        );
+    }
+
+    #[test]
+    fn itf_decode() {
+        let itf = TF::interleaved("1234567").expect("Failed to create interleaved barcode");
+        let itf14 = TF::itf14("15400141288763").expect("Failed to create ITF-14 barcode");
+
+        // The check digit appended to odd-length data is decoded too.
+        assert_eq!(
+            TF::decode_interleaved(&itf.encode()),
+            Ok("12345670".to_string())
+        );
+        assert_eq!(
+            TF::decode_interleaved(&itf14.encode()),
+            Ok("15400141288763".to_string())
+        );
+
+        for data in ["00", "99", "0123456789", "90817263544536271809"] {
+            let itf = TF::interleaved_no_pad(data).expect("Failed to create interleaved barcode");
+
+            assert_eq!(TF::decode_interleaved(&itf.encode()), Ok(data.to_string()));
+        }
+    }
+
+    #[test]
+    fn invalid_data_itf_decode() {
+        let stf = TF::standard("1234567").expect("Failed to create standard barcode");
+        let bars = TF::interleaved("1234567")
+            .expect("Failed to create interleaved barcode")
+            .encode();
+        let mut odd = bars.clone();
+        odd.insert(ITF_START.len(), 0);
+        let mut narrowed = bars;
+        // Narrows the first wide bar, of the digit 1 (WNNNW), to two modules.
+        narrowed[ITF_START.len() + 2] = 0;
+
+        assert_eq!(
+            TF::decode_interleaved(&stf.encode()).expect_err("Expected an error"),
+            Error::Character
+        );
+        assert_eq!(
+            TF::decode_interleaved(&odd).expect_err("Expected an error"),
+            Error::Length
+        );
+        assert_eq!(
+            TF::decode_interleaved(&[ITF_START, ITF_STOP].concat()).expect_err("Expected an error"),
+            Error::TooShort
+        );
+        assert_eq!(
+            TF::decode_interleaved(&narrowed).expect_err("Expected an error"),
+            Error::Character
+        );
+    }
+
+    #[test]
+    fn tf_encoded_len() {
+        let tf1 = TF::standard("1234567").expect("Failed to create barcode");
+        let tf2 = TF::interleaved("1234567").expect("Failed to create barcode");
+
+        assert_eq!(tf1.encoded_len(), tf1.encode().len());
+        assert_eq!(tf2.encoded_len(), tf2.encode().len());
+    }
 }
@@ -4,66 +4,38 @@
 //!
 //! This module defines types for:
 //!   * UPC-A
+//!
+//! An encoded UPC-A barcode is always 95 modules wide: two 3-module outer guards, a 5-module
+//! middle guard and twelve 7-module digits.
 
 use crate::error::{Error, Result};
-use crate::sym::{helpers, Parse};
+use crate::sym::{ean13, helpers, Barcode, Parse};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
 use core::char;
 use core::ops::Range;
 use helpers::Vec;
 
-/// Encoding mappings for UPC barcodes.
+/// Encoding mappings for UPC barcodes, shared with EAN-13.
 /// 1 = bar, 0 = no bar.
 ///
 /// The two indices are:
 /// * Left side encodings.
 /// * Right side encodings.
-pub const ENCODINGS: [[[u8; 7]; 10]; 2] = [
-    [
-        [0, 0, 0, 1, 1, 0, 1],
-        [0, 0, 1, 1, 0, 0, 1],
-        [0, 0, 1, 0, 0, 1, 1],
-        [0, 1, 1, 1, 1, 0, 1],
-        [0, 1, 0, 0, 0, 1, 1],
-        [0, 1, 1, 0, 0, 0, 1],
-        [0, 1, 0, 1, 1, 1, 1],
-        [0, 1, 1, 1, 0, 1, 1],
-        [0, 1, 1, 0, 1, 1, 1],
-        [0, 0, 0, 1, 0, 1, 1],
-    ],
-    [
-        [1, 1, 1, 0, 0, 1, 0],
-        [1, 1, 0, 0, 1, 1, 0],
-        [1, 1, 0, 1, 1, 0, 0],
-        [1, 0, 0, 0, 0, 1, 0],
-        [1, 0, 1, 1, 1, 0, 0],
-        [1, 0, 0, 1, 1, 1, 0],
-        [1, 0, 1, 0, 0, 0, 0],
-        [1, 0, 0, 0, 1, 0, 0],
-        [1, 0, 0, 1, 0, 0, 0],
-        [1, 1, 1, 0, 1, 0, 0],
-    ],
-];
-
-// Commented out because the previous dev didn't even use it.
-// const PARITY: [[usize; 5]; 10] = [
-//     [0, 0, 0, 0, 0],
-//     [0, 1, 0, 1, 1],
-//     [0, 1, 1, 0, 1],
-//     [0, 1, 1, 1, 0],
-//     [1, 0, 0, 1, 1],
-//     [1, 1, 0, 0, 1],
-//     [1, 1, 1, 0, 0],
-//     [1, 0, 1, 0, 1],
-//     [1, 0, 1, 1, 0],
-//     [1, 1, 0, 1, 0],
-// ];
-
-/// The left-hand guard pattern.
-pub const LEFT_GUARD: [u8; 3] = [1, 0, 1];
-/// The middle guard pattern.
-pub const MIDDLE_GUARD: [u8; 5] = [0, 1, 0, 1, 0];
-/// The right-hand guard pattern.
-pub const RIGHT_GUARD: [u8; 3] = [1, 0, 1];
+pub const ENCODINGS: [[[u8; 7]; 10]; 2] = [ean13::ENCODINGS[0], ean13::ENCODINGS[2]];
+
+/// The nominal bar height of a UPC-A barcode, in hundredths of the X dimension.
+pub(crate) const UPCA_HEIGHT: u64 = 6924;
+
+/// The number of modules in an encoded UPC-A barcode.
+const ENCODED_LEN: usize = 95;
+
+/// The left-hand guard pattern, shared with EAN-13.
+pub const LEFT_GUARD: [u8; 3] = ean13::LEFT_GUARD;
+/// The middle guard pattern, shared with EAN-13.
+pub const MIDDLE_GUARD: [u8; 5] = ean13::MIDDLE_GUARD;
+/// The right-hand guard pattern, shared with EAN-13.
+pub const RIGHT_GUARD: [u8; 3] = ean13::RIGHT_GUARD;
 
 /// The UPCA barcode type.
 #[derive(Debug)]
@@ -75,37 +47,44 @@ impl UPCA {
     /// # Errors
     /// This function returns an `Error::Checksum` if the provided checksum digit is invalid.
     /// It also returns an `Error::Character` if the input contains invalid characters,
-    /// or an `Error::Length` if the input length is not valid.
-    ///
-    /// # Panics
-    /// This function will panic if the input contains a character that cannot be converted
-    /// to a digit using `to_digit(10)`.
+    /// or an `Error::TooShort`/`Error::TooLong` if the input length is not valid.
     ///
     /// Returns `Result<UPCA, Error>` indicating parse success.
     pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
-        let d = Self::parse(data.as_ref())?;
-        #[allow(clippy::cast_possible_truncation)] // Safe: to_digit(10) returns values in 0..=9
-        let digits: Vec<u8> = d
-            .chars()
-            .map(|c| {
-                c.to_digit(10)
-                    .expect("Failed to convert character to digit") as u8
-            })
-            .collect();
+        Self::body(data.as_ref()).map(UPCA)
+    }
 
-        let upca = Self(digits[0..11].to_vec());
+    // Runs the same checks as `UPCA::new` without keeping the digits.
+    pub(crate) fn validate(data: &str) -> Result<()> {
+        Self::body(data).map(|_| ())
+    }
+
+    // Parses the data as digits, verifying and removing the check digit if one is provided.
+    fn body(data: &str) -> Result<Vec<u8>> {
+        let d = Self::parse(data)?;
+        let mut digits = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
 
         // If checksum digit is provided, check the checksum.
-        if digits.len() == 12 && upca.checksum_digit() != digits[11] {
+        if digits.len() == 12 && helpers::modulo_10_checksum(&digits[0..11]) != digits[11] {
             return Err(Error::Checksum);
         }
 
-        Ok(upca)
+        digits.truncate(11);
+        Ok(digits)
+    }
+
+    /// Returns the digits of the barcode, excluding the check digit.
+    #[must_use]
+    pub fn digits(&self) -> &[u8] {
+        &self.0
     }
 
     /// Calculates the checksum digit using a modulo-10 weighting algorithm.
     fn checksum_digit(&self) -> u8 {
-        helpers::modulo_10_checksum(&self.0[..], false)
+        helpers::modulo_10_checksum(&self.0[..])
     }
 
     fn checksum_encoding(&self) -> [u8; 7] {
@@ -148,7 +127,7 @@ impl UPCA {
     /// Returns a Vec<u8> of binary digits.
     #[must_use]
     pub fn encode(&self) -> Vec<u8> {
-        helpers::join_slices(
+        let enc = helpers::join_slices(
             &[
                 &LEFT_GUARD[..],
                 &self.left_payload()[..],
@@ -157,7 +136,68 @@ impl UPCA {
                 &self.checksum_encoding()[..],
                 &RIGHT_GUARD[..],
             ][..],
-        )
+        );
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is always 95.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        ENCODED_LEN
+    }
+
+    /// Decodes a sequence of bars (as produced by `encode`) back into its 12 digits, including
+    /// the check digit.
+    ///
+    /// # Errors
+    /// Returns an `Error::Length` if the bars are not 95 modules wide.
+    /// Returns an `Error::Character` if a guard or digit pattern is unknown.
+    /// Returns an `Error::Checksum` if the check digit does not match the data.
+    pub fn decode(bars: &[u8]) -> Result<String> {
+        if bars.len() != ENCODED_LEN {
+            return Err(Error::Length);
+        }
+
+        let (left, right) = (3..45, 50..92);
+
+        if bars[..left.start] != LEFT_GUARD
+            || bars[left.end..right.start] != MIDDLE_GUARD
+            || bars[right.end..] != RIGHT_GUARD
+        {
+            return Err(Error::Character);
+        }
+
+        let mut digits = helpers::decode_digits(&ENCODINGS[0], &bars[left])?;
+
+        digits.extend(helpers::decode_digits(&ENCODINGS[1], &bars[right])?);
+
+        let decoded = helpers::digits_to_string(&digits);
+
+        Self::new(&decoded).map(|_| decoded)
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// This is derived from the nominal UPC-A bar height of 22.85mm at an X dimension of 0.33mm.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim(xdim, UPCA_HEIGHT)
+    }
+}
+
+impl Barcode for UPCA {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
     }
 }
 
@@ -180,7 +220,7 @@ mod tests {
     use crate::error::Error;
     use crate::sym::upca::*;
     #[cfg(not(feature = "std"))]
-    use alloc::string::String;
+    use alloc::{format, string::String};
     use core::char;
 
     fn collapse_vec(v: &[u8]) -> String {
@@ -208,7 +248,7 @@ mod tests {
     fn invalid_len_upca() {
         let upca = UPCA::new("1234561234589");
 
-        assert_eq!(upca.expect_err("Expected an error"), Error::Length);
+        assert_eq!(upca.expect_err("Expected an error"), Error::TooLong);
     }
 
     #[test]
@@ -225,6 +265,23 @@ mod tests {
         assert!(upca.is_ok());
     }
 
+    #[test]
+    fn upca_decode() {
+        for data in ["036000291452", "123456789012", "012345678905"] {
+            let upca = UPCA::new(data).expect("Failed to create UPCA barcode");
+
+            assert_eq!(UPCA::decode(&upca.encode()), Ok(String::from(data)));
+        }
+
+        let mut encoded = UPCA::new("03600029145")
+            .expect("Failed to create UPCA barcode")
+            .encode();
+
+        assert_eq!(UPCA::decode(&encoded[..94]), Err(Error::Length));
+        encoded[85..92].copy_from_slice(&ENCODINGS[1][3]);
+        assert_eq!(UPCA::decode(&encoded), Err(Error::Checksum));
+    }
+
     #[test]
     fn upce_encode() {
         let upca1 = UPCA::new("72527273070").expect("Failed to create UPCA instance");
@@ -235,4 +292,37 @@ mod tests {
         assert_eq!(collapse_vec(&upca2.encode()), "10101110110111101011011101111010011001001001101010111001011001101011100111001011101001011100101");
         assert_eq!(collapse_vec(&upca3.encode()), "10100011010001011011000101000110010011001100101010111001010001001010000101000011001101100110101");
     }
+
+    #[test]
+    fn upca_encode_matches_ean13() {
+        // A UPC-A barcode is an EAN-13 barcode with a leading zero.
+        for data in ["03600029145", "72527273070", "12345678901"] {
+            let upca = UPCA::new(data).expect("Failed to create UPCA instance");
+            let ean13 =
+                ean13::EAN13::new(format!("0{data}")).expect("Failed to create EAN13 instance");
+
+            assert_eq!(upca.encode(), ean13.encode());
+        }
+    }
+
+    #[test]
+    fn upca_encode_digits() {
+        let upca = UPCA::new("03600029145").expect("Failed to create UPCA instance");
+        let encoded = upca.encode();
+
+        assert_eq!(encoded.len(), 95);
+        assert_eq!(&encoded[..3], &LEFT_GUARD);
+        assert_eq!(&encoded[3..10], &ENCODINGS[0][0]);
+        assert_eq!(&encoded[45..50], &MIDDLE_GUARD);
+        // The check digit is 2.
+        assert_eq!(&encoded[85..92], &ENCODINGS[1][2]);
+        assert_eq!(&encoded[92..], &RIGHT_GUARD);
+    }
+
+    #[test]
+    fn upca_encoded_len() {
+        let upca = UPCA::new("72527273070").expect("Failed to create barcode");
+
+        assert_eq!(upca.encoded_len(), upca.encode().len());
+    }
 }
@@ -0,0 +1,310 @@
+//! Encoder for UPC-E barcodes.
+//!
+//! UPC-E barcodes are zero-suppressed UPC-A barcodes, used on small packages where there is no
+//! room for a full UPC-A barcode.
+//!
+//! This module defines types for:
+//!   * UPC-E
+//!
+//! An encoded UPC-E barcode is always 51 modules wide: a 3-module left guard, six 7-module digits
+//! and a 6-module right guard.
+
+use crate::error::{Error, Result};
+use crate::sym::ean13::{ENCODINGS, LEFT_GUARD};
+use crate::sym::upca::{UPCA, UPCA_HEIGHT};
+use crate::sym::{helpers, Barcode, Parse};
+use core::char;
+use core::ops::Range;
+use helpers::Vec;
+
+/// The right-hand guard pattern of a UPC-E barcode.
+pub const RIGHT_GUARD: [u8; 6] = [0, 1, 0, 1, 0, 1];
+
+/// The number of modules in an encoded UPC-E barcode.
+const ENCODED_LEN: usize = 51;
+
+/// Maps parity (odd/even) for the UPC-E barcodes based on the check digit, for number system 0.
+/// Number system 1 uses the inverse of each pattern.
+///
+/// 0 = odd (left-hand), 1 = even (mirrored).
+const UPCE_PARITY: [[usize; 6]; 10] = [
+    [1, 1, 1, 0, 0, 0],
+    [1, 1, 0, 1, 0, 0],
+    [1, 1, 0, 0, 1, 0],
+    [1, 1, 0, 0, 0, 1],
+    [1, 0, 1, 1, 0, 0],
+    [1, 0, 0, 1, 1, 0],
+    [1, 0, 0, 0, 1, 1],
+    [1, 0, 1, 0, 1, 0],
+    [1, 0, 1, 0, 0, 1],
+    [1, 0, 0, 1, 0, 1],
+];
+
+/// The UPC-E barcode type.
+///
+/// Holds the number system digit followed by the six zero-suppressed digits.
+#[derive(Debug)]
+pub struct UPCE(Vec<u8>);
+
+impl UPCE {
+    /// Creates a new barcode from the number system digit (0 or 1) followed by the six
+    /// zero-suppressed digits, and optionally the check digit, e.g. "0123456" or "01234565".
+    ///
+    /// # Errors
+    /// Returns an `Error::Checksum` if the provided checksum digit is invalid.
+    /// Returns an `Error::Character` if the input contains invalid characters or the number
+    /// system is not 0 or 1.
+    /// Returns an `Error::TooShort` or `Error::TooLong` if the input length is not valid.
+    pub fn new<T: AsRef<str>>(data: T) -> Result<Self> {
+        let d = Self::parse(data.as_ref())?;
+        let digits = d
+            .chars()
+            .map(helpers::char_to_digit)
+            .collect::<Result<Vec<u8>>>()?;
+
+        if digits[0] > 1 {
+            return Err(Error::Character);
+        }
+
+        let upce = Self(digits[0..7].to_vec());
+
+        // If checksum digit is provided, check the checksum.
+        if digits.len() == 8 && upce.checksum_digit() != digits[7] {
+            return Err(Error::Checksum);
+        }
+
+        Ok(upce)
+    }
+
+    /// Creates a new barcode by zero-suppressing a UPC-A barcode.
+    ///
+    /// # Errors
+    /// Returns an `Error::Conversion` if the UPC-A barcode cannot be zero-suppressed, either
+    /// because its number system is not 0 or 1 or because it does not have enough zeros in the
+    /// right places.
+    pub fn from_upca(upca: &UPCA) -> Result<Self> {
+        let digits = upca.digits();
+        let (m, p) = (&digits[1..6], &digits[6..11]);
+
+        if digits[0] > 1 {
+            return Err(Error::Conversion);
+        }
+
+        let suppressed = if m[3..] == [0, 0] && m[2] <= 2 && p[..2] == [0, 0] {
+            [m[0], m[1], p[2], p[3], p[4], m[2]]
+        } else if m[3..] == [0, 0] && p[..3] == [0, 0, 0] {
+            [m[0], m[1], m[2], p[3], p[4], 3]
+        } else if m[4] == 0 && p[..4] == [0, 0, 0, 0] {
+            [m[0], m[1], m[2], m[3], p[4], 4]
+        } else if p[..4] == [0, 0, 0, 0] && p[4] >= 5 {
+            [m[0], m[1], m[2], m[3], m[4], p[4]]
+        } else {
+            return Err(Error::Conversion);
+        };
+
+        let mut encoded = Vec::with_capacity(7);
+        encoded.push(digits[0]);
+        encoded.extend_from_slice(&suppressed);
+
+        Ok(Self(encoded))
+    }
+
+    /// Expands the zero-suppressed digits into the 11 digits of the equivalent UPC-A barcode,
+    /// excluding the check digit.
+    fn expand(&self) -> [u8; 11] {
+        let d = &self.0;
+        let body = match d[6] {
+            0..=2 => [d[1], d[2], d[6], 0, 0, 0, 0, d[3], d[4], d[5]],
+            3 => [d[1], d[2], d[3], 0, 0, 0, 0, 0, d[4], d[5]],
+            4 => [d[1], d[2], d[3], d[4], 0, 0, 0, 0, 0, d[5]],
+            _ => [d[1], d[2], d[3], d[4], d[5], 0, 0, 0, 0, d[6]],
+        };
+
+        let mut expanded = [d[0]; 11];
+        expanded[1..].copy_from_slice(&body);
+        expanded
+    }
+
+    /// Calculates the checksum digit of the equivalent UPC-A barcode.
+    fn checksum_digit(&self) -> u8 {
+        helpers::modulo_10_checksum(&self.expand())
+    }
+
+    /// Returns the parity pattern for the number system and check digit.
+    fn parity(&self) -> [usize; 6] {
+        let parity = UPCE_PARITY[usize::from(self.checksum_digit())];
+
+        match self.0[0] {
+            0 => parity,
+            _ => parity.map(|p| 1 - p),
+        }
+    }
+
+    fn payload(&self) -> Vec<u8> {
+        let slices: Vec<[u8; 7]> = self.0[1..]
+            .iter()
+            .zip(self.parity())
+            .map(|(d, p)| ENCODINGS[p][usize::from(*d)])
+            .collect();
+
+        helpers::join_iters(slices.iter())
+    }
+
+    /// Encodes the barcode.
+    /// Returns a Vec<u8> of binary digits.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let enc =
+            helpers::join_slices(&[&LEFT_GUARD[..], &self.payload()[..], &RIGHT_GUARD[..]][..]);
+
+        debug_assert_eq!(enc.len(), self.encoded_len());
+        enc
+    }
+
+    /// Returns the number of modules in the encoded barcode, i.e. the length of `encode()`,
+    /// without encoding it.
+    ///
+    /// This is always 51.
+    #[must_use]
+    pub const fn encoded_len(&self) -> usize {
+        ENCODED_LEN
+    }
+
+    /// Returns the recommended minimum bar height for the given X dimension.
+    ///
+    /// UPC-E shares the nominal UPC-A bar height of 22.85mm at an X dimension of 0.33mm.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn recommended_min_height(&self, xdim: u32) -> u32 {
+        helpers::scale_xdim(xdim, UPCA_HEIGHT)
+    }
+}
+
+impl Barcode for UPCE {
+    fn encode(&self) -> Vec<u8> {
+        Self::encode(self)
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::encoded_len(self)
+    }
+}
+
+impl Parse for UPCE {
+    /// Returns the valid length of data acceptable in this type of barcode.
+    fn valid_len() -> Range<u32> {
+        7..8
+    }
+
+    /// Returns the set of valid characters allowed in this type of barcode.
+    fn valid_chars() -> Vec<char> {
+        (0..10)
+            .map(|i| char::from_digit(i, 10).expect("Failed to convert digit to character"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::sym::layout::default_height_modules;
+    use crate::sym::upca::UPCA;
+    use crate::sym::upce::*;
+    use crate::sym::Symbology;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+    use core::char;
+
+    fn collapse_vec(v: &[u8]) -> String {
+        let chars = v.iter().map(|d| {
+            char::from_digit(u32::from(*d), 10).expect("Failed to convert digit to character")
+        });
+        chars.collect()
+    }
+
+    #[test]
+    fn new_upce() {
+        let upce = UPCE::new("0123456").expect("Failed to create UPCE barcode");
+
+        assert_eq!(upce.checksum_digit(), 5);
+        assert!(UPCE::new("01234565").is_ok());
+        assert!(UPCE::new("1123456").is_ok());
+    }
+
+    #[test]
+    fn invalid_upce() {
+        assert_eq!(UPCE::new("01234566").err(), Some(Error::Checksum));
+        assert_eq!(UPCE::new("2123456").err(), Some(Error::Character));
+        assert_eq!(UPCE::new("012345A").err(), Some(Error::Character));
+        assert_eq!(UPCE::new("012345").err(), Some(Error::TooShort));
+        assert_eq!(UPCE::new("012345650").err(), Some(Error::TooLong));
+    }
+
+    #[test]
+    fn upce_from_upca() {
+        for (upca_data, upce_data) in [
+            ("01234500006", "0123456"),
+            ("04210000526", "0425261"),
+            ("01200000789", "0127890"),
+            ("01230000045", "0123453"),
+            ("01240000005", "0124053"),
+            ("11234000005", "1123454"),
+        ] {
+            let upca = UPCA::new(upca_data).expect("Failed to create UPCA barcode");
+            let expected = UPCE::new(upce_data).expect("Failed to create UPCE barcode");
+            let converted = UPCE::from_upca(&upca).expect("Failed to convert UPCA barcode");
+
+            assert_eq!(converted.0, expected.0);
+            assert_eq!(&converted.expand()[..], upca.digits());
+        }
+
+        for upca in ["21234500006", "01234500106", "01234100004"] {
+            let upca = UPCA::new(upca).expect("Failed to create UPCA barcode");
+
+            assert_eq!(UPCE::from_upca(&upca).err(), Some(Error::Conversion));
+        }
+    }
+
+    #[test]
+    fn upce_encode() {
+        let upce1 = UPCE::new("0123456").expect("Failed to create UPCE barcode"); // Check digit: 5
+        let upce2 = UPCE::new("0425261").expect("Failed to create UPCE barcode"); // Check digit: 4
+        let upce3 = UPCE::new("1123456").expect("Failed to create UPCE barcode"); // Check digit: 2
+
+        assert_eq!(upce1.encode().len(), 51);
+        assert_eq!(
+            collapse_vec(&upce1.encode()),
+            "101011001100100110111101001110101110010101111010101"
+        );
+        assert_eq!(
+            collapse_vec(&upce2.encode()),
+            "101001110100100110111001001101101011110011001010101"
+        );
+        assert_eq!(
+            collapse_vec(&upce3.encode()),
+            "101001100100100110100001001110101100010000101010101"
+        );
+    }
+
+    #[test]
+    fn upce_encoded_len() {
+        let upce = UPCE::new("0123456").expect("Failed to create barcode");
+
+        assert_eq!(upce.encoded_len(), upce.encode().len());
+    }
+
+    #[test]
+    fn upce_recommended_min_height() {
+        let upce = UPCE::new("0123456").expect("Failed to create barcode");
+        let expanded = UPCA::new("01234500006").expect("Failed to create barcode");
+
+        assert_eq!(
+            upce.recommended_min_height(1),
+            default_height_modules(Symbology::UPCE, upce.encoded_len())
+        );
+        assert_eq!(
+            upce.recommended_min_height(3),
+            expanded.recommended_min_height(3)
+        );
+    }
+}
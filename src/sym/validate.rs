@@ -0,0 +1,293 @@
+//! Standalone validators for each symbology.
+//!
+//! Each validator runs the same checks as the corresponding constructor, returning the same
+//! errors, but without building the barcode. This is useful for validating input (e.g. in a
+//! form) before it is stored.
+//!
+//! For example:
+//!
+//! ```rust
+//! use scanning::error::Error;
+//! use scanning::sym::validate;
+//!
+//! assert_eq!(validate::ean13("750103131130"), Ok(()));
+//! assert_eq!(validate::ean13("7501031311309"), Ok(()));
+//! assert_eq!(validate::ean13("7501031311308"), Err(Error::Checksum));
+//! ```
+
+use crate::error::Result;
+use crate::sym::codabar::Codabar;
+use crate::sym::code11::Code11;
+use crate::sym::code128::{CharacterSet, Code128};
+use crate::sym::code39::Code39;
+use crate::sym::code93::Code93;
+use crate::sym::ean13::EAN13;
+use crate::sym::ean8::EAN8;
+use crate::sym::ean_supp::EANSUPP;
+use crate::sym::tf::TF;
+use crate::sym::upca::UPCA;
+use crate::sym::Parse;
+
+/// Validates data for an EAN-13 (or Bookland/JAN) barcode.
+///
+/// # Errors
+/// Returns the same errors as `EAN13::new`.
+pub fn ean13<T: AsRef<str>>(data: T) -> Result<()> {
+    EAN13::validate(data.as_ref())
+}
+
+/// Validates data for an EAN-8 barcode.
+///
+/// # Errors
+/// Returns the same errors as `EAN8::new`.
+pub fn ean8<T: AsRef<str>>(data: T) -> Result<()> {
+    EAN8::validate(data.as_ref())
+}
+
+/// Validates data for a UPC-A barcode.
+///
+/// # Errors
+/// Returns the same errors as `UPCA::new`.
+pub fn upca<T: AsRef<str>>(data: T) -> Result<()> {
+    UPCA::validate(data.as_ref())
+}
+
+/// Validates data for a supplemental EAN-2 or EAN-5 barcode.
+///
+/// # Errors
+/// Returns the same errors as `EANSUPP::new`.
+pub fn ean_supp<T: AsRef<str>>(data: T) -> Result<()> {
+    EANSUPP::validate(data.as_ref())
+}
+
+/// Validates data for a Code11 barcode.
+///
+/// # Errors
+/// Returns the same errors as `Code11::new`.
+pub fn code11<T: AsRef<str>>(data: T) -> Result<()> {
+    Code11::parse(data.as_ref()).map(|_| ())
+}
+
+/// Validates data for a Code39 barcode.
+///
+/// # Errors
+/// Returns the same errors as `Code39::new`.
+pub fn code39<T: AsRef<str>>(data: T) -> Result<()> {
+    Code39::parse(data.as_ref()).map(|_| ())
+}
+
+/// Validates data for a Code93 barcode.
+///
+/// # Errors
+/// Returns the same errors as `Code93::new`.
+pub fn code93<T: AsRef<str>>(data: T) -> Result<()> {
+    Code93::parse(data.as_ref()).map(|_| ())
+}
+
+/// Validates data for a full-ASCII Code93 barcode.
+///
+/// # Errors
+/// Returns the same errors as `Code93::new_extended`.
+pub fn code93_extended<T: AsRef<str>>(data: T) -> Result<()> {
+    Code93::validate_extended(data.as_ref())
+}
+
+/// Validates data for a Code128 barcode using the given starting character-set.
+///
+/// # Errors
+/// Returns the same errors as `Code128::new`.
+pub fn code128<T: AsRef<str>>(data: T, character_set: CharacterSet) -> Result<()> {
+    Code128::validate(data.as_ref(), character_set)
+}
+
+/// Validates data for a Codabar barcode.
+///
+/// # Errors
+/// Returns the same errors as `Codabar::new`.
+pub fn codabar<T: AsRef<str>>(data: T) -> Result<()> {
+    Codabar::validate(data.as_ref())
+}
+
+/// Validates data for an interleaved 2-of-5 barcode.
+///
+/// # Errors
+/// Returns the same errors as `TF::interleaved`.
+pub fn itf<T: AsRef<str>>(data: T) -> Result<()> {
+    TF::parse(data.as_ref()).map(|_| ())
+}
+
+/// Validates data for a standard 2-of-5 barcode.
+///
+/// # Errors
+/// Returns the same errors as `TF::standard`.
+pub fn stf<T: AsRef<str>>(data: T) -> Result<()> {
+    TF::parse(data.as_ref()).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::sym::codabar::*;
+    use crate::sym::code11::*;
+    use crate::sym::code128::*;
+    use crate::sym::code39::*;
+    use crate::sym::code93::*;
+    use crate::sym::ean13::*;
+    use crate::sym::ean8::*;
+    use crate::sym::ean_supp::*;
+    use crate::sym::tf::*;
+    use crate::sym::upca::*;
+    use crate::sym::validate;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+
+    #[test]
+    fn validators_agree_with_constructors() {
+        for data in [
+            "750103131130",
+            "7501031311309",
+            "7501031311308",
+            "75010313113",
+            "75O103131130",
+            "12345678901",
+            "123456789012",
+            "123456789013",
+        ] {
+            assert_eq!(validate::ean13(data), EAN13::new(data).map(|_| ()));
+            assert_eq!(validate::upca(data), UPCA::new(data).map(|_| ()));
+        }
+
+        for data in ["9638507", "96385074", "96385075", "963850", "12E4567"] {
+            assert_eq!(validate::ean8(data), EAN8::new(data).map(|_| ()));
+        }
+
+        for data in ["12", "12345", "123", "1", "123456", "1A"] {
+            assert_eq!(validate::ean_supp(data), EANSUPP::new(data).map(|_| ()));
+        }
+
+        for data in ["123-45", "", "12A45"] {
+            assert_eq!(validate::code11(data), Code11::new(data).map(|_| ()));
+        }
+
+        let long_lowercase: String = core::iter::repeat_n('a', 200).collect();
+
+        for data in ["TEST8052", "", "TEST☺", "test", "test93", &long_lowercase] {
+            assert_eq!(validate::code39(data), Code39::new(data).map(|_| ()));
+            assert_eq!(validate::code93(data), Code93::new(data).map(|_| ()));
+            assert_eq!(
+                validate::code93_extended(data),
+                Code93::new_extended(data).map(|_| ())
+            );
+        }
+
+        for (data, character_set) in [
+            ("HELLO", CharacterSet::A),
+            ("HELLO", CharacterSet::None),
+            ("Ć4218402050À0", CharacterSet::None),
+            ("H", CharacterSet::B),
+            ("123", CharacterSet::C),
+        ] {
+            assert_eq!(
+                validate::code128(data, character_set),
+                Code128::new(data, character_set).map(|_| ())
+            );
+        }
+
+        for data in ["A1234B", "", "A", "1234B", "A12C34B", "A12345G"] {
+            assert_eq!(validate::codabar(data), Codabar::new(data).map(|_| ()));
+        }
+
+        for data in ["1234567", "12345678", "", "WORDUP"] {
+            assert_eq!(validate::itf(data), TF::interleaved(data).map(|_| ()));
+            assert_eq!(validate::stf(data), TF::standard(data).map(|_| ()));
+        }
+    }
+
+    #[test]
+    fn validate_ean_upc() {
+        assert_eq!(validate::ean13("750103131130"), Ok(()));
+        assert_eq!(validate::ean13("7501031311309"), Ok(()));
+        assert_eq!(validate::ean13("7501031311308"), Err(Error::Checksum));
+        assert_eq!(validate::ean13("75010313113"), Err(Error::TooShort));
+        assert_eq!(validate::ean13("75010313113090"), Err(Error::TooLong));
+        assert_eq!(validate::ean13("75O103131130"), Err(Error::Character));
+
+        assert_eq!(validate::ean8("9638507"), Ok(()));
+        assert_eq!(validate::ean8("96385074"), Ok(()));
+        assert_eq!(validate::ean8("96385075"), Err(Error::Checksum));
+        assert_eq!(validate::ean8("963850"), Err(Error::TooShort));
+        assert_eq!(validate::ean8("12E4567"), Err(Error::Character));
+
+        assert_eq!(validate::upca("12345678901"), Ok(()));
+        assert_eq!(validate::upca("123456789012"), Ok(()));
+        assert_eq!(validate::upca("123456789013"), Err(Error::Checksum));
+        assert_eq!(validate::upca("1234567890"), Err(Error::TooShort));
+        assert_eq!(validate::upca("1234567890123"), Err(Error::TooLong));
+    }
+
+    #[test]
+    fn validate_ean_supp() {
+        assert_eq!(validate::ean_supp("12"), Ok(()));
+        assert_eq!(validate::ean_supp("12345"), Ok(()));
+        assert_eq!(validate::ean_supp("123"), Err(Error::Length));
+        assert_eq!(validate::ean_supp("1"), Err(Error::TooShort));
+        assert_eq!(validate::ean_supp("123456"), Err(Error::TooLong));
+        assert_eq!(validate::ean_supp("1A"), Err(Error::Character));
+    }
+
+    #[test]
+    fn validate_variable_length() {
+        assert_eq!(validate::code11("123-45"), Ok(()));
+        assert_eq!(validate::code11(""), Err(Error::TooShort));
+        assert_eq!(validate::code11("12A45"), Err(Error::Character));
+
+        assert_eq!(validate::code39("TEST8052"), Ok(()));
+        assert_eq!(validate::code39("test"), Err(Error::Character));
+
+        assert_eq!(validate::code93("TEST93"), Ok(()));
+        assert_eq!(validate::code93("TEST☺"), Err(Error::Character));
+        assert_eq!(validate::code93_extended("test93"), Ok(()));
+        assert_eq!(validate::code93_extended(""), Err(Error::TooShort));
+        assert_eq!(validate::code93_extended("TEST☺"), Err(Error::Character));
+
+        assert_eq!(validate::itf("1234567"), Ok(()));
+        assert_eq!(validate::itf("WORDUP"), Err(Error::Character));
+        assert_eq!(validate::stf("12345678"), Ok(()));
+        assert_eq!(validate::stf(""), Err(Error::TooShort));
+    }
+
+    #[test]
+    fn validate_codabar() {
+        assert_eq!(validate::codabar("A1234B"), Ok(()));
+        assert_eq!(validate::codabar("1234B"), Err(Error::Character));
+        assert_eq!(validate::codabar("A12C34B"), Err(Error::Character));
+        assert_eq!(validate::codabar("A"), Err(Error::Character));
+        assert_eq!(validate::codabar("A12345G"), Err(Error::Character));
+        assert_eq!(validate::codabar(""), Err(Error::TooShort));
+    }
+
+    #[test]
+    fn validate_code128() {
+        assert_eq!(validate::code128("HELLO", CharacterSet::A), Ok(()));
+        assert_eq!(
+            validate::code128("Ć4218402050À0", CharacterSet::None),
+            Ok(())
+        );
+        assert_eq!(
+            validate::code128("HELLO", CharacterSet::None),
+            Err(Error::CharacterSet)
+        );
+        assert_eq!(
+            validate::code128("H", CharacterSet::B),
+            Err(Error::TooShort)
+        );
+        assert_eq!(
+            validate::code128("☺ ", CharacterSet::A),
+            Err(Error::Character)
+        );
+        assert_eq!(
+            validate::code128("123", CharacterSet::C),
+            Err(Error::Character)
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Round-trip guarantees between the encoders and decoders of each symbology.
+//!
+//! For every symbology with a decoder, each fixture is encoded and then decoded, and the result
+//! must equal the expected data. Fixtures are `(input, decoded)` pairs, as some symbologies
+//! add check characters (EAN/UPC, ITF) that are part of the decoded data.
+//!
+//! The EAN-2/EAN-5 supplements, UPC-E, MSI and GS1 DataBar have no decoders yet, and so are not
+//! covered.
+
+use scanning::sym::decode;
+use scanning::sym::Symbology;
+
+/// Asserts that each input encodes to bars which decode to the expected data, both with the
+/// symbology's own decoder and when the symbology is auto-detected.
+fn assert_roundtrips<E>(
+    sym: Symbology,
+    fixtures: &[(&str, &str)],
+    encode: impl Fn(&str) -> E,
+    decode: impl Fn(&[u8]) -> scanning::error::Result<String>,
+) where
+    E: AsRef<[u8]>,
+{
+    for &(input, decoded) in fixtures {
+        let bars = encode(input);
+
+        assert_eq!(decode(bars.as_ref()).as_deref(), Ok(decoded), "{input:?}");
+        assert_eq!(
+            decode::auto(bars.as_ref()),
+            Ok((sym, String::from(decoded))),
+            "{input:?}"
+        );
+    }
+}
+
+mod ean {
+    use super::*;
+    use scanning::sym::ean13::EAN13;
+    use scanning::sym::ean8::EAN8;
+    use scanning::sym::upca::UPCA;
+
+    const EAN13_FIXTURES: &[(&str, &str)] = &[
+        ("750103131130", "7501031311309"),
+        ("9781234567897", "9781234567897"),
+        ("4901234567894", "4901234567894"),
+        ("200000000000", "2000000000008"),
+    ];
+
+    const UPCA_FIXTURES: &[(&str, &str)] = &[
+        ("03600029145", "036000291452"),
+        ("123456789012", "123456789012"),
+        ("00000000000", "000000000000"),
+    ];
+
+    const EAN8_FIXTURES: &[(&str, &str)] = &[
+        ("5512345", "55123457"),
+        ("12345670", "12345670"),
+        ("00000000", "00000000"),
+    ];
+
+    #[test]
+    fn ean13_roundtrip() {
+        assert_roundtrips(
+            Symbology::EAN13,
+            EAN13_FIXTURES,
+            |data| EAN13::new(data).expect("Failed to create EAN13").encode(),
+            EAN13::decode,
+        );
+    }
+
+    #[test]
+    fn upca_roundtrip() {
+        assert_roundtrips(
+            Symbology::UPCA,
+            UPCA_FIXTURES,
+            |data| UPCA::new(data).expect("Failed to create UPCA").encode(),
+            UPCA::decode,
+        );
+    }
+
+    #[test]
+    fn ean8_roundtrip() {
+        assert_roundtrips(
+            Symbology::EAN8,
+            EAN8_FIXTURES,
+            |data| EAN8::new(data).expect("Failed to create EAN8").encode(),
+            EAN8::decode,
+        );
+    }
+}
+
+mod code128 {
+    use super::*;
+    use scanning::sym::code128::{CharacterSet, Code128};
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("ÀHELLO", "ÀHELLO"),
+        ("ƁHello, World!", "ƁHello, World!"),
+        ("Ć123456", "Ć123456"),
+        ("ĆŹ4218402050À0", "ĆŹ4218402050À0"),
+        ("ƁAźBŻCżDŽEŹ", "ƁAźBŻCżDŽEŹ"),
+        ("À\u{0000}\u{001F}", "À\u{0000}\u{001F}"),
+    ];
+
+    #[test]
+    fn code128_roundtrip() {
+        assert_roundtrips(
+            Symbology::Code128,
+            FIXTURES,
+            |data| {
+                Code128::new(data, CharacterSet::None)
+                    .expect("Failed to create Code128")
+                    .encode()
+            },
+            Code128::decode,
+        );
+    }
+}
+
+mod code39 {
+    use super::*;
+    use scanning::sym::code39::Code39;
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("1234", "1234"),
+        ("CODE-39", "CODE-39"),
+        ("A B.C$D/E+F%G", "A B.C$D/E+F%G"),
+        (
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        ),
+    ];
+
+    #[test]
+    fn code39_roundtrip() {
+        assert_roundtrips(
+            Symbology::Code39,
+            FIXTURES,
+            |data| Code39::new(data).expect("Failed to create Code39").encode(),
+            Code39::decode,
+        );
+    }
+}
+
+mod code93 {
+    use super::*;
+    use scanning::sym::code93::Code93;
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("TEST93", "TEST93"),
+        ("9", "9"),
+        ("CODE 93-$/+%.", "CODE 93-$/+%."),
+    ];
+
+    #[test]
+    fn code93_roundtrip() {
+        assert_roundtrips(
+            Symbology::Code93,
+            FIXTURES,
+            |data| Code93::new(data).expect("Failed to create Code93").encode(),
+            Code93::decode,
+        );
+    }
+}
+
+mod code11 {
+    use super::*;
+    use scanning::sym::code11::Code11;
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("123-45", "123-45"),
+        ("9988-45643201", "9988-45643201"),
+        ("0", "0"),
+    ];
+
+    #[test]
+    fn code11_roundtrip() {
+        assert_roundtrips(
+            Symbology::Code11,
+            FIXTURES,
+            |data| Code11::new(data).expect("Failed to create Code11").encode(),
+            Code11::decode,
+        );
+    }
+}
+
+mod tf {
+    use super::*;
+    use scanning::sym::tf::TF;
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("1234567", "1234567"),
+        ("0", "0"),
+        ("9876543210", "9876543210"),
+    ];
+
+    // Inputs with an odd number of digits gain a check digit, so that the digits pair up.
+    const ITF_FIXTURES: &[(&str, &str)] = &[
+        ("1234567", "12345670"),
+        ("0", "00"),
+        ("12345678", "12345678"),
+        ("9876543210", "9876543210"),
+    ];
+
+    const ITF14_FIXTURES: &[(&str, &str)] = &[
+        ("1061414112345", "10614141123459"),
+        ("10614141123459", "10614141123459"),
+    ];
+
+    #[test]
+    fn stf_roundtrip() {
+        assert_roundtrips(
+            Symbology::STF,
+            FIXTURES,
+            |data| TF::standard(data).expect("Failed to create STF").encode(),
+            TF::decode_standard,
+        );
+    }
+
+    #[test]
+    fn itf_roundtrip() {
+        assert_roundtrips(
+            Symbology::ITF,
+            ITF_FIXTURES,
+            |data| {
+                TF::interleaved(data)
+                    .expect("Failed to create ITF")
+                    .encode()
+            },
+            TF::decode_interleaved,
+        );
+    }
+
+    #[test]
+    fn itf14_roundtrip() {
+        assert_roundtrips(
+            Symbology::ITF14,
+            ITF14_FIXTURES,
+            |data| TF::itf14(data).expect("Failed to create ITF-14").encode(),
+            TF::decode_interleaved,
+        );
+    }
+}
+
+mod codabar {
+    use super::*;
+    use scanning::sym::codabar::Codabar;
+
+    const FIXTURES: &[(&str, &str)] = &[
+        ("A40156B", "A40156B"),
+        ("C12-34$56:78/90.12+D", "C12-34$56:78/90.12+D"),
+        ("D1234A", "D1234A"),
+    ];
+
+    #[test]
+    fn codabar_roundtrip() {
+        assert_roundtrips(
+            Symbology::Codabar,
+            FIXTURES,
+            |data| {
+                Codabar::new(data)
+                    .expect("Failed to create Codabar")
+                    .encode()
+            },
+            Codabar::decode,
+        );
+    }
+}